@@ -1,13 +1,20 @@
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_constructor_entry_point;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::RuntimeState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage::StorageAccess;
+use blockifier::execution::execution_utils::felt_to_stark_felt;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
 use blockifier::execution::syscalls::{
-    DeployRequest, DeployResponse, LibraryCallRequest, SyscallResponse, SyscallResult,
+    get_block_hash, storage_read, storage_write, DeployRequest, DeployResponse,
+    GetBlockHashRequest, GetBlockHashResponse, LibraryCallRequest, StorageReadRequest,
+    StorageReadResponse, StorageWriteRequest, StorageWriteResponse, SyscallResponse, SyscallResult,
 };
 use blockifier::execution::{call_info::CallInfo, entry_point::ConstructorContext};
 use blockifier::execution::{
     execution_utils::ReadOnlySegment,
-    syscalls::{hint_processor::write_segment, WriteResponseResult},
+    syscalls::{
+        hint_processor::{write_maybe_relocatable, write_segment},
+        WriteResponseResult,
+    },
 };
 use blockifier::state::errors::StateError;
 use blockifier::{
@@ -24,9 +31,12 @@ use blockifier::{
     },
     state::state_api::State,
 };
+use cairo_felt::Felt252;
 use cairo_vm::types::relocatable::Relocatable;
 use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
 use cairo_vm::vm::vm_core::VirtualMachine;
+use conversions::IntoConv;
+use starknet_api::block::BlockHash;
 use starknet_api::core::calculate_contract_address;
 use starknet_api::{
     core::{ClassHash, ContractAddress},
@@ -59,6 +69,73 @@ pub fn get_execution_info_syscall(
     })
 }
 
+// blockifier/src/execution/syscalls/mod.rs (get_block_hash)
+pub fn get_block_hash_syscall(
+    request: GetBlockHashRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut SyscallHintProcessor<'_>,
+    runtime_state: &mut RuntimeState,
+    remaining_gas: &mut u64,
+) -> SyscallResult<GetBlockHashResponse> {
+    let contract_address = syscall_handler.storage_address();
+    let block_number = request.block_number.0;
+
+    if let Some(block_hash) = runtime_state
+        .cheatnet_state
+        .get_mocked_block_hash(&contract_address, block_number)
+    {
+        return Ok(GetBlockHashResponse {
+            block_hash: BlockHash(felt_to_stark_felt(&block_hash)),
+        });
+    }
+
+    get_block_hash(request, vm, syscall_handler, remaining_gas)
+}
+
+// blockifier/src/execution/syscalls/mod.rs:603 (storage_read)
+pub fn storage_read_syscall(
+    request: StorageReadRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut SyscallHintProcessor<'_>,
+    runtime_state: &mut RuntimeState,
+    remaining_gas: &mut u64,
+) -> SyscallResult<StorageReadResponse> {
+    let key = request.address;
+    let response = storage_read(request, vm, syscall_handler, remaining_gas)?;
+
+    runtime_state
+        .cheatnet_state
+        .record_storage_access(StorageAccess {
+            contract_address: syscall_handler.storage_address(),
+            key: (*key.0.key()).into_(),
+            is_write: false,
+            value: response.value.into_(),
+        });
+    Ok(response)
+}
+
+// blockifier/src/execution/syscalls/mod.rs:635 (storage_write)
+pub fn storage_write_syscall(
+    request: StorageWriteRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut SyscallHintProcessor<'_>,
+    runtime_state: &mut RuntimeState,
+    remaining_gas: &mut u64,
+) -> SyscallResult<StorageWriteResponse> {
+    let storage_access = StorageAccess {
+        contract_address: syscall_handler.storage_address(),
+        key: (*request.address.0.key()).into_(),
+        is_write: true,
+        value: request.value.into_(),
+    };
+    let response = storage_write(request, vm, syscall_handler, remaining_gas)?;
+
+    runtime_state
+        .cheatnet_state
+        .record_storage_access(storage_access);
+    Ok(response)
+}
+
 // blockifier/src/execution/syscalls/mod.rs:222 (deploy_syscall)
 pub fn deploy_syscall(
     request: DeployRequest,
@@ -217,3 +294,16 @@ impl SyscallResponse for SingleSegmentResponse {
         write_segment(vm, ptr, self.segment)
     }
 }
+
+/// Response of a syscall mocked with `start_mock_syscall`, written as is
+#[derive(Debug)]
+pub struct MockedSyscallResponse(pub Vec<Felt252>);
+
+impl SyscallResponse for MockedSyscallResponse {
+    fn write(self, vm: &mut VirtualMachine, ptr: &mut Relocatable) -> WriteResponseResult {
+        for felt in self.0 {
+            write_maybe_relocatable(vm, ptr, felt)?;
+        }
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@ use blockifier::{
 };
 use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
 use starknet_api::{
-    core::ClassHash,
+    core::{ClassHash, ContractAddress},
     deprecated_contract_class::EntryPointType,
     hash::StarkFelt,
     transaction::{Calldata, TransactionVersion},
@@ -43,6 +43,21 @@ pub fn execute_call_entry_point(
     resources: &mut ExecutionResources,
     context: &mut EntryPointExecutionContext,
 ) -> EntryPointExecutionResult<CallInfo> {
+    // region: Modified blockifier code
+    // Recursion depth is validated against the call stack, as the depth counter of the context is private.
+    // The stack holds the test itself and calls in progress, so its size is the depth of this call.
+    let max_recursion_depth = context.versioned_constants().max_recursion_depth;
+    if runtime_state
+        .cheatnet_state
+        .trace_data
+        .current_call_stack
+        .size()
+        > max_recursion_depth
+    {
+        return Err(EntryPointExecutionError::RecursionDepthExceeded);
+    }
+    // endregion
+
     let cheated_data = if let CallType::Delegate = entry_point.call_type {
         runtime_state
             .cheatnet_state
@@ -52,42 +67,55 @@ pub fn execute_call_entry_point(
             .clone()
     } else {
         let contract_address = &entry_point.storage_address;
-        let cheated_data_ = runtime_state
+        let mut cheated_data_ = runtime_state
             .cheatnet_state
             .create_cheated_data(contract_address);
+        if cheated_data_.caller_address.is_none()
+            && runtime_state
+                .cheatnet_state
+                .is_impersonated_execute(entry_point)
+        {
+            cheated_data_.caller_address = Some(ContractAddress::default());
+        }
         runtime_state.cheatnet_state.update_cheats(contract_address);
         cheated_data_
     };
 
     // region: Modified blockifier code
-    // We skip recursion depth validation here.
     runtime_state.cheatnet_state.trace_data.enter_nested_call(
         entry_point.clone(),
         resources.clone(),
         cheated_data,
     );
 
+    runtime_state
+        .cheatnet_state
+        .register_expected_call(entry_point);
+
     if let Some(cheat_status) =
         get_mocked_function_cheat_status(entry_point, runtime_state.cheatnet_state)
     {
         if let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone() {
             cheat_status.decrement_cheat_span();
-            let ret_data_f252: Vec<Felt252> = ret_data
-                .iter()
-                .map(|datum| Felt252::from_(*datum))
-                .collect();
-            runtime_state.cheatnet_state.trace_data.exit_nested_call(
+            return Ok(exit_mocked_call(
+                entry_point,
+                ret_data,
+                runtime_state,
                 resources,
-                Default::default(),
-                CallResult::Success {
-                    ret_data: ret_data_f252,
-                },
-                &[],
-                None,
-            );
-            return Ok(mocked_call_info(entry_point.clone(), ret_data.clone()));
+            ));
         }
     }
+    if let Some(ret_data) = runtime_state
+        .cheatnet_state
+        .impersonated_validation_result(entry_point)
+    {
+        return Ok(exit_mocked_call(
+            entry_point,
+            ret_data,
+            runtime_state,
+            resources,
+        ));
+    }
     // endregion
 
     // Validate contract is deployed.
@@ -237,10 +265,15 @@ fn exit_error_call(
         CallType::Call => AddressOrClassHash::ContractAddress(entry_point.storage_address),
         CallType::Delegate => AddressOrClassHash::ClassHash(entry_point.class_hash.unwrap()),
     };
+    let result = CallResult::from_err(
+        error,
+        &identifier,
+        &runtime_state.cheatnet_state.contract_labels,
+    );
     runtime_state.cheatnet_state.trace_data.exit_nested_call(
         resources,
         Default::default(),
-        CallResult::from_err(error, &identifier),
+        result,
         &[],
         None,
     );
@@ -293,12 +326,48 @@ fn get_mocked_function_cheat_status<'a>(
         return None;
     }
 
+    let calldata_key = (call.entry_point_selector, call.calldata.0.to_vec());
+    let mocked_for_calldata = cheatnet_state
+        .calldata_mocked_functions
+        .get(&call.storage_address)
+        .and_then(|contract_functions| contract_functions.get(&calldata_key))
+        .is_some_and(|cheat_status| matches!(cheat_status, CheatStatus::Cheated(_, _)));
+    if mocked_for_calldata {
+        return cheatnet_state
+            .calldata_mocked_functions
+            .get_mut(&call.storage_address)
+            .and_then(|contract_functions| contract_functions.get_mut(&calldata_key));
+    }
+
     cheatnet_state
         .mocked_functions
         .get_mut(&call.storage_address)
         .and_then(|contract_functions| contract_functions.get_mut(&call.entry_point_selector))
 }
 
+/// Finishes a call which is not executed, with `ret_data` as its result
+fn exit_mocked_call(
+    entry_point: &CallEntryPoint,
+    ret_data: Vec<StarkFelt>,
+    runtime_state: &mut RuntimeState,
+    resources: &mut ExecutionResources,
+) -> CallInfo {
+    let ret_data_f252: Vec<Felt252> = ret_data
+        .iter()
+        .map(|datum| Felt252::from_(*datum))
+        .collect();
+    runtime_state.cheatnet_state.trace_data.exit_nested_call(
+        resources,
+        Default::default(),
+        CallResult::Success {
+            ret_data: ret_data_f252,
+        },
+        &[],
+        None,
+    );
+    mocked_call_info(entry_point.clone(), ret_data)
+}
+
 fn mocked_call_info(call: CallEntryPoint, ret_data: Vec<StarkFelt>) -> CallInfo {
     CallInfo {
         call,
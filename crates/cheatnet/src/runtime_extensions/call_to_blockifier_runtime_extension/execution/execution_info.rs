@@ -4,6 +4,8 @@ use cairo_vm::{
     vm::vm_core::VirtualMachine,
 };
 use conversions::{FromConv, IntoConv};
+use num_traits::ToPrimitive;
+use runtime::utils::{BufferReadError, BufferReadResult, BufferReader};
 
 use crate::{
     runtime_extensions::forge_runtime_extension::cheatcodes::spoof::TxInfoMock, state::CheatedData,
@@ -184,3 +186,97 @@ fn add_vec_memory_segment(
 
     (vector_start_ptr, vector_end_ptr)
 }
+
+/// Field of `TxInfo`, spans are stored in separate memory segments
+#[derive(Clone, Debug, PartialEq)]
+enum TxInfoField {
+    Felt(Felt252),
+    Span(Vec<Felt252>),
+}
+
+/// `ExecutionInfo` read from the response of a `get_execution_info` syscall mocked with `start_mock_syscall`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionInfoMock {
+    block_info: Vec<Felt252>,
+    tx_info: Vec<TxInfoField>,
+    /// Caller address, contract address and entry point selector
+    call_info: Vec<Felt252>,
+}
+
+impl ExecutionInfoMock {
+    /// Reads fields of `BlockInfo`, `TxInfo` and `ExecutionInfo` in order, with spans prefixed by their lengths
+    pub fn read(response: &[Felt252]) -> BufferReadResult<Self> {
+        let mut reader = BufferReader::new(response);
+
+        let block_info = reader.read_vec_body(3)?;
+        let tx_info = vec![
+            // version, account_contract_address, max_fee
+            TxInfoField::Felt(reader.read_felt()?),
+            TxInfoField::Felt(reader.read_felt()?),
+            TxInfoField::Felt(reader.read_felt()?),
+            // signature
+            TxInfoField::Span(reader.read_vec()?),
+            // transaction_hash, chain_id, nonce
+            TxInfoField::Felt(reader.read_felt()?),
+            TxInfoField::Felt(reader.read_felt()?),
+            TxInfoField::Felt(reader.read_felt()?),
+            // resource_bounds, each made of resource, max_amount and max_price_per_unit
+            TxInfoField::Span({
+                let count = reader
+                    .read_felt()?
+                    .to_usize()
+                    .ok_or(BufferReadError::ParseFailed)?;
+                reader.read_vec_body(count * 3)?
+            }),
+            // tip
+            TxInfoField::Felt(reader.read_felt()?),
+            // paymaster_data
+            TxInfoField::Span(reader.read_vec()?),
+            // nonce_data_availability_mode, fee_data_availability_mode
+            TxInfoField::Felt(reader.read_felt()?),
+            TxInfoField::Felt(reader.read_felt()?),
+            // account_deployment_data
+            TxInfoField::Span(reader.read_vec()?),
+        ];
+        let call_info = reader.read_vec_body(3)?;
+
+        if reader.idx != response.len() {
+            return Err(BufferReadError::ParseFailed);
+        }
+
+        Ok(Self {
+            block_info,
+            tx_info,
+            call_info,
+        })
+    }
+
+    /// Writes the execution info to new memory segments, returns the pointer to it
+    pub fn add_memory_segments(&self, vm: &mut VirtualMachine) -> Relocatable {
+        let ptr_block_info = vm.add_memory_segment();
+        let block_info: Vec<MaybeRelocatable> =
+            self.block_info.iter().map(MaybeRelocatable::from).collect();
+        vm.load_data(ptr_block_info, &block_info).unwrap();
+
+        let ptr_tx_info = vm.add_memory_segment();
+        let mut tx_info = vec![];
+        for field in &self.tx_info {
+            match field {
+                TxInfoField::Felt(felt) => tx_info.push(felt.into()),
+                TxInfoField::Span(span) => {
+                    let (start_ptr, end_ptr) = add_vec_memory_segment(span, vm);
+                    tx_info.push(start_ptr.into());
+                    tx_info.push(end_ptr.into());
+                }
+            }
+        }
+        vm.load_data(ptr_tx_info, &tx_info).unwrap();
+
+        let ptr_exec_info = vm.add_memory_segment();
+        let mut exec_info = vec![ptr_block_info.into(), ptr_tx_info.into()];
+        exec_info.extend(self.call_info.iter().map(MaybeRelocatable::from));
+        vm.load_data(ptr_exec_info, &exec_info).unwrap();
+
+        ptr_exec_info
+    }
+}
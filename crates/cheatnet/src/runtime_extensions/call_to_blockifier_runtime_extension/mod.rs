@@ -173,6 +173,9 @@ fn execute_syscall<Request: ExecuteCall + SyscallRequest>(
     let mut runtime_state = RuntimeState { cheatnet_state };
 
     let call_result = request.execute_call(syscall_handler, &mut runtime_state);
+    let call_result = runtime_state
+        .cheatnet_state
+        .apply_expected_revert(call_result)?;
     write_call_response(syscall_handler, vm, gas_counter, call_result)?;
     Ok(())
 }
@@ -1,6 +1,74 @@
 use cairo_felt::Felt252;
+use cairo_lang_runner::short_string::as_cairo_short_string;
 use conversions::byte_array::ByteArray;
+use num_traits::Zero;
 use regex::Regex;
+use runtime::utils::BufferReader;
+
+/// Single value of decoded panic data
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanicDataItem {
+    /// Felt which is a printable short string, e.g. a message passed to `assert`
+    ShortString(Felt252),
+    /// `ByteArray` serialized with the magic prefix, e.g. a message passed to `panic!`
+    String(String),
+    /// Any other felt, e.g. an error selector
+    Felt(Felt252),
+}
+
+impl PanicDataItem {
+    fn serialize(self) -> Vec<Felt252> {
+        match self {
+            PanicDataItem::ShortString(felt) => vec![Felt252::from(0), felt],
+            PanicDataItem::String(string) => {
+                let mut result = vec![Felt252::from(1)];
+                result.extend(ByteArray::from(string.as_str()).serialize_no_magic());
+                result
+            }
+            PanicDataItem::Felt(felt) => vec![Felt252::from(2), felt],
+        }
+    }
+}
+
+/// Splits panic data into short strings, `ByteArray`s and other felts
+#[must_use]
+pub fn decode_panic_data(panic_data: &[Felt252]) -> Vec<PanicDataItem> {
+    let mut reader = BufferReader::new(panic_data);
+    let mut items = vec![];
+
+    loop {
+        // Reading a string does not move the reader if the data is not a `ByteArray`
+        if let Ok(string) = reader.read_string() {
+            items.push(PanicDataItem::String(string));
+            continue;
+        }
+        let Ok(felt) = reader.read_felt() else {
+            break;
+        };
+
+        let is_short_string = !felt.is_zero()
+            && as_cairo_short_string(&felt).is_some_and(|string| {
+                string
+                    .chars()
+                    .all(|char| char.is_ascii_graphic() || char == ' ')
+            });
+        items.push(if is_short_string {
+            PanicDataItem::ShortString(felt)
+        } else {
+            PanicDataItem::Felt(felt)
+        });
+    }
+
+    items
+}
+
+/// Serializes decoded panic data as `Array<PanicDataItem>` of `snforge_std`
+#[must_use]
+pub fn serialize_panic_data_items(items: Vec<PanicDataItem>) -> Vec<Felt252> {
+    let mut result = vec![Felt252::from(items.len())];
+    result.extend(items.into_iter().flat_map(PanicDataItem::serialize));
+    result
+}
 
 #[must_use]
 pub fn try_extract_panic_data(err: &str) -> Option<Vec<Felt252>> {
@@ -130,4 +198,54 @@ mod test {
             assert_eq!(try_extract_panic_data(str), expected);
         }
     }
+
+    #[test]
+    fn decoding_panic_data() {
+        let selector = Felt252::try_from_hex_str(
+            "0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad",
+        )
+        .unwrap();
+        let mut panic_data = vec![
+            Felt252::from_short_string("Not owner").unwrap(),
+            selector.clone(),
+            Felt252::from(0),
+        ];
+        panic_data.extend(ByteArray::from("Amount too large").serialize_with_magic());
+        panic_data.push(Felt252::from(10));
+
+        assert_eq!(
+            decode_panic_data(&panic_data),
+            vec![
+                PanicDataItem::ShortString(Felt252::from_short_string("Not owner").unwrap()),
+                PanicDataItem::Felt(selector),
+                PanicDataItem::Felt(Felt252::from(0)),
+                PanicDataItem::String("Amount too large".to_string()),
+                PanicDataItem::Felt(Felt252::from(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializing_panic_data_items() {
+        let items = vec![
+            PanicDataItem::ShortString(Felt252::from_short_string("Not owner").unwrap()),
+            PanicDataItem::String("abc".to_string()),
+            PanicDataItem::Felt(Felt252::from(10)),
+        ];
+
+        assert_eq!(
+            serialize_panic_data_items(items),
+            vec![
+                Felt252::from(3),
+                Felt252::from(0),
+                Felt252::from_short_string("Not owner").unwrap(),
+                Felt252::from(1),
+                Felt252::from(0),
+                Felt252::from_short_string("abc").unwrap(),
+                Felt252::from(3),
+                Felt252::from(2),
+                Felt252::from(10),
+            ]
+        );
+    }
 }
@@ -22,6 +22,7 @@ use starknet_api::{
     core::{ClassHash, ContractAddress},
     deprecated_contract_class::EntryPointType,
 };
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Default)]
 pub struct UsedResources {
@@ -53,12 +54,26 @@ pub enum AddressOrClassHash {
     ClassHash(ClassHash),
 }
 
+/// Formats `contract_address` as `Label (0x...)` if a label was attached to it with the `label` cheatcode
+#[must_use]
+pub fn format_contract_address(
+    contract_address: &ContractAddress,
+    contract_labels: &HashMap<ContractAddress, String>,
+) -> String {
+    let address = contract_address.0.key().to_string();
+    match contract_labels.get(contract_address) {
+        Some(label) => format!("{label} ({address})"),
+        None => address,
+    }
+}
+
 impl CallFailure {
     /// Maps blockifier-type error, to one that can be put into memory as panic-data (or re-raised)
     #[must_use]
     pub fn from_execution_error(
         err: &EntryPointExecutionError,
         starknet_identifier: &AddressOrClassHash,
+        contract_labels: &HashMap<ContractAddress, String>,
     ) -> Self {
         match err {
             EntryPointExecutionError::ExecutionFailed { error_data } => {
@@ -108,7 +123,7 @@ impl CallFailure {
                 let msg = match starknet_identifier {
                     AddressOrClassHash::ContractAddress(address) => format!(
                         "Entry point selector {selector_hash} not found in contract {}",
-                        address.0.key()
+                        format_contract_address(address, contract_labels)
                     ),
                     AddressOrClassHash::ClassHash(class_hash) => format!(
                         "Entry point selector {selector_hash} not found for class hash {class_hash}"
@@ -125,7 +140,7 @@ impl CallFailure {
             EntryPointExecutionError::PreExecutionError(
                 PreExecutionError::UninitializedStorageAddress(contract_address),
             ) => {
-                let address = contract_address.0.key().to_string();
+                let address = format_contract_address(contract_address, contract_labels);
                 let msg = format!("Contract not deployed at address: {address}");
                 CallFailure::Error { msg }
             }
@@ -144,10 +159,11 @@ impl CallResult {
     pub fn from_execution_result(
         result: &EntryPointExecutionResult<CallInfo>,
         starknet_identifier: &AddressOrClassHash,
+        contract_labels: &HashMap<ContractAddress, String>,
     ) -> Self {
         match result {
             Ok(call_info) => Self::from_success(call_info),
-            Err(err) => Self::from_err(err, starknet_identifier),
+            Err(err) => Self::from_err(err, starknet_identifier, contract_labels),
         }
     }
 
@@ -166,8 +182,13 @@ impl CallResult {
     pub fn from_err(
         err: &EntryPointExecutionError,
         starknet_identifier: &AddressOrClassHash,
+        contract_labels: &HashMap<ContractAddress, String>,
     ) -> Self {
-        CallResult::Failure(CallFailure::from_execution_error(err, starknet_identifier))
+        CallResult::Failure(CallFailure::from_execution_error(
+            err,
+            starknet_identifier,
+            contract_labels,
+        ))
     }
 }
 
@@ -214,7 +235,11 @@ pub fn call_entry_point(
         syscall_handler.context,
     );
 
-    let result = CallResult::from_execution_result(&exec_result, starknet_identifier);
+    let result = CallResult::from_execution_result(
+        &exec_result,
+        starknet_identifier,
+        &runtime_state.cheatnet_state.contract_labels,
+    );
 
     if let Ok(call_info) = exec_result {
         syscall_handler.inner_calls.push(call_info);
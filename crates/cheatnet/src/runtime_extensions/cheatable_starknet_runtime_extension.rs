@@ -1,11 +1,15 @@
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::{
-    cheated_syscalls, syscall_hooks,
+    cheated_syscalls::{self, MockedSyscallResponse},
+    execution_info::ExecutionInfoMock,
+    syscall_hooks,
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_syscall::SyscallMock;
 use crate::state::CheatnetState;
 use anyhow::Result;
 use blockifier::execution::entry_point::EntryPointExecutionContext;
 use blockifier::execution::syscalls::hint_processor::OUT_OF_GAS_ERROR;
 use blockifier::execution::syscalls::{
+    EmptyRequest, GetBlockHashRequest, GetExecutionInfoResponse, KeccakRequest, StorageReadRequest,
     SyscallRequest, SyscallRequestWrapper, SyscallResponse, SyscallResponseWrapper, SyscallResult,
 };
 use blockifier::execution::{
@@ -46,6 +50,15 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
     ) -> Result<SyscallHandlingResult, HintError> {
         let syscall_handler = &mut extended_runtime.hint_handler;
 
+        if let Some(mock) = self
+            .cheatnet_state
+            .get_mocked_syscall(&syscall_handler.storage_address(), selector)
+        {
+            return self
+                .execute_mocked_syscall(syscall_handler, vm, selector, mock)
+                .map(|()| SyscallHandlingResult::Handled(()));
+        }
+
         match selector {
             SyscallSelector::GetExecutionInfo => self
                 .execute_syscall(
@@ -80,6 +93,30 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
                     SyscallSelector::Deploy,
                 )
                 .map(|()| SyscallHandlingResult::Handled(())),
+            SyscallSelector::GetBlockHash => self
+                .execute_syscall(
+                    syscall_handler,
+                    vm,
+                    cheated_syscalls::get_block_hash_syscall,
+                    SyscallSelector::GetBlockHash,
+                )
+                .map(|()| SyscallHandlingResult::Handled(())),
+            SyscallSelector::StorageRead => self
+                .execute_syscall(
+                    syscall_handler,
+                    vm,
+                    cheated_syscalls::storage_read_syscall,
+                    SyscallSelector::StorageRead,
+                )
+                .map(|()| SyscallHandlingResult::Handled(())),
+            SyscallSelector::StorageWrite => self
+                .execute_syscall(
+                    syscall_handler,
+                    vm,
+                    cheated_syscalls::storage_write_syscall,
+                    SyscallSelector::StorageWrite,
+                )
+                .map(|()| SyscallHandlingResult::Handled(())),
             _ => Ok(SyscallHandlingResult::Forwarded),
         }
     }
@@ -90,8 +127,14 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
         extended_runtime: &mut Self::Runtime,
     ) {
         let syscall_handler = &extended_runtime.hint_handler;
-        if let SyscallSelector::EmitEvent = selector {
-            syscall_hooks::emit_event_hook(syscall_handler, self.cheatnet_state);
+        match selector {
+            SyscallSelector::EmitEvent => {
+                syscall_hooks::emit_event_hook(syscall_handler, self.cheatnet_state);
+            }
+            SyscallSelector::SendMessageToL1 => {
+                syscall_hooks::send_message_to_l1_hook(syscall_handler, self.cheatnet_state);
+            }
+            _ => {}
         }
     }
 }
@@ -120,12 +163,70 @@ fn get_syscall_cost(
         SyscallSelector::CallContract => "call_contract_gas_cost",
         SyscallSelector::Deploy => "deploy_gas_cost",
         SyscallSelector::GetExecutionInfo => "get_execution_info_gas_cost",
+        SyscallSelector::GetBlockHash => "get_block_hash_gas_cost",
+        SyscallSelector::Keccak => "keccak_gas_cost",
+        SyscallSelector::StorageRead => "storage_read_gas_cost",
         _ => unreachable!("Syscall has no associated cost"),
     };
     context.get_gas_cost(constant_name)
 }
 
 impl CheatableStarknetRuntimeExtension<'_> {
+    /// Reads the request of a syscall mocked with `start_mock_syscall`
+    /// and writes the mocked result instead of executing it
+    fn execute_mocked_syscall(
+        &mut self,
+        syscall_handler: &mut SyscallHintProcessor,
+        vm: &mut VirtualMachine,
+        selector: SyscallSelector,
+        mock: SyscallMock,
+    ) -> HintExecutionResult {
+        match selector {
+            SyscallSelector::GetBlockHash => self.execute_syscall(
+                syscall_handler,
+                vm,
+                |request: GetBlockHashRequest, _, syscall_handler, runtime_state, _| {
+                    // Block hash mocked for the specific block number takes precedence
+                    match runtime_state.cheatnet_state.get_mocked_block_hash(
+                        &syscall_handler.storage_address(),
+                        request.block_number.0,
+                    ) {
+                        Some(block_hash) => Ok(MockedSyscallResponse(vec![block_hash])),
+                        None => mock.into_result(),
+                    }
+                },
+                selector,
+            ),
+            SyscallSelector::GetExecutionInfo => self.execute_syscall(
+                syscall_handler,
+                vm,
+                |_: EmptyRequest, vm, _, _, _| {
+                    mock.into_result().map(|MockedSyscallResponse(response)| {
+                        let execution_info = ExecutionInfoMock::read(&response)
+                            .expect("Mocked execution info is validated in start_mock_syscall");
+                        GetExecutionInfoResponse {
+                            execution_info_ptr: execution_info.add_memory_segments(vm),
+                        }
+                    })
+                },
+                selector,
+            ),
+            SyscallSelector::Keccak => self.execute_syscall(
+                syscall_handler,
+                vm,
+                |_: KeccakRequest, _, _, _, _| mock.into_result(),
+                selector,
+            ),
+            SyscallSelector::StorageRead => self.execute_syscall(
+                syscall_handler,
+                vm,
+                |_: StorageReadRequest, _, _, _, _| mock.into_result(),
+                selector,
+            ),
+            _ => unreachable!("Syscall {selector:?} can't be mocked"),
+        }
+    }
+
     // crates/blockifier/src/execution/syscalls/hint_processor.rs:280 (SyscallHintProcessor::execute_syscall)
     fn execute_syscall<Request, Response, ExecuteCallback>(
         &mut self,
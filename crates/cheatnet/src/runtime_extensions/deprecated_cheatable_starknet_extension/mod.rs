@@ -4,16 +4,17 @@ use blockifier::execution::deprecated_syscalls::hint_processor::{
     DeprecatedSyscallExecutionError, DeprecatedSyscallHintProcessor,
 };
 use blockifier::execution::deprecated_syscalls::{
-    CallContractRequest, DeployRequest, DeployResponse, DeprecatedSyscallResult,
+    self, CallContractRequest, DeployRequest, DeployResponse, DeprecatedSyscallResult,
     DeprecatedSyscallSelector, GetBlockNumberResponse, GetBlockTimestampResponse,
-    GetContractAddressResponse, LibraryCallRequest, SyscallRequest, SyscallResponse,
+    GetContractAddressResponse, LibraryCallRequest, StorageReadRequest, StorageReadResponse,
+    StorageWriteRequest, StorageWriteResponse, SyscallRequest, SyscallResponse,
     WriteResponseResult,
 };
 use blockifier::execution::entry_point::{CallEntryPoint, CallType, ConstructorContext};
 use blockifier::execution::execution_utils::{
     execute_deployment, write_maybe_relocatable, ReadOnlySegment,
 };
-use conversions::FromConv;
+use conversions::{FromConv, IntoConv};
 
 use ::runtime::SyscallHandlingResult;
 use cairo_felt::Felt252;
@@ -35,6 +36,7 @@ use self::runtime::{
 use super::call_to_blockifier_runtime_extension::execution::entry_point::execute_call_entry_point;
 use super::call_to_blockifier_runtime_extension::execution::syscall_hooks;
 use super::call_to_blockifier_runtime_extension::RuntimeState;
+use super::forge_runtime_extension::cheatcodes::storage::StorageAccess;
 
 pub mod runtime;
 
@@ -156,6 +158,20 @@ impl<'a> DeprecatedExtensionLogic for DeprecatedCheatableStarknetRuntimeExtensio
                 self.execute_syscall(vm, deploy, syscall_handler)?;
                 Ok(SyscallHandlingResult::Handled(()))
             }
+            DeprecatedSyscallSelector::StorageRead => {
+                syscall_handler.syscall_ptr += 1;
+                increment_syscall_count(syscall_handler, selector);
+
+                self.execute_syscall(vm, storage_read, syscall_handler)?;
+                Ok(SyscallHandlingResult::Handled(()))
+            }
+            DeprecatedSyscallSelector::StorageWrite => {
+                syscall_handler.syscall_ptr += 1;
+                increment_syscall_count(syscall_handler, selector);
+
+                self.execute_syscall(vm, storage_write, syscall_handler)?;
+                Ok(SyscallHandlingResult::Handled(()))
+            }
             _ => Ok(SyscallHandlingResult::Forwarded),
         }
     }
@@ -166,8 +182,14 @@ impl<'a> DeprecatedExtensionLogic for DeprecatedCheatableStarknetRuntimeExtensio
         extended_runtime: &mut Self::Runtime,
     ) {
         let syscall_handler = &extended_runtime.hint_handler;
-        if let DeprecatedSyscallSelector::EmitEvent = selector {
-            syscall_hooks::emit_event_hook(syscall_handler, self.cheatnet_state);
+        match selector {
+            DeprecatedSyscallSelector::EmitEvent => {
+                syscall_hooks::emit_event_hook(syscall_handler, self.cheatnet_state);
+            }
+            DeprecatedSyscallSelector::SendMessageToL1 => {
+                syscall_hooks::send_message_to_l1_hook(syscall_handler, self.cheatnet_state);
+            }
+            _ => {}
         }
     }
 }
@@ -335,6 +357,44 @@ pub fn library_call(
     })
 }
 
+// blockifier/src/execution/deprecated_syscalls/mod.rs:711 (storage_read)
+pub fn storage_read(
+    request: StorageReadRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut DeprecatedSyscallHintProcessor<'_>,
+    cheatnet_state: &mut CheatnetState,
+) -> DeprecatedSyscallResult<StorageReadResponse> {
+    let key = request.address;
+    let response = deprecated_syscalls::storage_read(request, vm, syscall_handler)?;
+
+    cheatnet_state.record_storage_access(StorageAccess {
+        contract_address: syscall_handler.storage_address,
+        key: (*key.0.key()).into_(),
+        is_write: false,
+        value: response.value.into_(),
+    });
+    Ok(response)
+}
+
+// blockifier/src/execution/deprecated_syscalls/mod.rs:740 (storage_write)
+pub fn storage_write(
+    request: StorageWriteRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut DeprecatedSyscallHintProcessor<'_>,
+    cheatnet_state: &mut CheatnetState,
+) -> DeprecatedSyscallResult<StorageWriteResponse> {
+    let storage_access = StorageAccess {
+        contract_address: syscall_handler.storage_address,
+        key: (*request.address.0.key()).into_(),
+        is_write: true,
+        value: request.value.into_(),
+    };
+    let response = deprecated_syscalls::storage_write(request, vm, syscall_handler)?;
+
+    cheatnet_state.record_storage_access(storage_access);
+    Ok(response)
+}
+
 // blockifier/src/execution/deprecated_syscalls/mod.rs:426 (get_caller_address)
 pub fn get_caller_address(
     syscall_handler: &mut DeprecatedCheatableStarknetRuntimeExtension<'_>,
@@ -3,13 +3,18 @@ use crate::runtime_extensions::forge_runtime_extension::{
     contracts_data::ContractsData,
 };
 use anyhow::{anyhow, Context, Result};
-use blockifier::{
-    execution::contract_class::{ContractClass as BlockifierContractClass, ContractClassV1},
-    state::{errors::StateError, state_api::State},
-};
+use blockifier::execution::contract_class::{ContractClass, ContractClassV0, ContractClassV1};
+use blockifier::state::{errors::StateError, state_api::State};
+use cairo_lang_starknet_classes::casm_contract_class::CasmContractClass;
+use camino::Utf8Path;
 use conversions::IntoConv;
+use serde_json::Value;
+use starknet::core::types::contract::legacy::LegacyContractClass;
 use starknet::core::types::contract::SierraClass;
 use starknet_api::core::ClassHash;
+use starknet_api::deprecated_contract_class::ContractClass as DeprecatedContractClass;
+use std::fs;
+use universal_sierra_compiler_api::{compile_sierra, SierraType};
 
 #[allow(clippy::implicit_hasher)]
 pub fn declare(
@@ -17,19 +22,70 @@ pub fn declare(
     contract_name: &str,
     contracts_data: &ContractsData,
 ) -> Result<ClassHash, CheatcodeError> {
-    let contract_artifact = contracts_data.contracts.get(contract_name).with_context(|| {
-            format!("Failed to get contract artifact for name = {contract_name}. Make sure starknet target is correctly defined in Scarb.toml file.")
-        }).map_err::<EnhancedHintError, _>(From::from)?;
-
-    let contract_class = ContractClassV1::try_from_json_string(&contract_artifact.casm)
-        .expect("Failed to read contract class from json");
-    let contract_class = BlockifierContractClass::V1(contract_class);
+    let contract_class = contracts_data
+        .get_compiled_contract_class(contract_name)
+        .map_err::<EnhancedHintError, _>(From::from)?;
 
     let class_hash = *contracts_data
         .class_hashes
         .get_by_left(contract_name)
         .expect("Failed to get class hash");
 
+    declare_contract_class(state, class_hash, contract_class)
+}
+
+/// Declares a Cairo 0 (legacy) contract class from a compiled contract at `path`
+pub fn declare_legacy(state: &mut dyn State, path: &Utf8Path) -> Result<ClassHash, CheatcodeError> {
+    let (class_hash, contract_class) = load_legacy_contract_class(path)
+        .with_context(|| format!("Failed to load Cairo 0 contract class from {path}"))
+        .map_err::<EnhancedHintError, _>(From::from)?;
+
+    declare_contract_class(state, class_hash, ContractClass::V0(contract_class))
+}
+
+fn load_legacy_contract_class(path: &Utf8Path) -> Result<(ClassHash, ContractClassV0)> {
+    let artifact = fs::read_to_string(path)?;
+
+    let legacy_class: LegacyContractClass = serde_json::from_str(&artifact)?;
+    let class_hash = legacy_class.class_hash()?.into_();
+
+    let deprecated_class: DeprecatedContractClass = serde_json::from_str(&artifact)?;
+    let contract_class = ContractClassV0::try_from(deprecated_class)?;
+
+    Ok((class_hash, contract_class))
+}
+
+/// Declares a Sierra contract class from a `*.contract_class.json` artifact at `path`
+pub fn declare_from_file(
+    state: &mut dyn State,
+    path: &Utf8Path,
+) -> Result<ClassHash, CheatcodeError> {
+    let (class_hash, contract_class) = load_sierra_contract_class(path)
+        .with_context(|| format!("Failed to load Sierra contract class from {path}"))
+        .map_err::<EnhancedHintError, _>(From::from)?;
+
+    declare_contract_class(state, class_hash, ContractClass::V1(contract_class))
+}
+
+fn load_sierra_contract_class(path: &Utf8Path) -> Result<(ClassHash, ContractClassV1)> {
+    let artifact = fs::read_to_string(path)?;
+
+    let sierra_class: SierraClass = serde_json::from_str(&artifact)?;
+    let class_hash = get_class_hash(&sierra_class)?;
+
+    let sierra_json: Value = serde_json::from_str(&artifact)?;
+    let casm = compile_sierra(&sierra_json, None, &SierraType::Contract)?;
+    let casm_class: CasmContractClass = serde_json::from_str(&casm)?;
+    let contract_class = ContractClassV1::try_from(casm_class)?;
+
+    Ok((class_hash, contract_class))
+}
+
+fn declare_contract_class(
+    state: &mut dyn State,
+    class_hash: ClassHash,
+    contract_class: ContractClass,
+) -> Result<ClassHash, CheatcodeError> {
     match state.get_compiled_contract_class(class_hash) {
         Err(StateError::UndeclaredClassHash(_)) => {
             // Class is undeclared; declare it.
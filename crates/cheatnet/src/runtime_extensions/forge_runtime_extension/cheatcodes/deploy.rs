@@ -63,6 +63,7 @@ pub fn deploy_at(
         let call_contract_failure = CallFailure::from_execution_error(
             err,
             &AddressOrClassHash::ContractAddress(contract_address),
+            &runtime_state.cheatnet_state.contract_labels,
         );
         CheatcodeError::from(call_contract_failure)
     })?;
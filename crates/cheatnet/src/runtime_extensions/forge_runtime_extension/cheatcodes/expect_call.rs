@@ -0,0 +1,94 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::format_contract_address;
+use crate::CheatnetState;
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::execution::execution_utils::felt_to_stark_felt;
+use cairo_felt::Felt252;
+use conversions::IntoConv;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_api::hash::StarkFelt;
+use starknet_api::transaction::Calldata;
+
+/// Call which has to be made before the test finishes, registered with `expect_call`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectedCall {
+    pub contract_address: ContractAddress,
+    pub function_selector: EntryPointSelector,
+    /// Calldata the call has to be made with, any calldata matches if not set
+    pub calldata: Option<Vec<StarkFelt>>,
+    pub fulfilled: bool,
+}
+
+impl ExpectedCall {
+    fn matches(
+        &self,
+        contract_address: ContractAddress,
+        selector: EntryPointSelector,
+        calldata: &Calldata,
+    ) -> bool {
+        self.contract_address == contract_address
+            && self.function_selector == selector
+            && self
+                .calldata
+                .as_ref()
+                .map_or(true, |expected| expected == calldata.0.as_ref())
+    }
+}
+
+impl CheatnetState {
+    pub fn expect_call(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: Felt252,
+        calldata: Option<&[Felt252]>,
+    ) {
+        self.expected_calls.push(ExpectedCall {
+            contract_address,
+            function_selector: EntryPointSelector(function_selector.into_()),
+            calldata: calldata.map(|calldata| calldata.iter().map(felt_to_stark_felt).collect()),
+            fulfilled: false,
+        });
+    }
+
+    /// Marks expectations matching the `call` as fulfilled, library calls are not matched
+    pub fn register_expected_call(&mut self, call: &CallEntryPoint) {
+        if call.call_type == CallType::Delegate {
+            return;
+        }
+        for expected_call in &mut self.expected_calls {
+            if expected_call.matches(
+                call.storage_address,
+                call.entry_point_selector,
+                &call.calldata,
+            ) {
+                expected_call.fulfilled = true;
+            }
+        }
+    }
+
+    /// Returns failure messages of expectations which were not fulfilled
+    #[must_use]
+    pub fn unmet_expected_calls(&self) -> Vec<String> {
+        self.expected_calls
+            .iter()
+            .filter(|expected_call| !expected_call.fulfilled)
+            .map(|expected_call| {
+                let contract_address =
+                    format_contract_address(&expected_call.contract_address, &self.contract_labels);
+                let selector = expected_call.function_selector.0;
+                match &expected_call.calldata {
+                    Some(calldata) => {
+                        let calldata: Vec<String> =
+                            calldata.iter().map(ToString::to_string).collect();
+                        format!(
+                            "Expected call to {selector} of {contract_address} with calldata [{}] was not made",
+                            calldata.join(", ")
+                        )
+                    }
+                    None => {
+                        format!("Expected call to {selector} of {contract_address} was not made")
+                    }
+                }
+            })
+            .collect()
+    }
+}
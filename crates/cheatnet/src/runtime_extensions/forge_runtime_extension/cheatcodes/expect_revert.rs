@@ -0,0 +1,55 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    CallFailure, CallResult,
+};
+use crate::CheatnetState;
+use cairo_felt::Felt252;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_vm::vm::errors::hint_errors::HintError;
+
+impl CheatnetState {
+    /// Expects the next call made from the test to panic with `panic_data`
+    pub fn expect_revert(&mut self, panic_data: Vec<Felt252>) {
+        self.expected_revert = Some(panic_data);
+    }
+
+    /// Checks the result of a call made from the test against the expected revert, if there is one
+    ///
+    /// A call panicking with the expected data is returned unchanged, so the test continues with the failed call.
+    /// A successful call or a call panicking with other data fails the test.
+    pub fn apply_expected_revert(
+        &mut self,
+        call_result: CallResult,
+    ) -> Result<CallResult, HintError> {
+        let Some(expected_panic_data) = self.expected_revert.take() else {
+            return Ok(call_result);
+        };
+
+        match call_result {
+            CallResult::Failure(CallFailure::Panic { ref panic_data })
+                if *panic_data == expected_panic_data =>
+            {
+                Ok(call_result)
+            }
+            CallResult::Failure(CallFailure::Panic { panic_data }) => {
+                Err(HintError::CustomHint(Box::from(format!(
+                    "Expected revert with data = [{}], call reverted with data = [{}]",
+                    format_panic_data(&expected_panic_data),
+                    format_panic_data(&panic_data)
+                ))))
+            }
+            CallResult::Success { .. } => Err(HintError::CustomHint(Box::from(format!(
+                "Expected revert did not occur, expected data = [{}]",
+                format_panic_data(&expected_panic_data)
+            )))),
+            call_result @ CallResult::Failure(CallFailure::Error { .. }) => Ok(call_result),
+        }
+    }
+}
+
+fn format_panic_data(panic_data: &[Felt252]) -> String {
+    panic_data
+        .iter()
+        .map(|felt| as_cairo_short_string(felt).unwrap_or_else(|| felt.to_string()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
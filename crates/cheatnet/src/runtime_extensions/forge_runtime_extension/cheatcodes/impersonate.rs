@@ -0,0 +1,56 @@
+use crate::CheatnetState;
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::execution::execution_utils::felt_to_stark_felt;
+use cairo_felt::Felt252;
+use conversions::IntoConv;
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_api::hash::StarkFelt;
+
+/// Entry points of account contracts validating signatures, not executed for impersonated accounts
+const VALIDATION_ENTRY_POINTS: [&str; 5] = [
+    "__validate__",
+    "__validate_declare__",
+    "__validate_deploy__",
+    "is_valid_signature",
+    "isValidSignature",
+];
+
+fn is_entry_point(call: &CallEntryPoint, name: &str) -> bool {
+    let selector: EntryPointSelector = get_selector_from_name(name).unwrap().into_();
+    call.entry_point_selector == selector
+}
+
+impl CheatnetState {
+    pub fn start_impersonate(&mut self, account: ContractAddress) {
+        self.impersonated_accounts.insert(account);
+    }
+
+    pub fn stop_impersonate(&mut self, account: &ContractAddress) {
+        self.impersonated_accounts.remove(account);
+    }
+
+    fn is_impersonated(&self, call: &CallEntryPoint) -> bool {
+        call.call_type != CallType::Delegate
+            && self.impersonated_accounts.contains(&call.storage_address)
+    }
+
+    /// Returns the `'VALID'` result of a call to a validation entry point of an impersonated account,
+    /// `None` if the call has to be executed
+    #[must_use]
+    pub fn impersonated_validation_result(&self, call: &CallEntryPoint) -> Option<Vec<StarkFelt>> {
+        let is_validation = self.is_impersonated(call)
+            && VALIDATION_ENTRY_POINTS
+                .iter()
+                .any(|name| is_entry_point(call, name));
+
+        is_validation.then(|| vec![felt_to_stark_felt(&Felt252::from_bytes_be(b"VALID"))])
+    }
+
+    /// Checks if the call is made to `__execute__` of an impersonated account,
+    /// such calls see zero caller address like ones made by the protocol
+    #[must_use]
+    pub fn is_impersonated_execute(&self, call: &CallEntryPoint) -> bool {
+        self.is_impersonated(call) && is_entry_point(call, "__execute__")
+    }
+}
@@ -0,0 +1,92 @@
+use crate::CheatnetState;
+use blockifier::execution::syscalls::hint_processor::SyscallCounter;
+use cairo_felt::Felt252;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use conversions::felt252::FromShortString;
+
+/// Resources used during the test up to some point, taken by `start_measure` and `stop_measure`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ResourcesSnapshot {
+    pub execution_resources: ExecutionResources,
+    pub syscall_counter: SyscallCounter,
+}
+
+impl ResourcesSnapshot {
+    /// Returns resources used between `start` and `self`
+    #[must_use]
+    pub fn since(&self, start: &ResourcesSnapshot) -> ResourcesSnapshot {
+        let execution_resources = &self.execution_resources - &start.execution_resources;
+        let syscall_counter = self
+            .syscall_counter
+            .iter()
+            .map(|(selector, count)| {
+                let start_count = start.syscall_counter.get(selector).unwrap_or(&0);
+                (*selector, count - start_count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        ResourcesSnapshot {
+            execution_resources,
+            syscall_counter,
+        }
+    }
+
+    /// Serializes the snapshot as `steps, memory_holes, builtins, syscalls`,
+    /// builtins and syscalls are arrays of `(name, count)` pairs sorted by name
+    #[must_use]
+    pub fn serialize(&self) -> Vec<Felt252> {
+        let builtins = self
+            .execution_resources
+            .builtin_instance_counter
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+
+        let mut result = vec![
+            Felt252::from(self.execution_resources.n_steps),
+            Felt252::from(self.execution_resources.n_memory_holes),
+        ];
+        result.extend(serialize_counters(builtins));
+        result.extend(self.serialize_syscalls());
+        result
+    }
+
+    /// Serializes syscalls as an array of `(name, count)` pairs sorted by name
+    #[must_use]
+    pub fn serialize_syscalls(&self) -> Vec<Felt252> {
+        serialize_counters(
+            self.syscall_counter
+                .iter()
+                .map(|(selector, count)| (format!("{selector:?}"), *count))
+                .collect(),
+        )
+    }
+}
+
+fn serialize_counters(mut counters: Vec<(String, usize)>) -> Vec<Felt252> {
+    counters.sort();
+
+    let mut result = vec![Felt252::from(counters.len())];
+    for (name, count) in counters {
+        result.push(Felt252::from_short_string(&name).expect("Should convert name to Felt252"));
+        result.push(Felt252::from(count));
+    }
+    result
+}
+
+impl CheatnetState {
+    /// Starts measuring resources, replacing the previous measurement if there was one
+    pub fn start_measure(&mut self, snapshot: ResourcesSnapshot) {
+        self.measure_start = Some(snapshot);
+    }
+
+    /// Stops measuring resources, returns resources used since `start_measure`
+    /// or `None` if no measurement was started
+    pub fn stop_measure(&mut self, snapshot: &ResourcesSnapshot) -> Option<ResourcesSnapshot> {
+        self.measure_start
+            .take()
+            .map(|start| snapshot.since(&start))
+    }
+}
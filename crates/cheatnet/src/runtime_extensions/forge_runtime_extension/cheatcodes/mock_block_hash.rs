@@ -0,0 +1,31 @@
+use crate::state::{start_cheat, stop_cheat, CheatSpan, CheatTarget};
+use crate::CheatnetState;
+use cairo_felt::Felt252;
+
+impl CheatnetState {
+    pub fn start_mock_block_hash(
+        &mut self,
+        target: CheatTarget,
+        block_number: u64,
+        block_hash: Felt252,
+    ) {
+        let (global_block_hash, contracts_block_hashes) =
+            self.mocked_block_hashes.entry(block_number).or_default();
+
+        start_cheat(
+            global_block_hash,
+            contracts_block_hashes,
+            target,
+            block_hash,
+            CheatSpan::Indefinite,
+        );
+    }
+
+    pub fn stop_mock_block_hash(&mut self, target: CheatTarget, block_number: u64) {
+        if let Some((global_block_hash, contracts_block_hashes)) =
+            self.mocked_block_hashes.get_mut(&block_number)
+        {
+            stop_cheat(global_block_hash, contracts_block_hashes, target);
+        }
+    }
+}
@@ -49,4 +49,42 @@ impl CheatnetState {
             contract_mocked_functions.remove(&function_selector.into_());
         }
     }
+
+    /// Mocks calls to `function_selector` made only with exactly `calldata`,
+    /// calls with other calldata are handled by `mock_call` mocks or the real implementation
+    pub fn mock_call_when(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: Felt252,
+        calldata: &[Felt252],
+        ret_data: &[Felt252],
+        span: CheatSpan,
+    ) {
+        let calldata: Vec<StarkFelt> = calldata.iter().map(felt_to_stark_felt).collect();
+        let ret_data: Vec<StarkFelt> = ret_data.iter().map(felt_to_stark_felt).collect();
+
+        let contract_mocked_functions = self
+            .calldata_mocked_functions
+            .entry(contract_address)
+            .or_default();
+
+        contract_mocked_functions.insert(
+            (EntryPointSelector(function_selector.into_()), calldata),
+            CheatStatus::Cheated(ret_data, span),
+        );
+    }
+
+    pub fn stop_mock_call_when(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: Felt252,
+        calldata: &[Felt252],
+    ) {
+        if let Entry::Occupied(mut e) = self.calldata_mocked_functions.entry(contract_address) {
+            let calldata: Vec<StarkFelt> = calldata.iter().map(felt_to_stark_felt).collect();
+            let contract_mocked_functions = e.get_mut();
+            contract_mocked_functions
+                .remove(&(EntryPointSelector(function_selector.into_()), calldata));
+        }
+    }
 }
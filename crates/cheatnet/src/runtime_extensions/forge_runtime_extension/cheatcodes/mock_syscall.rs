@@ -0,0 +1,87 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::cheated_syscalls::MockedSyscallResponse;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::execution_info::ExecutionInfoMock;
+use crate::runtime_extensions::cheatable_starknet_runtime_extension::SyscallSelector;
+use crate::state::{start_cheat, stop_cheat, CheatSpan, CheatTarget};
+use crate::CheatnetState;
+use blockifier::execution::execution_utils::felt_to_stark_felt;
+use blockifier::execution::syscalls::hint_processor::SyscallExecutionError;
+use blockifier::execution::syscalls::SyscallResult;
+use cairo_felt::Felt252;
+
+/// Result returned from a mocked syscall instead of executing it
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyscallMock {
+    /// Felts of the syscall response
+    Response(Vec<Felt252>),
+    /// Error data the syscall fails with
+    Failure(Vec<Felt252>),
+}
+
+impl SyscallMock {
+    pub fn into_result(self) -> SyscallResult<MockedSyscallResponse> {
+        match self {
+            SyscallMock::Response(response) => Ok(MockedSyscallResponse(response)),
+            SyscallMock::Failure(error_data) => Err(SyscallExecutionError::SyscallError {
+                error_data: error_data.iter().map(felt_to_stark_felt).collect(),
+            }),
+        }
+    }
+}
+
+/// Syscalls which can be mocked, in order of `MockableSyscall` variants
+pub const MOCKABLE_SYSCALLS: [SyscallSelector; 4] = [
+    SyscallSelector::GetBlockHash,
+    SyscallSelector::Keccak,
+    SyscallSelector::StorageRead,
+    SyscallSelector::GetExecutionInfo,
+];
+
+/// Checks if `response` can be returned from the mocked `selector` syscall
+pub fn validate_mock_response(
+    selector: SyscallSelector,
+    response: &[Felt252],
+) -> Result<(), String> {
+    let response_len = match selector {
+        SyscallSelector::GetExecutionInfo => {
+            return ExecutionInfoMock::read(response).map(|_| ()).map_err(|_| {
+                format!("Response of {selector:?} syscall has to be a serialized ExecutionInfo")
+            });
+        }
+        SyscallSelector::Keccak => 2,
+        _ => 1,
+    };
+
+    if response.len() == response_len {
+        Ok(())
+    } else {
+        Err(format!(
+            "Response of {selector:?} syscall has to consist of {response_len} felt(s), got {}",
+            response.len()
+        ))
+    }
+}
+
+impl CheatnetState {
+    pub fn start_mock_syscall(
+        &mut self,
+        target: CheatTarget,
+        selector: SyscallSelector,
+        mock: SyscallMock,
+    ) {
+        let (global_mock, contracts_mocks) = self.mocked_syscalls.entry(selector).or_default();
+
+        start_cheat(
+            global_mock,
+            contracts_mocks,
+            target,
+            mock,
+            CheatSpan::Indefinite,
+        );
+    }
+
+    pub fn stop_mock_syscall(&mut self, target: CheatTarget, selector: SyscallSelector) {
+        if let Some((global_mock, contracts_mocks)) = self.mocked_syscalls.get_mut(&selector) {
+            stop_cheat(global_mock, contracts_mocks, target);
+        }
+    }
+}
@@ -6,15 +6,24 @@ use runtime::EnhancedHintError;
 pub mod declare;
 pub mod deploy;
 pub mod elect;
+pub mod expect_call;
+pub mod expect_revert;
 pub mod get_class_hash;
+pub mod impersonate;
 pub mod l1_handler_execute;
+pub mod measure;
+pub mod mock_block_hash;
 pub mod mock_call;
+pub mod mock_syscall;
+pub mod nonce;
 pub mod prank;
 pub mod precalculate_address;
+pub mod random;
 pub mod replace_bytecode;
 pub mod roll;
 pub mod spoof;
 pub mod spy_events;
+pub mod spy_messages_to_l1;
 pub mod storage;
 pub mod warp;
 
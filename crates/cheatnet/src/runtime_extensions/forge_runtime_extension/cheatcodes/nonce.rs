@@ -0,0 +1,34 @@
+use anyhow::ensure;
+use blockifier::state::state_api::State;
+use cairo_felt::Felt252;
+use conversions::FromConv;
+use starknet_api::core::ContractAddress;
+
+///
+/// # Arguments
+///
+/// * `state`: Blockifier state
+/// * `target`: The address of the contract whose nonce we want to set
+/// * `nonce`: New nonce of the contract, can't be lower than the current one
+///
+/// returns: Result<(), Error> - a result containing the error if `set_contract_nonce` failed
+///
+pub fn set_contract_nonce(
+    state: &mut dyn State,
+    target: ContractAddress,
+    nonce: &Felt252,
+) -> Result<(), anyhow::Error> {
+    // Blockifier state only allows incrementing nonces
+    let mut current_nonce = Felt252::from_(state.get_nonce_at(target)?);
+    ensure!(
+        &current_nonce <= nonce,
+        "Nonce of contract {target} can't be decreased from {current_nonce} to {nonce}",
+        target = Felt252::from_(target),
+    );
+
+    while &current_nonce < nonce {
+        state.increment_nonce(target)?;
+        current_nonce += Felt252::from(1);
+    }
+    Ok(())
+}
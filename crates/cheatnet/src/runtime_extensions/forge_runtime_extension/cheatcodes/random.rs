@@ -0,0 +1,28 @@
+use crate::state::CheatnetState;
+use anyhow::{ensure, Result};
+use cairo_felt::Felt252;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::One;
+
+impl CheatnetState {
+    /// Returns a random felt generated with the seeded generator of the test
+    pub fn rand_felt(&mut self) -> Felt252 {
+        self.used_random_values = true;
+        Felt252::from(self.rng.gen_biguint_below(&Felt252::prime()))
+    }
+
+    /// Returns a random felt from `low` to `high` inclusive, bounds are compared as integers
+    pub fn rand_in_range(&mut self, low: &Felt252, high: &Felt252) -> Result<Felt252> {
+        let low = low.to_biguint();
+        let high = high.to_biguint();
+        ensure!(
+            low <= high,
+            "`rand_in_range` called with low = {low} greater than high = {high}"
+        );
+
+        self.used_random_values = true;
+        Ok(Felt252::from(
+            self.rng.gen_biguint_range(&low, &(high + BigUint::one())),
+        ))
+    }
+}
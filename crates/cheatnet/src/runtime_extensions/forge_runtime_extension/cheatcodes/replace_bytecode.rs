@@ -10,4 +10,8 @@ impl CheatnetState {
         self.replaced_bytecode_contracts
             .insert(contract_address, class_hash);
     }
+
+    pub fn reset_class_for_contract(&mut self, contract_address: ContractAddress) {
+        self.replaced_bytecode_contracts.remove(&contract_address);
+    }
 }
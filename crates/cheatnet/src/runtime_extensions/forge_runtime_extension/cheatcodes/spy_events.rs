@@ -58,9 +58,46 @@ impl SpyTarget {
     }
 }
 
+/// Spy created with `spy_events`, specifies which events are spied on.
+#[derive(Debug)]
+pub struct Spy {
+    pub target: SpyTarget,
+    /// Selectors (first keys) of spied events, events with any selector are spied on if not set
+    pub selectors: Option<Vec<Felt252>>,
+}
+
+impl Spy {
+    #[must_use]
+    pub fn does_spy(&self, event: &Event) -> bool {
+        self.target.does_spy(event.from)
+            && self.selectors.as_ref().map_or(true, |selectors| {
+                event
+                    .keys
+                    .first()
+                    .is_some_and(|selector| selectors.contains(selector))
+            })
+    }
+}
+
 impl CheatnetState {
     pub fn spy_events(&mut self, spy_on: SpyTarget) -> usize {
-        self.spies.push(spy_on);
+        self.spies.push(Spy {
+            target: spy_on,
+            selectors: None,
+        });
+        self.spies.len() - 1
+    }
+
+    /// Creates a spy catching only events with one of `selectors` emitted by `spy_on` targets
+    pub fn spy_events_with_selectors(
+        &mut self,
+        spy_on: SpyTarget,
+        selectors: Vec<Felt252>,
+    ) -> usize {
+        self.spies.push(Spy {
+            target: spy_on,
+            selectors: Some(selectors),
+        });
         self.spies.len() - 1
     }
 
@@ -74,7 +111,7 @@ impl CheatnetState {
             .iter()
             .flat_map(|event| {
                 let mut flattened_event = vec![];
-                if spy_on.does_spy(event.from) {
+                if spy_on.does_spy(event) {
                     flattened_event.push(Felt252::from_(event.from));
                     flattened_event.push(Felt252::from(event.keys.len()));
                     flattened_event.append(&mut event.keys.clone());
@@ -0,0 +1,66 @@
+use crate::CheatnetState;
+use blockifier::execution::call_info::OrderedL2ToL1Message;
+use cairo_felt::Felt252;
+use cairo_vm::hint_processor::hint_processor_utils::felt_to_usize;
+use conversions::FromConv;
+use starknet_api::core::ContractAddress;
+
+/// Represents a message sent to L1 with `send_message_to_l1_syscall`. It is used in the `CheatnetState`
+/// to keep track of messages sent during the test
+#[derive(Debug, PartialEq, Clone)]
+pub struct MessageToL1 {
+    pub from: ContractAddress,
+    pub to_address: Felt252,
+    pub payload: Vec<Felt252>,
+}
+
+impl MessageToL1 {
+    #[must_use]
+    pub fn from_ordered_message(
+        ordered_message: &OrderedL2ToL1Message,
+        contract_address: ContractAddress,
+    ) -> Self {
+        Self {
+            from: contract_address,
+            to_address: Felt252::from_bytes_be(ordered_message.message.to_address.0.as_bytes()),
+            payload: ordered_message
+                .message
+                .payload
+                .0
+                .iter()
+                .map(|el| Felt252::from_(*el))
+                .collect(),
+        }
+    }
+}
+
+impl CheatnetState {
+    /// Creates a spy on messages sent to L1 after its creation, returns its id
+    pub fn spy_messages_to_l1(&mut self) -> usize {
+        self.message_to_l1_spies
+            .push(self.detected_messages_to_l1.len());
+        self.message_to_l1_spies.len() - 1
+    }
+
+    /// Returns serialized messages sent to L1 since the last fetch of the spy with `id`
+    pub fn fetch_messages_to_l1(&mut self, id: &Felt252) -> (usize, Vec<Felt252>) {
+        let fetched_len = &mut self.message_to_l1_spies[felt_to_usize(id).unwrap()];
+        let new_messages = &self.detected_messages_to_l1[*fetched_len..];
+        *fetched_len = self.detected_messages_to_l1.len();
+
+        let serialized_messages = new_messages
+            .iter()
+            .flat_map(|message| {
+                let mut flattened_message = vec![
+                    Felt252::from_(message.from),
+                    message.to_address.clone(),
+                    Felt252::from(message.payload.len()),
+                ];
+                flattened_message.extend(message.payload.iter().cloned());
+                flattened_message
+            })
+            .collect();
+
+        (new_messages.len(), serialized_messages)
+    }
+}
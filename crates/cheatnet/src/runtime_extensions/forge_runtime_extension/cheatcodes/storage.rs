@@ -1,3 +1,6 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::format_contract_address;
+use crate::CheatnetState;
+use blockifier::execution::execution_utils::felt_to_stark_felt;
 use blockifier::state::state_api::State;
 use cairo_felt::Felt252;
 use conversions::{FromConv, IntoConv};
@@ -8,6 +11,28 @@ use starknet_api::core::{ContractAddress, PatriciaKey};
 use starknet_api::hash::StarkHash;
 use starknet_api::state::StorageKey;
 
+/// Storage read or write made with a syscall during the test
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageAccess {
+    pub contract_address: ContractAddress,
+    pub key: Felt252,
+    pub is_write: bool,
+    /// Value read from or written to the slot
+    pub value: Felt252,
+}
+
+impl StorageAccess {
+    #[must_use]
+    pub fn serialize(&self) -> Vec<Felt252> {
+        vec![
+            self.contract_address.into_(),
+            self.key.clone(),
+            Felt252::from(u8::from(self.is_write)),
+            self.value.clone(),
+        ]
+    }
+}
+
 ///
 /// # Arguments
 ///
@@ -47,6 +72,61 @@ pub fn load(
         .into_())
 }
 
+impl CheatnetState {
+    /// Remembers the value of a storage slot from before it was overridden with `store` for the first time
+    pub fn record_original_storage_value(
+        &mut self,
+        target: ContractAddress,
+        storage_address: &Felt252,
+        original_value: Felt252,
+    ) {
+        self.original_storage_values
+            .entry((target, storage_address.clone()))
+            .or_insert(original_value);
+    }
+
+    #[must_use]
+    pub fn is_original_storage_value_recorded(
+        &self,
+        target: ContractAddress,
+        storage_address: &Felt252,
+    ) -> bool {
+        self.original_storage_values
+            .contains_key(&(target, storage_address.clone()))
+    }
+
+    /// Returns the value of a storage slot from before it was overridden with `store`,
+    /// `None` if the slot was not overridden or was already reset
+    pub fn take_original_storage_value(
+        &mut self,
+        target: ContractAddress,
+        storage_address: &Felt252,
+    ) -> Option<Felt252> {
+        self.original_storage_values
+            .remove(&(target, storage_address.clone()))
+    }
+
+    pub fn record_storage_access(&mut self, storage_access: StorageAccess) {
+        self.storage_accesses.push(storage_access);
+    }
+
+    /// Returns storage reads and writes made during the test, one line per access
+    #[must_use]
+    pub fn storage_trace(&self) -> Vec<String> {
+        self.storage_accesses
+            .iter()
+            .map(|access| {
+                let contract_address =
+                    format_contract_address(&access.contract_address, &self.contract_labels);
+                let key = felt_to_stark_felt(&access.key);
+                let value = felt_to_stark_felt(&access.value);
+                let kind = if access.is_write { "write" } else { "read" };
+                format!("{kind} {contract_address} [{key}]: {value}")
+            })
+            .collect()
+    }
+}
+
 /// The address after hashing with pedersen, needs to be taken with a specific modulo value (2^251 - 256)
 /// For details see:
 /// <https://docs.starknet.io/documentation/architecture_and_concepts/Smart_Contracts/contract-storage>
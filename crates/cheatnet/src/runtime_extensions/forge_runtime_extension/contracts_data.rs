@@ -1,23 +1,30 @@
 use super::cheatcodes::declare::get_class_hash;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use bimap::BiMap;
+use blockifier::execution::contract_class::{
+    ContractClass as BlockifierContractClass, ContractClassV1,
+};
 use conversions::IntoConv;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
-use scarb_api::StarknetContractArtifacts;
+use scarb_api::LazyStarknetContractArtifacts;
 use starknet::core::types::contract::{AbiEntry, SierraClass};
 use starknet::core::utils::get_selector_from_name;
 use starknet_api::core::{ClassHash, EntryPointSelector};
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone)]
 pub struct ContractsData {
-    pub contracts: HashMap<String, StarknetContractArtifacts>,
+    /// Artifacts of the contracts, casm of each is loaded on its first declare
+    pub contracts: HashMap<String, LazyStarknetContractArtifacts>,
     pub class_hashes: BiMap<String, ClassHash>,
     pub selectors: HashMap<EntryPointSelector, String>,
+    /// Compiled classes shared between all test cases using these contracts
+    compiled_classes: Arc<RwLock<HashMap<String, BlockifierContractClass>>>,
 }
 
 impl ContractsData {
-    pub fn try_from(contracts: HashMap<String, StarknetContractArtifacts>) -> Result<Self> {
+    pub fn try_from(contracts: HashMap<String, LazyStarknetContractArtifacts>) -> Result<Self> {
         let parsed_contracts: HashMap<String, SierraClass> = contracts
             .par_iter()
             .map(|(name, artifact)| Ok((name.clone(), serde_json::from_str(&artifact.sierra)?)))
@@ -38,8 +45,38 @@ impl ContractsData {
             contracts,
             class_hashes: BiMap::from_iter(class_hashes),
             selectors,
+            compiled_classes: Arc::default(),
         })
     }
+
+    /// Returns compiled class of the contract with given name.
+    /// Casm of each contract is loaded and parsed only once and reused for every subsequent call.
+    pub fn get_compiled_contract_class(
+        &self,
+        contract_name: &str,
+    ) -> Result<BlockifierContractClass> {
+        if let Some(contract_class) = self.compiled_classes.read().unwrap().get(contract_name) {
+            return Ok(contract_class.clone());
+        }
+
+        let contract_artifact = self.contracts.get(contract_name).with_context(|| {
+            format!("Failed to get contract artifact for name = {contract_name}. Make sure starknet target is correctly defined in Scarb.toml file.")
+        })?;
+        let casm = contract_artifact
+            .casm
+            .load()
+            .with_context(|| format!("Failed to load casm of contract = {contract_name}"))?;
+        let contract_class = ContractClassV1::try_from_json_string(&casm)
+            .context("Failed to read contract class from json")?;
+
+        Ok(self
+            .compiled_classes
+            .write()
+            .unwrap()
+            .entry(contract_name.to_string())
+            .or_insert(BlockifierContractClass::V1(contract_class))
+            .clone())
+    }
 }
 
 fn build_name_selector_map(abi: Vec<AbiEntry>) -> HashMap<EntryPointSelector, String> {
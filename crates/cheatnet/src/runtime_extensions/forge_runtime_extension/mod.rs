@@ -2,18 +2,22 @@ use self::contracts_data::ContractsData;
 use crate::{
     runtime_extensions::{
         call_to_blockifier_runtime_extension::{
+            panic_data::{decode_panic_data, serialize_panic_data_items},
             rpc::{CallFailure, CallResult, UsedResources},
             CallToBlockifierRuntime, RuntimeState,
         },
         cheatable_starknet_runtime_extension::SyscallSelector,
         common::{get_relocated_vm_trace, sum_syscall_counters},
         forge_runtime_extension::cheatcodes::{
-            declare::declare,
+            declare::{declare, declare_from_file, declare_legacy},
             deploy::{deploy, deploy_at},
             get_class_hash::get_class_hash,
             l1_handler_execute::l1_handler_execute,
+            measure::ResourcesSnapshot,
+            mock_syscall::{validate_mock_response, SyscallMock, MOCKABLE_SYSCALLS},
+            nonce::set_contract_nonce,
             spy_events::SpyTarget,
-            storage::{calculate_variable_address, load, store},
+            storage::{calculate_variable_address, load, store, StorageAccess},
             CheatcodeError,
         },
     },
@@ -37,6 +41,7 @@ use cairo_vm::vm::{
     errors::hint_errors::HintError, runners::cairo_runner::ExecutionResources,
     vm_core::VirtualMachine,
 };
+use camino::Utf8PathBuf;
 use conversions::{
     byte_array::ByteArray,
     felt252::{FromShortString, TryInferFormat},
@@ -48,6 +53,7 @@ use runtime::{
     CheatcodeHandlingResult, EnhancedHintError, ExtendedRuntime, ExtensionLogic,
     SyscallHandlingResult,
 };
+use semver::Version;
 use starknet::signers::SigningKey;
 use starknet_api::{
     core::ContractAddress,
@@ -64,11 +70,14 @@ pub type ForgeRuntime<'a> = ExtendedRuntime<ForgeExtension<'a>>;
 pub struct ForgeExtension<'a> {
     pub environment_variables: &'a HashMap<String, String>,
     pub contracts_data: &'a ContractsData,
+    /// Version of the snforge binary, returned by `cheatcode_version`
+    pub snforge_version: &'a Version,
 }
 
 trait BufferReaderExt {
     fn read_cheat_target(&mut self) -> BufferReadResult<CheatTarget>;
     fn read_cheat_span(&mut self) -> BufferReadResult<CheatSpan>;
+    fn read_mockable_syscall(&mut self) -> BufferReadResult<SyscallSelector>;
 }
 
 impl BufferReaderExt for BufferReader<'_> {
@@ -98,6 +107,22 @@ impl BufferReaderExt for BufferReader<'_> {
             _ => Err(BufferReadError::ParseFailed)?,
         })
     }
+
+    /// Reads a variant of `MockableSyscall`
+    fn read_mockable_syscall(&mut self) -> BufferReadResult<SyscallSelector> {
+        self.read_felt()?
+            .to_usize()
+            .and_then(|variant| MOCKABLE_SYSCALLS.get(variant).copied())
+            .ok_or(BufferReadError::ParseFailed)
+    }
+}
+
+/// Reads a block number passed to a cheatcode, which can be any felt when the cheatcode is called directly
+fn read_block_number(input_reader: &mut BufferReader<'_>) -> Result<u64, EnhancedHintError> {
+    let block_number = input_reader.read_felt()?;
+    block_number
+        .to_u64()
+        .ok_or_else(|| anyhow!("Block number = {block_number} does not fit in u64").into())
 }
 
 // This runtime extension provides an implementation logic for functions from snforge_std library.
@@ -201,6 +226,26 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .stop_prank(target);
                 Ok(CheatcodeHandlingResult::Handled(vec![]))
             }
+            "start_impersonate" => {
+                let account = input_reader.read_felt()?.into_();
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_impersonate(account);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "stop_impersonate" => {
+                let account = input_reader.read_felt()?.into_();
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_impersonate(&account);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
             "mock_call" => {
                 let contract_address = input_reader.read_felt()?.into_();
                 let function_selector = input_reader.read_felt()?;
@@ -226,6 +271,61 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .stop_mock_call(contract_address, function_selector);
                 Ok(CheatcodeHandlingResult::Handled(vec![]))
             }
+            "mock_call_when" => {
+                let contract_address = input_reader.read_felt()?.into_();
+                let function_selector = input_reader.read_felt()?;
+                let span = input_reader.read_cheat_span()?;
+
+                let calldata = input_reader.read_vec()?;
+                let ret_data = input_reader.read_vec()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mock_call_when(
+                        contract_address,
+                        function_selector,
+                        &calldata,
+                        &ret_data,
+                        span,
+                    );
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "stop_mock_call_when" => {
+                let contract_address = input_reader.read_felt()?.into_();
+                let function_selector = input_reader.read_felt()?;
+                let calldata = input_reader.read_vec()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_mock_call_when(contract_address, function_selector, &calldata);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "expect_call" => {
+                let contract_address = input_reader.read_felt()?.into_();
+                let function_selector = input_reader.read_felt()?;
+                let calldata = input_reader.read_option_vec()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_call(contract_address, function_selector, calldata.as_deref());
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "expect_revert" => {
+                let panic_data = input_reader.read_vec()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_revert(panic_data);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
             "spoof" => {
                 let target = input_reader.read_cheat_target()?;
                 let span = input_reader.read_cheat_span()?;
@@ -282,6 +382,61 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .stop_spoof(target);
                 Ok(CheatcodeHandlingResult::Handled(vec![]))
             }
+            "start_mock_block_hash" => {
+                let target = input_reader.read_cheat_target()?;
+                let block_number = read_block_number(&mut input_reader)?;
+                let block_hash = input_reader.read_felt()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_mock_block_hash(target, block_number, block_hash);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "stop_mock_block_hash" => {
+                let target = input_reader.read_cheat_target()?;
+                let block_number = read_block_number(&mut input_reader)?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_mock_block_hash(target, block_number);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "start_mock_syscall" => {
+                let target = input_reader.read_cheat_target()?;
+                let selector = input_reader.read_mockable_syscall()?;
+                let is_failure = input_reader.read_bool()?;
+                let data = input_reader.read_vec()?;
+
+                let mock = if is_failure {
+                    SyscallMock::Failure(data)
+                } else {
+                    validate_mock_response(selector, &data)
+                        .map_err(|message| EnhancedHintError::from(anyhow!(message)))?;
+                    SyscallMock::Response(data)
+                };
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_mock_syscall(target, selector, mock);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "stop_mock_syscall" => {
+                let target = input_reader.read_cheat_target()?;
+                let selector = input_reader.read_mockable_syscall()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_mock_syscall(target, selector);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
             "replace_bytecode" => {
                 let contract = input_reader.read_felt()?.into_();
                 let class = input_reader.read_felt()?.into_();
@@ -293,6 +448,69 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .replace_class_for_contract(contract, class);
                 Ok(CheatcodeHandlingResult::Handled(vec![]))
             }
+            "reset_bytecode" => {
+                let contract = input_reader.read_felt()?.into_();
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .reset_class_for_contract(contract);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "rand_felt" => {
+                let value = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .rand_felt();
+
+                Ok(CheatcodeHandlingResult::Handled(vec![value]))
+            }
+            "rand_in_range" => {
+                let low = input_reader.read_felt()?;
+                let high = input_reader.read_felt()?;
+
+                let value = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .rand_in_range(&low, &high)?;
+
+                Ok(CheatcodeHandlingResult::Handled(vec![value]))
+            }
+            "label" => {
+                let label = input_reader.read_string()?;
+                let contract_address = input_reader.read_felt()?.into_();
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .contract_labels
+                    .insert(contract_address, label);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "get_label" => {
+                let contract_address = input_reader.read_felt()?.into_();
+
+                let label = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .contract_labels
+                    .get(&contract_address);
+
+                let output = match label {
+                    Some(label) => {
+                        let mut output = vec![Felt252::from(0)];
+                        output.extend(ByteArray::from(label.as_str()).serialize_no_magic());
+                        output
+                    }
+                    None => vec![Felt252::from(1)],
+                };
+                Ok(CheatcodeHandlingResult::Handled(output))
+            }
             "declare" => {
                 let state = &mut extended_runtime
                     .extended_runtime
@@ -313,6 +531,46 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     Err(CheatcodeError::Unrecoverable(err)) => Err(err),
                 }
             }
+            "declare_legacy" => {
+                let state = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler
+                    .state;
+
+                let path = Utf8PathBuf::from(input_reader.read_string()?);
+
+                match declare_legacy(*state, &path) {
+                    Ok(class_hash) => {
+                        let result = vec![Felt252::from(0), class_hash.into_()];
+                        Ok(CheatcodeHandlingResult::Handled(result))
+                    }
+                    Err(CheatcodeError::Recoverable(_)) => {
+                        panic!("Declare should not fail recoverably!")
+                    }
+                    Err(CheatcodeError::Unrecoverable(err)) => Err(err),
+                }
+            }
+            "declare_from_file" => {
+                let state = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler
+                    .state;
+
+                let path = Utf8PathBuf::from(input_reader.read_string()?);
+
+                match declare_from_file(*state, &path) {
+                    Ok(class_hash) => {
+                        let result = vec![Felt252::from(0), class_hash.into_()];
+                        Ok(CheatcodeHandlingResult::Handled(result))
+                    }
+                    Err(CheatcodeError::Recoverable(_)) => {
+                        panic!("Declare should not fail recoverably!")
+                    }
+                    Err(CheatcodeError::Unrecoverable(err)) => Err(err),
+                }
+            }
             "deploy" => {
                 let class_hash = input_reader.read_felt()?.into_();
                 let calldata = input_reader.read_vec()?;
@@ -456,12 +714,14 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                         SpyTarget::Multiple(addresses)
                     }
                 };
+                let selectors = input_reader.read_option_vec()?;
 
-                let id = extended_runtime
-                    .extended_runtime
-                    .extension
-                    .cheatnet_state
-                    .spy_events(spy_on);
+                let cheatnet_state =
+                    &mut extended_runtime.extended_runtime.extension.cheatnet_state;
+                let id = match selectors {
+                    Some(selectors) => cheatnet_state.spy_events_with_selectors(spy_on, selectors),
+                    None => cheatnet_state.spy_events(spy_on),
+                };
                 Ok(CheatcodeHandlingResult::Handled(vec![Felt252::from(id)]))
             }
             "fetch_events" => {
@@ -475,6 +735,25 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 result.extend(serialized_events);
                 Ok(CheatcodeHandlingResult::Handled(result))
             }
+            "spy_messages_to_l1" => {
+                let id = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .spy_messages_to_l1();
+                Ok(CheatcodeHandlingResult::Handled(vec![Felt252::from(id)]))
+            }
+            "fetch_messages_to_l1" => {
+                let id = &input_reader.read_felt()?;
+                let (messages_len, serialized_messages) = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .fetch_messages_to_l1(id);
+                let mut result = vec![Felt252::from(messages_len)];
+                result.extend(serialized_messages);
+                Ok(CheatcodeHandlingResult::Handled(result))
+            }
             "event_name_hash" => {
                 let name = input_reader.read_felt()?;
                 let hash = starknet_keccak(as_cairo_short_string(&name).unwrap().as_bytes());
@@ -624,8 +903,40 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .state;
                 let target = ContractAddress::from_(input_reader.read_felt()?);
                 let storage_address = input_reader.read_felt()?;
-                store(*state, target, &storage_address, input_reader.read_felt()?)
-                    .expect("Failed to store");
+                let value = input_reader.read_felt()?;
+                let cheatnet_state =
+                    &mut *extended_runtime.extended_runtime.extension.cheatnet_state;
+
+                // Only the value from before the first override is restored by `reset_store`
+                if !cheatnet_state.is_original_storage_value_recorded(target, &storage_address) {
+                    let original_value = load(*state, target, &storage_address)?;
+                    cheatnet_state.record_original_storage_value(
+                        target,
+                        &storage_address,
+                        original_value,
+                    );
+                }
+                store(*state, target, &storage_address, value)?;
+
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "reset_store" => {
+                let target = ContractAddress::from_(input_reader.read_felt()?);
+                let storage_address = input_reader.read_felt()?;
+                let original_value = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .take_original_storage_value(target, &storage_address);
+
+                if let Some(original_value) = original_value {
+                    let state = &mut extended_runtime
+                        .extended_runtime
+                        .extended_runtime
+                        .hint_handler
+                        .state;
+                    store(*state, target, &storage_address, original_value)?;
+                }
                 Ok(CheatcodeHandlingResult::Handled(vec![]))
             }
             "load" => {
@@ -639,6 +950,65 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 let loaded = load(*state, target, storage_address).expect("Failed to load");
                 Ok(CheatcodeHandlingResult::Handled(vec![loaded]))
             }
+            "get_storage_accesses" => {
+                let storage_accesses = &extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .storage_accesses;
+                let mut result = vec![Felt252::from(storage_accesses.len())];
+                result.extend(storage_accesses.iter().flat_map(StorageAccess::serialize));
+                Ok(CheatcodeHandlingResult::Handled(result))
+            }
+            "set_contract_nonce" => {
+                let state = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler
+                    .state;
+                let target = ContractAddress::from_(input_reader.read_felt()?);
+                let nonce = input_reader.read_felt()?;
+                set_contract_nonce(*state, target, &nonce)?;
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "start_measure" => {
+                let snapshot = get_resources_snapshot(extended_runtime);
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_measure(snapshot);
+                Ok(CheatcodeHandlingResult::Handled(vec![]))
+            }
+            "stop_measure" => {
+                let snapshot = get_resources_snapshot(extended_runtime);
+                let measurement = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_measure(&snapshot)
+                    .ok_or_else(|| anyhow!("`stop_measure` called without `start_measure`"))?;
+                Ok(CheatcodeHandlingResult::Handled(measurement.serialize()))
+            }
+            "get_syscall_counts" => {
+                let snapshot = get_resources_snapshot(extended_runtime);
+                Ok(CheatcodeHandlingResult::Handled(
+                    snapshot.serialize_syscalls(),
+                ))
+            }
+            "decode_panic_data" => {
+                let panic_data = input_reader.read_vec()?;
+                let items = decode_panic_data(&panic_data);
+
+                Ok(CheatcodeHandlingResult::Handled(
+                    serialize_panic_data_items(items),
+                ))
+            }
+            "cheatcode_version" => Ok(CheatcodeHandlingResult::Handled(vec![
+                Felt252::from(self.snforge_version.major),
+                Felt252::from(self.snforge_version.minor),
+                Felt252::from(self.snforge_version.patch),
+            ])),
             "map_entry_address" => {
                 let map_selector = &input_reader.read_felt()?;
                 let keys = &input_reader.read_vec()?;
@@ -784,6 +1154,38 @@ fn concat_u128_bytes(low: &[u8; 32], high: &[u8; 32]) -> [u8; 32] {
     result
 }
 
+/// Resources used by calls made from the test and syscalls executed so far,
+/// steps executed directly in the test code are not included
+fn get_resources_snapshot(runtime: &CallToBlockifierRuntime) -> ResourcesSnapshot {
+    let hint_handler = &runtime.extended_runtime.extended_runtime.hint_handler;
+
+    // call representing the test code
+    let top_call = runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .trace_data
+        .current_call_stack
+        .top();
+
+    // Only sum 1-level since these include syscalls from inner calls
+    let nested_calls_syscalls = top_call
+        .borrow()
+        .nested_calls
+        .iter()
+        .fold(SyscallCounter::new(), |syscalls, trace| {
+            sum_syscall_counters(syscalls, &trace.borrow().used_syscalls)
+        });
+
+    ResourcesSnapshot {
+        execution_resources: hint_handler.resources.clone(),
+        syscall_counter: sum_syscall_counters(
+            hint_handler.syscall_counter.clone(),
+            &nested_calls_syscalls,
+        ),
+    }
+}
+
 pub fn update_top_call_execution_resources(runtime: &mut ForgeRuntime) {
     let all_execution_resources = runtime
         .extended_runtime
@@ -1,15 +1,21 @@
 use crate::forking::state::ForkStateReader;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallResult;
+use crate::runtime_extensions::cheatable_starknet_runtime_extension::SyscallSelector;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect_call::ExpectedCall;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::measure::ResourcesSnapshot;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_syscall::SyscallMock;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spoof::TxInfoMock;
-use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::{
-    Event, SpyTarget,
-};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::{Event, Spy};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage::StorageAccess;
 use blockifier::execution::entry_point::CallEntryPoint;
 use blockifier::{
     execution::contract_class::ContractClass,
     state::state_api::{StateReader, StateResult},
 };
 use cairo_felt::Felt252;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use runtime::starknet::state::DictStateReader;
 
 use starknet_api::core::EntryPointSelector;
@@ -30,7 +36,7 @@ use starknet_api::{
     state::StorageKey,
 };
 use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::BuildHasher;
 use std::rc::Rc;
 use trace_data::L1Resources;
@@ -232,6 +238,17 @@ pub struct TraceData {
     pub is_vm_trace_needed: bool,
 }
 
+/// Mocked block hash of a single block number, for all contracts and for chosen ones
+pub type BlockHashCheats = (
+    Option<(Felt252, CheatSpan)>,
+    HashMap<ContractAddress, CheatStatus<Felt252>>,
+);
+
+pub type SyscallMockCheats = (
+    Option<(SyscallMock, CheatSpan)>,
+    HashMap<ContractAddress, CheatStatus<SyscallMock>>,
+);
+
 pub struct CheatnetState {
     pub rolled_contracts: HashMap<ContractAddress, CheatStatus<Felt252>>,
     pub global_roll: Option<(Felt252, CheatSpan)>,
@@ -243,11 +260,42 @@ pub struct CheatnetState {
     pub global_elect: Option<(ContractAddress, CheatSpan)>,
     pub mocked_functions:
         HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<StarkFelt>>>>,
+    /// Mocks applied only to calls with the given calldata, checked before `mocked_functions`
+    pub calldata_mocked_functions: HashMap<
+        ContractAddress,
+        HashMap<(EntryPointSelector, Vec<StarkFelt>), CheatStatus<Vec<StarkFelt>>>,
+    >,
     pub spoofed_contracts: HashMap<ContractAddress, CheatStatus<TxInfoMock>>,
     pub global_spoof: Option<(TxInfoMock, CheatSpan)>,
+    /// Results of `get_block_hash` syscall, by block number
+    pub mocked_block_hashes: HashMap<u64, BlockHashCheats>,
+    /// Results returned from syscalls instead of executing them, by syscall
+    pub mocked_syscalls: HashMap<SyscallSelector, SyscallMockCheats>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
-    pub spies: Vec<SpyTarget>,
+    /// Accounts which signatures are not validated, set with `start_impersonate`
+    pub impersonated_accounts: HashSet<ContractAddress>,
+    /// Values of storage slots from before they were overridden with `store`, restored by `reset_store`
+    pub original_storage_values: HashMap<(ContractAddress, Felt252), Felt252>,
+    /// Storage reads and writes made by contracts and the test, in order
+    pub storage_accesses: Vec<StorageAccess>,
+    /// Human-readable names of contracts, printed instead of bare addresses
+    pub contract_labels: HashMap<ContractAddress, String>,
+    pub spies: Vec<Spy>,
+    /// Calls which have to be made before the test finishes
+    pub expected_calls: Vec<ExpectedCall>,
+    /// Panic data the next call made from the test is expected to fail with
+    pub expected_revert: Option<Vec<Felt252>>,
     pub detected_events: Vec<Event>,
+    /// Messages sent to L1 during the test, in order
+    pub detected_messages_to_l1: Vec<MessageToL1>,
+    /// Numbers of messages already fetched by each L1 message spy
+    pub message_to_l1_spies: Vec<usize>,
+    /// Resources used before `start_measure` was called, if the measurement is in progress
+    pub measure_start: Option<ResourcesSnapshot>,
+    /// Generator behind `rand_felt` and `rand_in_range`, seeded for every test by the runner
+    pub rng: StdRng,
+    /// Whether the test generated values with `rng`, so its seed is needed to reproduce a failure
+    pub used_random_values: bool,
     pub deploy_salt_base: u32,
     pub block_info: BlockInfo,
     pub trace_data: TraceData,
@@ -276,11 +324,25 @@ impl Default for CheatnetState {
             elected_contracts: Default::default(),
             global_elect: None,
             mocked_functions: Default::default(),
+            calldata_mocked_functions: Default::default(),
             spoofed_contracts: Default::default(),
             replaced_bytecode_contracts: Default::default(),
+            impersonated_accounts: Default::default(),
+            original_storage_values: Default::default(),
+            storage_accesses: vec![],
+            contract_labels: Default::default(),
             global_spoof: None,
+            mocked_block_hashes: Default::default(),
+            mocked_syscalls: Default::default(),
             spies: vec![],
+            expected_calls: vec![],
+            expected_revert: None,
             detected_events: vec![],
+            detected_messages_to_l1: vec![],
+            message_to_l1_spies: vec![],
+            measure_start: None,
+            rng: StdRng::seed_from_u64(0),
+            used_random_values: false,
             deploy_salt_base: 0,
             block_info: SerializableBlockInfo::default().into(),
             trace_data: TraceData {
@@ -378,6 +440,31 @@ impl CheatnetState {
         get_cheat_for_contract(&self.global_prank, &self.pranked_contracts, address)
     }
 
+    #[must_use]
+    pub fn get_mocked_block_hash(
+        &self,
+        address: &ContractAddress,
+        block_number: u64,
+    ) -> Option<Felt252> {
+        self.mocked_block_hashes.get(&block_number).and_then(
+            |(global_block_hash, contracts_block_hashes)| {
+                get_cheat_for_contract(global_block_hash, contracts_block_hashes, address)
+            },
+        )
+    }
+
+    pub fn get_mocked_syscall(
+        &self,
+        address: &ContractAddress,
+        selector: SyscallSelector,
+    ) -> Option<SyscallMock> {
+        self.mocked_syscalls
+            .get(&selector)
+            .and_then(|(global_mock, contracts_mocks)| {
+                get_cheat_for_contract(global_mock, contracts_mocks, address)
+            })
+    }
+
     pub fn update_cheats(&mut self, address: &ContractAddress) {
         update_cheat_for_contract(&self.global_roll, &mut self.rolled_contracts, address);
         update_cheat_for_contract(&self.global_warp, &mut self.warped_contracts, address);
@@ -1,16 +1,23 @@
 use crate::common::{get_contracts, state::create_cached_state};
+use blockifier::execution::contract_class::ContractClass;
+use blockifier::state::state_api::StateReader;
+use camino::{Utf8Path, Utf8PathBuf};
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::declare::{
-    declare, get_class_hash,
+    declare, declare_from_file, declare_legacy, get_class_hash,
 };
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::CheatcodeError;
+use conversions::IntoConv;
 use runtime::EnhancedHintError;
-use scarb_api::StarknetContractArtifacts;
+use scarb_api::LazyStarknetContractArtifacts;
+use starknet::core::types::contract::legacy::LegacyContractClass;
 use starknet_api::core::ClassHash;
 use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
 
 fn get_contract_class_hash(
     contract_name: &str,
-    contracts: &HashMap<String, StarknetContractArtifacts>,
+    contracts: &HashMap<String, LazyStarknetContractArtifacts>,
 ) -> ClassHash {
     let contract = contracts.get(contract_name).unwrap();
     let sierra_class = serde_json::from_str(&contract.sierra).unwrap();
@@ -68,6 +75,21 @@ fn declare_same_contract() {
     });
 }
 
+#[test]
+fn declare_same_contract_in_separate_states() {
+    let contract_name = "HelloStarknet";
+
+    let contracts_data = get_contracts();
+    let expected_class_hash = get_contract_class_hash(contract_name, &contracts_data.contracts);
+
+    for _ in 0..2 {
+        let mut cached_state = create_cached_state();
+
+        let class_hash = declare(&mut cached_state, contract_name, &contracts_data).unwrap();
+        assert_eq!(class_hash, expected_class_hash);
+    }
+}
+
 #[test]
 fn declare_non_existent() {
     let contract_name = "GoodbyeStarknet";
@@ -86,3 +108,80 @@ fn declare_non_existent() {
         _ => false,
     });
 }
+
+#[test]
+fn declare_legacy_contract() {
+    let path = Utf8Path::new("tests/data/empty_legacy_contract.json");
+
+    let mut cached_state = create_cached_state();
+
+    let class_hash = declare_legacy(&mut cached_state, path).unwrap();
+
+    let artifact = fs::read_to_string(path).unwrap();
+    let legacy_class: LegacyContractClass = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(class_hash, legacy_class.class_hash().unwrap().into_());
+    assert!(matches!(
+        cached_state.get_compiled_contract_class(class_hash),
+        Ok(ContractClass::V0(_))
+    ));
+
+    let output = declare_legacy(&mut cached_state, path);
+    assert!(match output {
+        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
+            msg.to_string().contains("is already declared")
+        }
+        _ => false,
+    });
+}
+
+#[test]
+fn declare_legacy_missing_artifact() {
+    let mut cached_state = create_cached_state();
+
+    let output = declare_legacy(&mut cached_state, Utf8Path::new("tests/data/missing.json"));
+
+    assert!(match output {
+        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => msg
+            .to_string()
+            .contains("Failed to load Cairo 0 contract class from tests/data/missing.json"),
+        _ => false,
+    });
+}
+
+#[test]
+fn declare_from_file_contract() {
+    let contract_name = "HelloStarknet";
+    let contracts_data = get_contracts();
+
+    let temp_dir = TempDir::new().unwrap();
+    let path =
+        Utf8PathBuf::from_path_buf(temp_dir.path().join("hello.contract_class.json")).unwrap();
+    fs::write(&path, &contracts_data.contracts[contract_name].sierra).unwrap();
+
+    let mut cached_state = create_cached_state();
+
+    let class_hash = declare_from_file(&mut cached_state, &path).unwrap();
+
+    assert_eq!(
+        class_hash,
+        get_contract_class_hash(contract_name, &contracts_data.contracts)
+    );
+    assert!(matches!(
+        cached_state.get_compiled_contract_class(class_hash),
+        Ok(ContractClass::V1(_))
+    ));
+}
+
+#[test]
+fn declare_from_file_missing_artifact() {
+    let mut cached_state = create_cached_state();
+
+    let output = declare_from_file(&mut cached_state, Utf8Path::new("tests/data/missing.json"));
+
+    assert!(match output {
+        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => msg
+            .to_string()
+            .contains("Failed to load Sierra contract class from tests/data/missing.json"),
+        _ => false,
+    });
+}
@@ -10,6 +10,7 @@ mod elect;
 mod get_class_hash;
 mod load;
 mod mock_call;
+mod nonce;
 mod prank;
 mod precalculate_address;
 mod replace_bytecode;
@@ -0,0 +1,58 @@
+use crate::common::get_contracts;
+use blockifier::state::state_api::StateReader;
+use cairo_felt::Felt252;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::nonce::set_contract_nonce;
+use cheatnet::state::CheatnetState;
+use conversions::FromConv;
+
+use super::test_environment::TestEnvironment;
+
+#[test]
+fn set_contract_nonce_increases_nonce() {
+    let mut cheatnet_state = CheatnetState::default();
+    let mut test_env = TestEnvironment::new(&mut cheatnet_state);
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    set_contract_nonce(
+        &mut test_env.cached_state,
+        contract_address,
+        &Felt252::from(5),
+    )
+    .unwrap();
+
+    let nonce = test_env
+        .cached_state
+        .get_nonce_at(contract_address)
+        .unwrap();
+    assert_eq!(Felt252::from_(nonce), Felt252::from(5));
+}
+
+#[test]
+fn set_contract_nonce_cannot_decrease_nonce() {
+    let mut cheatnet_state = CheatnetState::default();
+    let mut test_env = TestEnvironment::new(&mut cheatnet_state);
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("HelloStarknet", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    set_contract_nonce(
+        &mut test_env.cached_state,
+        contract_address,
+        &Felt252::from(5),
+    )
+    .unwrap();
+    let result = set_contract_nonce(
+        &mut test_env.cached_state,
+        contract_address,
+        &Felt252::from(3),
+    );
+
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("can't be decreased from 5 to 3"));
+}
@@ -24,7 +24,7 @@ use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::Contr
 use conversions::IntoConv;
 use runtime::starknet::context::build_context;
 use scarb_api::metadata::MetadataCommandExt;
-use scarb_api::{get_contracts_map, ScarbCommand};
+use scarb_api::{get_lazy_contracts_map, ScarbCommand};
 use starknet::core::utils::get_selector_from_name;
 use starknet_api::core::PatriciaKey;
 use starknet_api::core::{ClassHash, ContractAddress};
@@ -76,7 +76,8 @@ pub fn get_contracts() -> ContractsData {
 
     let package = scarb_metadata.packages.first().unwrap();
 
-    ContractsData::try_from(get_contracts_map(&scarb_metadata, &package.id, None).unwrap()).unwrap()
+    ContractsData::try_from(get_lazy_contracts_map(&scarb_metadata, &package.id, None).unwrap())
+        .unwrap()
 }
 
 pub fn deploy_contract(
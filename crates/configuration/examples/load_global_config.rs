@@ -0,0 +1,34 @@
+//! Loads configuration of a custom tool from the `[my_tool.<profile>]` section of `snfoundry.toml`.
+//!
+//! Run with `cargo run -p configuration --example load_global_config -- <profile>`.
+
+use anyhow::Result;
+use configuration::{load_global_config, GlobalConfig};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Default)]
+struct MyToolConfig {
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    account: String,
+}
+
+impl GlobalConfig for MyToolConfig {
+    fn tool_name() -> &'static str {
+        "my_tool"
+    }
+
+    fn from_raw(config: serde_json::Value) -> Result<Self> {
+        Ok(serde_json::from_value(config)?)
+    }
+}
+
+fn main() -> Result<()> {
+    let profile = std::env::args().nth(1);
+    let config = load_global_config::<MyToolConfig>(&None, &profile)?;
+
+    println!("{config:?}");
+
+    Ok(())
+}
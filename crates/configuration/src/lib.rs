@@ -1,3 +1,13 @@
+//! Loading of Starknet Foundry configuration.
+//!
+//! Global tool configuration is read from the `snfoundry.toml` file using [`load_global_config`].
+//! Package configuration is read from the `tool` section of `Scarb.toml`
+//! using [`load_package_config`].
+//! Values starting with `$` are resolved from environment variables.
+//!
+//! The public API of this crate follows semantic versioning, see the `examples` directory
+//! for typical usage.
+
 use anyhow::{anyhow, Context, Result};
 use scarb_metadata::{Metadata, PackageId};
 use serde_json::Number;
@@ -78,6 +88,34 @@ pub fn load_global_config<T: GlobalConfig + Default>(
         None => Ok(T::default()),
     }
 }
+
+/// Returns names of the profiles defined for the `tool` in snfoundry.toml, sorted alphabetically
+/// # Arguments
+/// * `path` - Directory from which the search for snfoundry.toml starts, current directory if `None`
+/// * `tool` - Name of the tool, e.g. `snforge`
+pub fn load_profile_names(path: &Option<Utf8PathBuf>, tool: &str) -> Result<Vec<String>> {
+    let config_path = path
+        .as_ref()
+        .and_then(|p| search_config_upwards_relative_to(p).ok())
+        .or_else(|| find_config_file().ok());
+
+    let Some(path) = config_path else {
+        return Ok(vec![]);
+    };
+    let raw_config_toml = fs::read_to_string(path)
+        .context("Failed to read snfoundry.toml config file")?
+        .parse::<Value>()
+        .context("Failed to parse snfoundry.toml config file")?;
+
+    let mut profile_names: Vec<String> = match raw_config_toml.get(tool) {
+        Some(Value::Table(profiles)) => profiles.keys().cloned().collect(),
+        _ => vec![],
+    };
+    profile_names.sort();
+
+    Ok(profile_names)
+}
+
 /// Loads config for a specific package from the `Scarb.toml` file
 /// # Arguments
 /// * `metadata` - Scarb metadata object
@@ -276,6 +314,26 @@ mod tests {
         assert_eq!(config.url, String::from("http://127.0.0.1:5055/rpc"));
     }
 
+    #[test]
+    fn load_profile_names_from_config() {
+        let tempdir = copy_config_to_tempdir("tests/data/stubtool_snfoundry.toml", None).unwrap();
+        let path = Some(Utf8PathBuf::try_from(tempdir.path().to_path_buf()).unwrap());
+
+        assert_eq!(
+            load_profile_names(&path, "stubtool").unwrap(),
+            vec![
+                "default",
+                "profile1",
+                "profile2",
+                "profile3",
+                "profile4",
+                "profile5",
+                "with-envs"
+            ]
+        );
+        assert!(load_profile_names(&path, "othertool").unwrap().is_empty());
+    }
+
     #[test]
     fn load_config_not_found() {
         let tempdir = tempdir().expect("Failed to create a temporary directory");
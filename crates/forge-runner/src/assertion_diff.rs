@@ -0,0 +1,240 @@
+use cairo_felt::Felt252;
+use cairo_lang_runner::casm_run::format_next_item;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use std::fmt::Write;
+
+/// Values of a failed `assert_eq!` extracted from its panic message
+#[derive(Debug, PartialEq)]
+struct FailedAssertion<'a> {
+    left_name: &'a str,
+    right_name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+/// Builds an element by element diff of composite values compared in a failed `assert_eq!`
+///
+/// Returns `None` if the panic data does not come from `assert_eq!` or the compared values are not
+/// arrays, spans, tuples or structs
+pub(crate) fn build_assertion_diff(panic_data: &[Felt252]) -> Option<String> {
+    let mut data_iter = panic_data.iter().cloned();
+
+    while let Some(item) = format_next_item(&mut data_iter) {
+        let message = item.get();
+        if let Some(assertion) = parse_failed_assertion(&message) {
+            return render_diff(&assertion);
+        }
+    }
+
+    None
+}
+
+fn parse_failed_assertion(message: &str) -> Option<FailedAssertion> {
+    let mut lines = message.lines();
+
+    let header = lines.next()?.strip_prefix("assertion `")?;
+    let (comparison, _) = header.split_once("` failed")?;
+    let (left_name, right_name) = comparison.split_once(" == ")?;
+
+    let left = lines.next()?.strip_prefix(left_name)?.strip_prefix(": ")?;
+    let right = lines.next()?.strip_prefix(right_name)?.strip_prefix(": ")?;
+
+    Some(FailedAssertion {
+        left_name,
+        right_name,
+        left,
+        right,
+    })
+}
+
+/// Splits a debug representation of a composite value into labeled elements
+///
+/// `[1, 2]` and `(1, 2)` are labeled with indexes, `Point { x: 1, y: 2 }` with field names
+fn split_composite(value: &str) -> Option<Vec<(String, &str)>> {
+    let value = value.trim();
+
+    if let Some(inner) =
+        strip_delimiters(value, '[', ']').or_else(|| strip_delimiters(value, '(', ')'))
+    {
+        return Some(
+            split_top_level(inner)
+                .into_iter()
+                .enumerate()
+                .map(|(index, element)| (format!("[{index}]"), element))
+                .collect(),
+        );
+    }
+
+    let (_, fields) = value.split_once(" {")?;
+    let fields = fields.strip_suffix('}')?;
+    split_top_level(fields)
+        .into_iter()
+        .map(|field| {
+            let (name, value) = field.split_once(": ")?;
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+fn strip_delimiters(value: &str, open: char, close: char) -> Option<&str> {
+    value.strip_prefix(open)?.strip_suffix(close)
+}
+
+/// Splits `value` on commas which are not nested in brackets, braces, parentheses or strings
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut elements = vec![];
+    let mut depth = 0_usize;
+    let mut in_string = false;
+    let mut start = 0;
+
+    for (index, char) in value.char_indices() {
+        match char {
+            '"' => in_string = !in_string,
+            '[' | '(' | '{' if !in_string => depth += 1,
+            ']' | ')' | '}' if !in_string => depth = depth.saturating_sub(1),
+            ',' if !in_string && depth == 0 => {
+                elements.push(value[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = value[start..].trim();
+    if !last.is_empty() {
+        elements.push(last);
+    }
+    elements
+}
+
+/// Appends the short string a felt decodes to, e.g. `448378203247 ('hello')`
+fn with_decoded_short_string(element: &str) -> String {
+    let decoded = Felt252::parse_bytes(element.as_bytes(), 10)
+        .and_then(|felt| as_cairo_short_string(&felt))
+        .filter(|short_string| !short_string.is_empty());
+
+    match decoded {
+        Some(short_string) => format!("{element} ('{short_string}')"),
+        None => element.to_string(),
+    }
+}
+
+fn render_diff(assertion: &FailedAssertion) -> Option<String> {
+    let left = split_composite(assertion.left)?;
+    let right = split_composite(assertion.right)?;
+
+    let mut labels: Vec<&str> = left.iter().map(|(label, _)| label.as_str()).collect();
+    for (label, _) in &right {
+        if !labels.contains(&label.as_str()) {
+            labels.push(label);
+        }
+    }
+
+    let find = |elements: &[(String, &str)], label: &str| {
+        elements
+            .iter()
+            .find(|(element_label, _)| element_label == label)
+            .map_or_else(
+                || "<missing>".to_string(),
+                |(_, element)| with_decoded_short_string(element),
+            )
+    };
+    let rows: Vec<(&str, String, String)> = labels
+        .into_iter()
+        .map(|label| (label, find(&left, label), find(&right, label)))
+        .collect();
+
+    let label_width = rows.iter().map(|(label, _, _)| label.len()).max()?;
+    let left_width = rows
+        .iter()
+        .map(|(_, left, _)| left.len())
+        .chain([assertion.left_name.len()])
+        .max()?;
+
+    let mut diff = String::from("\n    Diff:\n");
+    writeln!(
+        diff,
+        "          {:label_width$}  {:left_width$}  {}",
+        "", assertion.left_name, assertion.right_name
+    )
+    .ok()?;
+    for (label, left, right) in rows {
+        let marker = if left == right { ' ' } else { '✗' };
+        writeln!(
+            diff,
+            "        {marker} {label:label_width$}  {left:left_width$}  {right}"
+        )
+        .ok()?;
+    }
+
+    Some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_assert_eq_message() {
+        let assertion =
+            parse_failed_assertion("assertion `a == b` failed.\na: [1, 2]\nb: [1, 3]").unwrap();
+
+        assert_eq!(
+            assertion,
+            FailedAssertion {
+                left_name: "a",
+                right_name: "b",
+                left: "[1, 2]",
+                right: "[1, 3]",
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_other_messages() {
+        assert!(parse_failed_assertion("failing check").is_none());
+        assert!(render_diff(&FailedAssertion {
+            left_name: "a",
+            right_name: "b",
+            left: "1",
+            right: "2",
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn splits_nested_values() {
+        assert_eq!(
+            split_composite("[1, (2, 3), Point { x: 4, y: 5 }]").unwrap(),
+            vec![
+                ("[0]".to_string(), "1"),
+                ("[1]".to_string(), "(2, 3)"),
+                ("[2]".to_string(), "Point { x: 4, y: 5 }"),
+            ]
+        );
+        assert_eq!(
+            split_composite("Point { x: 4, y: [5, 6] }").unwrap(),
+            vec![("x".to_string(), "4"), ("y".to_string(), "[5, 6]")]
+        );
+    }
+
+    #[test]
+    fn renders_aligned_diff() {
+        let diff = render_diff(&FailedAssertion {
+            left_name: "actual",
+            right_name: "expected",
+            left: "[1, 448378203247, 3]",
+            right: "[1, 2]",
+        })
+        .unwrap();
+
+        assert_eq!(
+            diff,
+            "\n    Diff:\n".to_string()
+                + "               actual                  expected\n"
+                + "          [0]  1                       1\n"
+                + "        ✗ [1]  448378203247 ('hello')  2\n"
+                + "        ✗ [2]  3                       <missing>\n"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::compiled_runnable::{CompiledTestCrateRunnable, FuzzerConfig, TestCaseRunnable};
 use crate::fuzzer::RandomFuzzer;
-use crate::printing::print_test_result;
+use crate::printing::{print_test_result, Progress};
 use crate::running::{run_fuzz_test, run_test};
 use crate::test_case_summary::TestCaseSummary;
 use crate::test_crate_summary::TestCrateSummary;
@@ -22,10 +22,11 @@ use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::Contr
 use std::collections::HashMap;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
 use tokio::sync::mpsc::{channel, Sender};
 use tokio::task::JoinHandle;
-use universal_sierra_compiler_api::{compile_sierra_to_casm, AssembledProgramWithDebugInfo};
+use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 pub mod build_trace_data;
 pub mod compiled_runnable;
@@ -34,6 +35,7 @@ pub mod profiler_api;
 pub mod test_case_summary;
 pub mod test_crate_summary;
 
+mod assertion_diff;
 mod fuzzer;
 mod gas;
 mod printing;
@@ -82,6 +84,21 @@ impl ExecutionDataToSave {
     }
 }
 
+/// How much per-test detail is printed while running tests
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OutputVerbosity {
+    /// Only failed tests are printed
+    Quiet,
+    #[default]
+    Normal,
+    /// Detailed resources are printed for every passed test
+    Verbose,
+    /// Like `Verbose`, storage reads and writes made by failed tests are printed as well
+    FailedTrace,
+    /// Like `Verbose`, storage reads and writes made by every test are printed as well
+    Trace,
+}
+
 /// Configuration of the test runner
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
@@ -93,6 +110,8 @@ pub struct RunnerConfig {
     pub detailed_resources: bool,
     pub execution_data_to_save: ExecutionDataToSave,
     pub max_n_steps: Option<u32>,
+    pub max_recursion_depth: Option<usize>,
+    pub output_verbosity: OutputVerbosity,
 }
 
 impl RunnerConfig {
@@ -107,6 +126,8 @@ impl RunnerConfig {
         save_trace_data: bool,
         build_profile: bool,
         max_n_steps: Option<u32>,
+        max_recursion_depth: Option<usize>,
+        output_verbosity: OutputVerbosity,
     ) -> Self {
         Self {
             workspace_root,
@@ -116,6 +137,8 @@ impl RunnerConfig {
             detailed_resources,
             execution_data_to_save: ExecutionDataToSave::from_flags(save_trace_data, build_profile),
             max_n_steps,
+            max_recursion_depth,
+            output_verbosity,
         }
     }
 }
@@ -141,10 +164,11 @@ impl RunnerParams {
 }
 
 /// Exit status of the runner
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 #[non_exhaustive]
 pub enum RunnerStatus {
     /// Runner exited without problems
+    #[default]
     Default,
     /// Some test failed
     TestFailed,
@@ -154,6 +178,11 @@ pub enum RunnerStatus {
 
 pub trait TestCaseFilter {
     fn should_be_run(&self, test_case: &TestCaseRunnable) -> bool;
+
+    /// Whether the test case should be scheduled before other test cases of the crate
+    fn should_be_run_first(&self, _test_case: &TestCaseRunnable) -> bool {
+        false
+    }
 }
 
 #[non_exhaustive]
@@ -162,17 +191,32 @@ pub enum TestCrateRunResult {
     Interrupted(TestCrateSummary),
 }
 
+/// Runs test cases from the crate, passing each summary with the time spent executing the test case
+/// to `report` as soon as the test case finishes
+///
+/// Test cases selected by [`TestCaseFilter::should_be_run_first`] are scheduled first,
+/// failed test cases are still kept in the order they are defined in.
+/// Only failed test cases are kept in the returned [`TestCrateSummary`], results of other test cases are counted
 pub async fn run_tests_from_crate(
     tests: CompiledTestCrateRunnable,
+    casm_program: Arc<AssembledProgramWithDebugInfo>,
     runner_config: Arc<RunnerConfig>,
     runner_params: Arc<RunnerParams>,
     tests_filter: &impl TestCaseFilter,
+    report: &mut impl FnMut(&AnyTestCaseSummary, Duration),
 ) -> Result<TestCrateRunResult> {
     let sierra_program = &tests.sierra_program;
-    let casm_program = Arc::new(compile_sierra_to_casm(sierra_program)?);
 
     let mut tasks = FuturesUnordered::new();
     let test_cases = tests.test_cases;
+    let definition_order: HashMap<String, usize> = test_cases
+        .iter()
+        .enumerate()
+        .map(|(index, case)| (case.name.clone(), index))
+        .collect();
+    let (run_first, run_later): (Vec<_>, Vec<_>) = test_cases
+        .into_iter()
+        .partition(|case| tests_filter.should_be_run_first(case));
     // Initiate two channels to manage the `--exit-first` flag.
     // Owing to `cheatnet` fork's utilization of its own Tokio runtime for RPC requests,
     // test execution must occur within a `tokio::spawn_blocking`.
@@ -180,15 +224,16 @@ pub async fn run_tests_from_crate(
     // a channel is used to signal the task that test processing is no longer necessary.
     let (send, mut rec) = channel(1);
 
-    for case in test_cases {
+    for case in run_first.into_iter().chain(run_later) {
         let case_name = case.name.clone();
 
         if !tests_filter.should_be_run(&case) {
             tasks.push(tokio::task::spawn(async {
                 // TODO TestCaseType should also be encoded in the test case definition
-                Ok(AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
-                    name: case_name,
-                }))
+                Ok((
+                    AnyTestCaseSummary::Single(TestCaseSummary::Ignored { name: case_name }),
+                    Duration::ZERO,
+                ))
             }));
             continue;
         };
@@ -214,27 +259,31 @@ pub async fn run_tests_from_crate(
         ));
     }
 
-    let mut results = vec![];
+    let mut summary = TestCrateSummary::default();
     let mut interrupted = false;
+    let mut progress = Progress::new(tasks.len());
 
     while let Some(task) = tasks.next().await {
-        let result = task??;
+        let (result, duration) = task??;
 
+        progress.clear();
         print_test_result(&result, &runner_config);
+        progress.update(&result);
         maybe_save_execution_data(&result, runner_config.execution_data_to_save)?;
+        report(&result, duration);
 
         if result.is_failed() && runner_config.exit_first {
             interrupted = true;
             rec.close();
         }
 
-        results.push(result);
+        summary.add(result);
     }
-
-    let summary = TestCrateSummary {
-        test_case_summaries: results,
-        runner_exit_status: RunnerStatus::Default,
-    };
+    progress.clear();
+    summary.failed_test_case_summaries.sort_by_key(|case| {
+        case.name()
+            .and_then(|name| definition_order.get(name).copied())
+    });
 
     if interrupted {
         Ok(TestCrateRunResult::Interrupted(summary))
@@ -273,18 +322,19 @@ fn choose_test_strategy_and_run(
     runner_config: Arc<RunnerConfig>,
     runner_params: Arc<RunnerParams>,
     send: Sender<()>,
-) -> JoinHandle<Result<AnyTestCaseSummary>> {
+) -> JoinHandle<Result<(AnyTestCaseSummary, Duration)>> {
     if args.is_empty() {
         tokio::task::spawn(async move {
-            let res = run_test(case, casm_program, runner_config, runner_params, send).await??;
-            Ok(AnyTestCaseSummary::Single(res))
+            let (res, duration) =
+                run_test(case, casm_program, runner_config, runner_params, send).await??;
+            Ok((AnyTestCaseSummary::Single(res), duration))
         })
     } else {
         tokio::task::spawn(async move {
-            let res =
+            let (res, duration) =
                 run_with_fuzzing(args, case, casm_program, runner_config, runner_params, send)
                     .await??;
-            Ok(AnyTestCaseSummary::Fuzzing(res))
+            Ok((AnyTestCaseSummary::Fuzzing(res), duration))
         })
     }
 }
@@ -296,10 +346,10 @@ fn run_with_fuzzing(
     runner_config: Arc<RunnerConfig>,
     runner_params: Arc<RunnerParams>,
     send: Sender<()>,
-) -> JoinHandle<Result<TestCaseSummary<Fuzzing>>> {
+) -> JoinHandle<Result<(TestCaseSummary<Fuzzing>, Duration)>> {
     tokio::task::spawn(async move {
         if send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok((TestCaseSummary::Skipped {}, Duration::ZERO));
         }
 
         let (fuzzing_send, mut fuzzing_rec) = channel(1);
@@ -338,42 +388,47 @@ fn run_with_fuzzing(
             ));
         }
 
-        let mut results = vec![];
+        // Only gas usages are kept from passed runs, so memory does not grow with the number of runs
+        let mut gas_usages = vec![];
+        let mut runs = 0;
+        let mut duration = Duration::ZERO;
+        let mut last_result = None;
         while let Some(task) = tasks.next().await {
-            let result = task??;
-
-            results.push(result.clone());
+            let (result, run_duration) = task??;
+            duration += run_duration;
+
+            match &result {
+                TestCaseSummary::Passed { gas_info, .. } => {
+                    gas_usages.push(*gas_info);
+                    runs += 1;
+                }
+                TestCaseSummary::Failed { .. } => runs += 1,
+                TestCaseSummary::Ignored { .. } | TestCaseSummary::Skipped {} => {}
+            }
+            let failed = matches!(result, TestCaseSummary::Failed { .. });
+            last_result = Some(result);
 
-            if let TestCaseSummary::Failed { .. } = result {
+            if failed {
                 fuzzing_rec.close();
                 break;
             }
         }
 
-        let runs = u32::try_from(
-            results
-                .iter()
-                .filter(|item| {
-                    matches!(
-                        item,
-                        TestCaseSummary::Passed { .. } | TestCaseSummary::Failed { .. }
-                    )
-                })
-                .count(),
-        )?;
-
-        let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
+        let last_result = last_result.expect("Fuzz test should always run at least once");
+        let fuzzing_run_summary: TestCaseSummary<Fuzzing> =
+            TestCaseSummary::from_last_run(last_result, &gas_usages, runs);
+        let runs = u32::try_from(runs)?;
 
         if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
             // Because we execute tests parallel, it's possible to
             // get Passed after Skipped. To treat fuzzing a test as Passed
             // we have to ensure that all fuzzing subtests Passed
             if runs != fuzzer_runs.get() {
-                return Ok(TestCaseSummary::Skipped {});
+                return Ok((TestCaseSummary::Skipped {}, duration));
             };
         };
 
-        Ok(fuzzing_run_summary)
+        Ok((fuzzing_run_summary, duration))
     })
 }
 
@@ -1,9 +1,53 @@
 use crate::{
     test_case_summary::{AnyTestCaseSummary, FuzzingStatistics, TestCaseSummary},
-    RunnerConfig,
+    OutputVerbosity, RunnerConfig,
 };
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
-use console::style;
+use console::{style, Term};
+
+/// Live `n passed / m failed / k remaining` indicator, shown only when stderr is a terminal
+pub(crate) struct Progress {
+    term: Option<Term>,
+    remaining: usize,
+    passed: usize,
+    failed: usize,
+}
+
+impl Progress {
+    pub(crate) fn new(tests_num: usize) -> Self {
+        let term = Term::stderr();
+        Self {
+            term: term.is_term().then_some(term),
+            remaining: tests_num,
+            passed: 0,
+            failed: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, any_test_result: &AnyTestCaseSummary) {
+        self.remaining = self.remaining.saturating_sub(1);
+        if any_test_result.is_passed() {
+            self.passed += 1;
+        } else if any_test_result.is_failed() {
+            self.failed += 1;
+        }
+
+        if let Some(term) = &self.term {
+            if self.remaining > 0 {
+                let _ = term.write_str(&format!(
+                    "{} passed / {} failed / {} remaining",
+                    self.passed, self.failed, self.remaining
+                ));
+            }
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        if let Some(term) = &self.term {
+            let _ = term.clear_line();
+        }
+    }
+}
 
 pub(crate) fn print_test_result(
     any_test_result: &AnyTestCaseSummary,
@@ -12,6 +56,9 @@ pub(crate) fn print_test_result(
     if any_test_result.is_skipped() {
         return;
     }
+    if runner_config.output_verbosity == OutputVerbosity::Quiet && !any_test_result.is_failed() {
+        return;
+    }
     let result_header = result_header(any_test_result);
     let result_name = any_test_result.name().unwrap();
 
@@ -45,7 +92,12 @@ pub(crate) fn print_test_result(
         _ => String::new(),
     };
 
-    let used_resources = match (runner_config.detailed_resources, any_test_result) {
+    let detailed_resources = runner_config.detailed_resources
+        || matches!(
+            runner_config.output_verbosity,
+            OutputVerbosity::Verbose | OutputVerbosity::FailedTrace | OutputVerbosity::Trace
+        );
+    let used_resources = match (detailed_resources, any_test_result) {
         (true, AnyTestCaseSummary::Single(TestCaseSummary::Passed { used_resources, .. })) => {
             format_detailed_resources(used_resources)
         }
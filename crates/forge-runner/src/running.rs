@@ -4,11 +4,12 @@ use std::default::Default;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::compiled_runnable::ValidatedForkConfig;
 use crate::gas::calculate_used_gas;
 use crate::test_case_summary::{Single, TestCaseSummary};
-use crate::{RunnerConfig, RunnerParams, TestCaseRunnable, CACHE_DIR};
+use crate::{OutputVerbosity, RunnerConfig, RunnerParams, TestCaseRunnable, CACHE_DIR};
 use anyhow::{bail, ensure, Result};
 use blockifier::execution::entry_point::EntryPointExecutionContext;
 use blockifier::execution::execution_utils::ReadOnlySegments;
@@ -44,39 +45,56 @@ use cheatnet::runtime_extensions::forge_runtime_extension::{
     update_top_call_vm_trace, ForgeExtension, ForgeRuntime,
 };
 use cheatnet::state::{BlockInfoReader, CallTrace, CheatnetState, ExtendedStateReader};
-use runtime::starknet::context::{build_context, set_max_steps};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use runtime::starknet::context::{
+    build_context_with_constants, build_versioned_constants, set_max_steps,
+};
 use runtime::{ExtendedRuntime, StarknetRuntime};
+use semver::Version;
+use sha2::{Digest, Sha256};
 use tokio::sync::mpsc::Sender;
 use tokio::task::JoinHandle;
 use universal_sierra_compiler_api::{
     AssembledCairoProgramWithSerde, AssembledProgramWithDebugInfo,
 };
 
+/// Runs the test case, returning its summary with the time spent executing it
 pub fn run_test(
     case: Arc<TestCaseRunnable>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     runner_config: Arc<RunnerConfig>,
     runner_params: Arc<RunnerParams>,
     send: Sender<()>,
-) -> JoinHandle<Result<TestCaseSummary<Single>>> {
+) -> JoinHandle<Result<(TestCaseSummary<Single>, Duration)>> {
     tokio::task::spawn_blocking(move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok((TestCaseSummary::Skipped {}, Duration::ZERO));
         }
+        let start = Instant::now();
         let run_result =
             run_test_case(vec![], &case, &casm_program, &runner_config, &runner_params);
+        let duration = start.elapsed();
 
         // TODO: code below is added to fix snforge tests
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok((TestCaseSummary::Skipped {}, duration));
         }
 
-        extract_test_case_summary(run_result, &case, vec![], &runner_params.contracts_data)
+        let summary = extract_test_case_summary(
+            run_result,
+            &case,
+            vec![],
+            &runner_params.contracts_data,
+            runner_config.output_verbosity,
+            runner_config.fuzzer_seed,
+        )?;
+        Ok((summary, duration))
     })
 }
 
@@ -89,15 +107,16 @@ pub(crate) fn run_fuzz_test(
     runner_params: Arc<RunnerParams>,
     send: Sender<()>,
     fuzzing_send: Sender<()>,
-) -> JoinHandle<Result<TestCaseSummary<Single>>> {
+) -> JoinHandle<Result<(TestCaseSummary<Single>, Duration)>> {
     tokio::task::spawn_blocking(move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() | fuzzing_send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok((TestCaseSummary::Skipped {}, Duration::ZERO));
         }
 
+        let start = Instant::now();
         let run_result = run_test_case(
             args.clone(),
             &case,
@@ -105,15 +124,24 @@ pub(crate) fn run_fuzz_test(
             &runner_config,
             &runner_params,
         );
+        let duration = start.elapsed();
 
         // TODO: code below is added to fix snforge tests
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return Ok(TestCaseSummary::Skipped {});
+            return Ok((TestCaseSummary::Skipped {}, duration));
         }
 
-        extract_test_case_summary(run_result, &case, args, &runner_params.contracts_data)
+        let summary = extract_test_case_summary(
+            run_result,
+            &case,
+            args,
+            &runner_params.contracts_data,
+            runner_config.output_verbosity,
+            runner_config.fuzzer_seed,
+        )?;
+        Ok((summary, duration))
     })
 }
 
@@ -157,6 +185,34 @@ pub struct RunResultWithInfo {
     pub(crate) call_trace: Rc<RefCell<CallTrace>>,
     pub(crate) gas_used: u128,
     pub(crate) used_resources: UsedResources,
+    /// Failure messages of `expect_call` expectations which were not met
+    pub(crate) unmet_expected_calls: Vec<String>,
+    /// Storage reads and writes made during the test, one line per access,
+    /// `None` if they are not printed with the current verbosity
+    pub(crate) storage_trace: Option<Vec<String>>,
+    /// Whether the test generated values with `rand_felt` or `rand_in_range`
+    pub(crate) used_random_values: bool,
+}
+
+/// Seed of the generator used by `rand_felt` and `rand_in_range`, derived from the seed of the run
+/// so every test and every run of a fuzz test gets its own reproducible values
+///
+/// A cryptographic hash is used as it is stable across Rust versions and platforms
+fn test_rng_seed(run_seed: u64, test_name: &str, args: &[Felt252]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(run_seed.to_le_bytes());
+    hasher.update((test_name.len() as u64).to_le_bytes());
+    hasher.update(test_name);
+    for arg in args {
+        hasher.update(arg.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+
+    u64::from_le_bytes(
+        digest[..8]
+            .try_into()
+            .expect("Sha256 digest should have at least 8 bytes"),
+    )
 }
 
 #[allow(clippy::too_many_lines)]
@@ -172,6 +228,7 @@ pub fn run_test_case(
         "\n\t`available_gas` attribute was incorrectly configured. Make sure you use scarb >= 2.4.4\n"
     );
 
+    let rng_seed = test_rng_seed(runner_config.fuzzer_seed, &case.name, &args);
     let initial_gas = usize::MAX;
     let runner_args: Vec<Arg> = args.into_iter().map(Arg::Value).collect();
     let (entry_code, builtins) = SierraCasmRunner::create_entry_code_from_params(
@@ -196,7 +253,9 @@ pub fn run_test_case(
     };
     let block_info = state_reader.get_block_info()?;
 
-    let mut context = build_context(&block_info);
+    let versioned_constants =
+        build_versioned_constants(runner_config.max_n_steps, runner_config.max_recursion_depth);
+    let mut context = build_context_with_constants(&block_info, &versioned_constants);
 
     if let Some(max_n_steps) = runner_config.max_n_steps {
         set_max_steps(&mut context, max_n_steps);
@@ -216,6 +275,7 @@ pub fn run_test_case(
 
     let mut cheatnet_state = CheatnetState {
         block_info,
+        rng: StdRng::seed_from_u64(rng_seed),
         ..Default::default()
     };
     cheatnet_state.trace_data.is_vm_trace_needed =
@@ -236,9 +296,12 @@ pub fn run_test_case(
         },
         extended_runtime: cheatable_runtime,
     };
+    let snforge_version =
+        Version::parse(env!("CARGO_PKG_VERSION")).expect("Failed to parse snforge version");
     let forge_extension = ForgeExtension {
         environment_variables: &runner_params.environment_variables,
         contracts_data: &runner_params.contracts_data,
+        snforge_version: &snforge_version,
     };
 
     let mut forge_runtime = ExtendedRuntime {
@@ -322,6 +385,14 @@ pub fn run_test_case(
         gas_used: gas,
         used_resources,
         call_trace: call_trace_ref,
+        unmet_expected_calls: cheatnet_state.unmet_expected_calls(),
+        // Formatting every access is costly, so the trace is built only when it can be printed
+        storage_trace: matches!(
+            runner_config.output_verbosity,
+            OutputVerbosity::FailedTrace | OutputVerbosity::Trace
+        )
+        .then(|| cheatnet_state.storage_trace()),
+        used_random_values: cheatnet_state.used_random_values,
     })
 }
 
@@ -330,21 +401,26 @@ fn extract_test_case_summary(
     case: &TestCaseRunnable,
     args: Vec<Felt252>,
     contracts_data: &ContractsData,
+    output_verbosity: OutputVerbosity,
+    run_seed: u64,
 ) -> Result<TestCaseSummary<Single>> {
     match run_result {
         Ok(result_with_info) => {
-            match result_with_info.run_result {
-                Ok(run_result) => Ok(TestCaseSummary::from_run_result_and_info(
-                    run_result,
-                    case,
-                    args,
-                    result_with_info.gas_used,
-                    result_with_info.used_resources,
-                    &result_with_info.call_trace,
-                    contracts_data,
-                )),
+            let summary = match result_with_info.run_result {
+                Ok(run_result) => check_expected_calls(
+                    &result_with_info.unmet_expected_calls,
+                    TestCaseSummary::from_run_result_and_info(
+                        run_result,
+                        case,
+                        args,
+                        result_with_info.gas_used,
+                        result_with_info.used_resources,
+                        &result_with_info.call_trace,
+                        contracts_data,
+                    ),
+                ),
                 // CairoRunError comes from VirtualMachineError which may come from HintException that originates in TestExecutionSyscallHandler
-                Err(RunnerError::CairoRunError(error)) => Ok(TestCaseSummary::Failed {
+                Err(RunnerError::CairoRunError(error)) => TestCaseSummary::Failed {
                     name: case.name.clone(),
                     msg: Some(format!(
                         "\n    {}\n",
@@ -352,8 +428,21 @@ fn extract_test_case_summary(
                     )),
                     arguments: args,
                     test_statistics: (),
-                }),
+                },
                 Err(err) => bail!(err),
+            };
+            let summary = if result_with_info.used_random_values {
+                append_rng_seed(run_seed, summary)
+            } else {
+                summary
+            };
+            match &result_with_info.storage_trace {
+                Some(storage_trace) => Ok(append_storage_trace(
+                    storage_trace,
+                    output_verbosity == OutputVerbosity::FailedTrace,
+                    summary,
+                )),
+                None => Ok(summary),
             }
         }
         // `ForkStateReader.get_block_info`, `get_fork_state_reader, `calculate_used_gas` may return an error
@@ -367,6 +456,58 @@ fn extract_test_case_summary(
     }
 }
 
+/// Appends storage reads and writes made during the test to its message,
+/// passed tests are left unchanged if `only_failed` is set
+fn append_storage_trace(
+    storage_trace: &[String],
+    only_failed: bool,
+    mut summary: TestCaseSummary<Single>,
+) -> TestCaseSummary<Single> {
+    let msg = match &mut summary {
+        TestCaseSummary::Failed { msg, .. } => Some(msg),
+        TestCaseSummary::Passed { msg, .. } if !only_failed => Some(msg),
+        _ => None,
+    };
+    if let Some(msg) = msg {
+        let accesses = if storage_trace.is_empty() {
+            " none".to_string()
+        } else {
+            format!("\n      {}", storage_trace.join("\n      "))
+        };
+        msg.get_or_insert_with(String::new)
+            .push_str(&format!("\n    Storage accesses:{accesses}\n"));
+    }
+    summary
+}
+
+/// Appends the seed of the run to the message of a failed test, so its random values can be reproduced
+fn append_rng_seed(run_seed: u64, mut summary: TestCaseSummary<Single>) -> TestCaseSummary<Single> {
+    if let TestCaseSummary::Failed { msg, .. } = &mut summary {
+        msg.get_or_insert_with(String::new).push_str(&format!(
+            "\n    Random values were generated with seed = {run_seed}, pass it to `--rng-seed` to reproduce them\n"
+        ));
+    }
+    summary
+}
+
+/// Fails a passed test if some of its `expect_call` expectations were not met
+fn check_expected_calls(
+    unmet_expected_calls: &[String],
+    summary: TestCaseSummary<Single>,
+) -> TestCaseSummary<Single> {
+    match summary {
+        TestCaseSummary::Passed {
+            name, arguments, ..
+        } if !unmet_expected_calls.is_empty() => TestCaseSummary::Failed {
+            name,
+            msg: Some(format!("\n    {}\n", unmet_expected_calls.join("\n    "))),
+            arguments,
+            test_statistics: (),
+        },
+        _ => summary,
+    }
+}
+
 fn get_fork_state_reader(
     workspace_root: &Utf8Path,
     fork_config: &Option<ValidatedForkConfig>,
@@ -1,3 +1,4 @@
+use crate::assertion_diff::build_assertion_diff;
 use crate::build_trace_data::build_profiler_call_trace;
 use crate::compiled_runnable::TestCaseRunnable;
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
@@ -120,7 +121,7 @@ pub enum TestCaseSummary<T: TestType> {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum AnyTestCaseSummary {
     Fuzzing(TestCaseSummary<Fuzzing>),
     Single(TestCaseSummary<Single>),
@@ -148,14 +149,12 @@ impl<T: TestType> TestCaseSummary<T> {
 }
 
 impl TestCaseSummary<Fuzzing> {
+    /// Builds the summary of a fuzz test from the result of its last run
+    ///
+    /// Only the last result matters as fuzzing is cancelled after first fail,
+    /// `gas_usages` are collected from all passed runs and `runs` counts passed and failed runs
     #[must_use]
-    pub fn from(results: Vec<TestCaseSummary<Single>>) -> Self {
-        let last: TestCaseSummary<Single> = results
-            .iter()
-            .last()
-            .cloned()
-            .expect("Fuzz test should always run at least once");
-        // Only the last result matters as fuzzing is cancelled after first fail
+    pub fn from_last_run(last: TestCaseSummary<Single>, gas_usages: &[u128], runs: usize) -> Self {
         match last {
             TestCaseSummary::Passed {
                 name,
@@ -165,26 +164,15 @@ impl TestCaseSummary<Fuzzing> {
                 used_resources: _,
                 test_statistics: (),
                 trace_data: _,
-            } => {
-                let runs = results.len();
-                let gas_usages: Vec<u128> = results
-                    .into_iter()
-                    .map(|a| match a {
-                        TestCaseSummary::Passed { gas_info, .. } => gas_info,
-                        _ => unreachable!(),
-                    })
-                    .collect();
-
-                TestCaseSummary::Passed {
-                    name,
-                    msg,
-                    arguments,
-                    gas_info: GasStatistics::new(&gas_usages),
-                    used_resources: UsedResources::default(),
-                    test_statistics: FuzzingStatistics { runs },
-                    trace_data: (),
-                }
-            }
+            } => TestCaseSummary::Passed {
+                name,
+                msg,
+                arguments,
+                gas_info: GasStatistics::new(gas_usages),
+                used_resources: UsedResources::default(),
+                test_statistics: FuzzingStatistics { runs },
+                trace_data: (),
+            },
             TestCaseSummary::Failed {
                 name,
                 msg,
@@ -194,11 +182,9 @@ impl TestCaseSummary<Fuzzing> {
                 name,
                 msg,
                 arguments,
-                test_statistics: FuzzingStatistics {
-                    runs: results.len(),
-                },
+                test_statistics: FuzzingStatistics { runs },
             },
-            TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
+            TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name },
             TestCaseSummary::Skipped {} => TestCaseSummary::Skipped {},
         }
     }
@@ -316,7 +302,12 @@ fn extract_result_data(run_result: &RunResult, expectation: &ExpectedTestResult)
                         format_args!("Expected:  {expected:?} ({expected_string})")
                     ))
                 }
-                None => build_readable_text(panic_data),
+                None => build_readable_text(panic_data).map(|text| {
+                    match build_assertion_diff(panic_data) {
+                        Some(diff) => text + &diff,
+                        None => text,
+                    }
+                }),
             }
         }
     }
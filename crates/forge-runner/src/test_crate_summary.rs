@@ -2,44 +2,49 @@ use crate::test_case_summary::AnyTestCaseSummary;
 use crate::RunnerStatus;
 
 /// Summary of the test run in the file
-#[derive(Debug)]
+///
+/// Only failed test cases are kept, results of other test cases are counted
+#[derive(Debug, Default)]
 pub struct TestCrateSummary {
-    /// Summaries of each test case in the file
-    pub test_case_summaries: Vec<AnyTestCaseSummary>,
+    /// Summaries of failed test cases in the file
+    pub failed_test_case_summaries: Vec<AnyTestCaseSummary>,
     /// Status of the runner after executing tests in the file
     pub runner_exit_status: RunnerStatus,
+    passed: usize,
+    skipped: usize,
+    ignored: usize,
 }
 
 impl TestCrateSummary {
+    pub(crate) fn add(&mut self, test_case_summary: AnyTestCaseSummary) {
+        if test_case_summary.is_passed() {
+            self.passed += 1;
+        } else if test_case_summary.is_failed() {
+            self.failed_test_case_summaries.push(test_case_summary);
+        } else if test_case_summary.is_skipped() {
+            self.skipped += 1;
+        } else if test_case_summary.is_ignored() {
+            self.ignored += 1;
+        }
+    }
+
     #[must_use]
     pub fn count_passed(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_passed())
-            .count()
+        self.passed
     }
 
     #[must_use]
     pub fn count_failed(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_failed())
-            .count()
+        self.failed_test_case_summaries.len()
     }
 
     #[must_use]
     pub fn count_skipped(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_skipped())
-            .count()
+        self.skipped
     }
 
     #[must_use]
     pub fn count_ignored(&self) -> usize {
-        self.test_case_summaries
-            .iter()
-            .filter(|tu| tu.is_ignored())
-            .count()
+        self.ignored
     }
 }
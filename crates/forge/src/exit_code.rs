@@ -0,0 +1,62 @@
+use thiserror::Error;
+
+/// Exit code of `snforge`, allowing to tell failure classes apart without parsing its output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    Success = 0,
+    TestsFailed = 1,
+    /// Any failure not belonging to one of the classes below, including invalid arguments
+    InternalError = 2,
+    /// Building tests or contracts, or compiling them to CASM failed
+    CompilationError = 3,
+    /// Loading test artifacts or resolving the configuration of test cases failed
+    CollectionError = 4,
+}
+
+impl ExitCode {
+    /// Exit code for an error returned from `snforge`, `InternalError` if it was not classified
+    #[must_use]
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        match error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ForgeError>())
+        {
+            Some(ForgeError::Compilation(_)) => ExitCode::CompilationError,
+            Some(ForgeError::Collection(_)) => ExitCode::CollectionError,
+            None => ExitCode::InternalError,
+        }
+    }
+}
+
+/// Error classified with the stage of `snforge` it occurred in
+#[derive(Debug, Error)]
+pub enum ForgeError {
+    #[error(transparent)]
+    Compilation(anyhow::Error),
+    #[error(transparent)]
+    Collection(anyhow::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{anyhow, Context, Error};
+
+    #[test]
+    fn classified_errors() {
+        let error = Error::from(ForgeError::Compilation(anyhow!("scarb exited with error")));
+        assert_eq!(ExitCode::from_error(&error), ExitCode::CompilationError);
+        assert_eq!(format!("{error:#}"), "scarb exited with error");
+
+        let error = Err::<(), _>(ForgeError::Collection(anyhow!("missing artifacts")))
+            .context("Failed to run tests")
+            .unwrap_err();
+        assert_eq!(ExitCode::from_error(&error), ExitCode::CollectionError);
+    }
+
+    #[test]
+    fn unclassified_error() {
+        let error = anyhow!("Failed to find any packages matching the specified filter");
+        assert_eq!(ExitCode::from_error(&error), ExitCode::InternalError);
+    }
+}
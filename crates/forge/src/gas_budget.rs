@@ -0,0 +1,106 @@
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use std::collections::HashMap;
+
+/// Soft gas limits, tests exceeding them still pass but are reported in the summary
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct GasBudgets {
+    /// Budget of every test in the package without its own budget
+    pub package: Option<u64>,
+    /// Budgets of single tests, by their fully qualified names
+    pub tests: HashMap<String, u64>,
+}
+
+/// Passed test which used more gas than its budget
+#[derive(Debug, PartialEq)]
+pub struct ExceededGasBudget {
+    pub test_name: String,
+    pub gas: u128,
+    pub budget: u64,
+}
+
+impl GasBudgets {
+    #[must_use]
+    pub fn budget_for(&self, test_name: &str) -> Option<u64> {
+        self.tests.get(test_name).copied().or(self.package)
+    }
+
+    /// Returns the exceeded budget if the test passed using more gas than allowed,
+    /// maximal gas of all runs is compared for fuzz tests
+    #[must_use]
+    pub fn check(&self, test_case: &AnyTestCaseSummary) -> Option<ExceededGasBudget> {
+        let (name, gas) = match test_case {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { name, gas_info, .. }) => {
+                (name, *gas_info)
+            }
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { name, gas_info, .. }) => {
+                (name, gas_info.max)
+            }
+            _ => return None,
+        };
+        let budget = self.budget_for(name)?;
+
+        (gas > u128::from(budget)).then(|| ExceededGasBudget {
+            test_name: name.clone(),
+            gas,
+            budget,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
+    use forge_runner::test_case_summary::{FuzzingStatistics, GasStatistics};
+
+    fn passed(name: &str, gas: u128) -> AnyTestCaseSummary {
+        AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+            name: name.to_string(),
+            msg: None,
+            arguments: vec![],
+            gas_info: GasStatistics {
+                max: gas,
+                ..Default::default()
+            },
+            used_resources: UsedResources::default(),
+            test_statistics: FuzzingStatistics { runs: 1 },
+            trace_data: (),
+        })
+    }
+
+    #[test]
+    fn test_budget_takes_precedence_over_package_budget() {
+        let budgets = GasBudgets {
+            package: Some(100),
+            tests: HashMap::from([("tests::heavy".to_string(), 1000)]),
+        };
+
+        assert_eq!(budgets.budget_for("tests::heavy"), Some(1000));
+        assert_eq!(budgets.budget_for("tests::light"), Some(100));
+        assert_eq!(GasBudgets::default().budget_for("tests::light"), None);
+    }
+
+    #[test]
+    fn reports_only_exceeded_budgets() {
+        let budgets = GasBudgets {
+            package: Some(100),
+            tests: HashMap::new(),
+        };
+
+        assert_eq!(budgets.check(&passed("tests::light", 100)), None);
+        assert_eq!(
+            budgets.check(&passed("tests::heavy", 101)),
+            Some(ExceededGasBudget {
+                test_name: "tests::heavy".to_string(),
+                gas: 101,
+                budget: 100,
+            })
+        );
+        assert_eq!(
+            budgets.check(&AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                name: "tests::ignored".to_string()
+            })),
+            None
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{ensure, Context, Result};
+use forge_runner::test_crate_summary::TestCrateSummary;
+use serde::Deserialize;
+use std::process::Command;
+
+/// Shell commands executed around running tests of a package, defined in `[tool.snforge.hooks]`
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct Hooks {
+    /// Executed before tests of the package are run
+    pub pre_run: Option<String>,
+    /// Executed after tests of the package are run, with their outcome passed in env variables
+    pub post_run: Option<String>,
+}
+
+/// Outcome of running tests of a package, passed to the `post_run` hook
+#[derive(Debug, PartialEq)]
+pub struct RunOutcome {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub ignored: usize,
+}
+
+impl RunOutcome {
+    #[must_use]
+    pub fn from_summaries(summaries: &[TestCrateSummary]) -> Self {
+        RunOutcome {
+            passed: summaries.iter().map(TestCrateSummary::count_passed).sum(),
+            failed: summaries.iter().map(TestCrateSummary::count_failed).sum(),
+            skipped: summaries.iter().map(TestCrateSummary::count_skipped).sum(),
+            ignored: summaries.iter().map(TestCrateSummary::count_ignored).sum(),
+        }
+    }
+}
+
+impl Hooks {
+    #[must_use]
+    pub fn merge_with_global(self, global: Hooks) -> Self {
+        Hooks {
+            pre_run: self.pre_run.or(global.pre_run),
+            post_run: self.post_run.or(global.post_run),
+        }
+    }
+
+    /// Runs the `pre_run` hook, fails if it exits with a non-zero code
+    pub fn run_pre_run(&self, package_name: &str) -> Result<()> {
+        match &self.pre_run {
+            Some(command) => run_hook(
+                "pre_run",
+                command,
+                &[("SNFORGE_PACKAGE_NAME", package_name.to_string())],
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs the `post_run` hook, fails if it exits with a non-zero code
+    ///
+    /// `outcome` is `None` when running tests of the package failed with an error
+    pub fn run_post_run(&self, package_name: &str, outcome: Option<&RunOutcome>) -> Result<()> {
+        let Some(command) = &self.post_run else {
+            return Ok(());
+        };
+
+        let mut envs = vec![("SNFORGE_PACKAGE_NAME", package_name.to_string())];
+        match outcome {
+            Some(outcome) => {
+                let result = if outcome.failed == 0 {
+                    "passed"
+                } else {
+                    "failed"
+                };
+                envs.extend([
+                    ("SNFORGE_TESTS_RESULT", result.to_string()),
+                    ("SNFORGE_TESTS_PASSED", outcome.passed.to_string()),
+                    ("SNFORGE_TESTS_FAILED", outcome.failed.to_string()),
+                    ("SNFORGE_TESTS_SKIPPED", outcome.skipped.to_string()),
+                    ("SNFORGE_TESTS_IGNORED", outcome.ignored.to_string()),
+                ]);
+            }
+            None => envs.push(("SNFORGE_TESTS_RESULT", "error".to_string())),
+        }
+
+        run_hook("post_run", command, &envs)
+    }
+}
+
+fn run_hook(name: &str, command: &str, envs: &[(&str, String)]) -> Result<()> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+
+    let status = shell
+        .arg(command)
+        .envs(envs.iter().map(|(key, value)| (key, value)))
+        .status()
+        .with_context(|| format!("Failed to run `{name}` hook"))?;
+    ensure!(
+        status.success(),
+        "`{name}` hook `{command}` failed with {status}"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_hooks_are_skipped() {
+        let hooks = Hooks::default();
+        let outcome = RunOutcome {
+            passed: 1,
+            failed: 0,
+            skipped: 0,
+            ignored: 0,
+        };
+
+        assert!(hooks.run_pre_run("package").is_ok());
+        assert!(hooks.run_post_run("package", Some(&outcome)).is_ok());
+        assert!(hooks.run_post_run("package", None).is_ok());
+    }
+
+    #[test]
+    fn failing_hook() {
+        let hooks = Hooks {
+            pre_run: Some("exit 3".to_string()),
+            post_run: None,
+        };
+
+        let error = hooks.run_pre_run("package").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("`pre_run` hook `exit 3` failed with"));
+    }
+
+    #[test]
+    fn package_hooks_take_precedence() {
+        let package = Hooks {
+            pre_run: Some("package pre".to_string()),
+            post_run: None,
+        };
+        let global = Hooks {
+            pre_run: Some("global pre".to_string()),
+            post_run: Some("global post".to_string()),
+        };
+
+        assert_eq!(
+            package.merge_with_global(global),
+            Hooks {
+                pre_run: Some("package pre".to_string()),
+                post_run: Some("global post".to_string()),
+            }
+        );
+    }
+}
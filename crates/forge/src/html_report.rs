@@ -0,0 +1,206 @@
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use std::fmt::Write;
+use std::fs;
+use std::time::Duration;
+
+const STYLE: &str = r"
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.4em 0.8em; border-bottom: 1px solid #ddd; vertical-align: top; }
+th { background: #f4f4f4; }
+.filters { margin: 1em 0; }
+.filters input { width: 30em; }
+.passed .status { color: #1a7f37; }
+.failed .status { color: #cf222e; }
+.ignored .status { color: #9a6700; }
+pre { background: #f6f8fa; padding: 0.8em; overflow-x: auto; }
+";
+
+const SCRIPT: &str = r#"
+const nameFilter = document.getElementById("name-filter");
+const statusFilter = document.getElementById("status-filter");
+function applyFilters() {
+    const name = nameFilter.value.toLowerCase();
+    const status = statusFilter.value;
+    for (const row of document.querySelectorAll("tr.test")) {
+        row.hidden = !row.dataset.name.includes(name)
+            || (status !== "" && row.dataset.status !== status);
+    }
+}
+nameFilter.addEventListener("input", applyFilters);
+statusFilter.addEventListener("change", applyFilters);
+"#;
+
+/// Result of a single test case shown in the report
+struct ReportEntry {
+    package_name: String,
+    name: String,
+    status: &'static str,
+    /// Gas used by a passed test, maximal gas of all runs for fuzz tests
+    gas: Option<u128>,
+    duration: Duration,
+    failure: Option<String>,
+}
+
+/// Single-file HTML report of test results, collected as test cases finish
+#[derive(Default)]
+pub struct HtmlReport {
+    entries: Vec<ReportEntry>,
+}
+
+impl HtmlReport {
+    pub fn add(&mut self, package_name: &str, test_case: &AnyTestCaseSummary, duration: Duration) {
+        // Skipped test cases have no name and are not shown
+        let Some(name) = test_case.name() else {
+            return;
+        };
+        let status = if test_case.is_passed() {
+            "passed"
+        } else if test_case.is_failed() {
+            "failed"
+        } else {
+            "ignored"
+        };
+        let gas = match test_case {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) => Some(*gas_info),
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(gas_info.max)
+            }
+            _ => None,
+        };
+        let failure = test_case
+            .is_failed()
+            .then(|| test_case.msg().unwrap_or_default().trim().to_string());
+
+        self.entries.push(ReportEntry {
+            package_name: package_name.to_string(),
+            name: name.to_string(),
+            status,
+            gas,
+            duration,
+            failure,
+        });
+    }
+
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut entries: Vec<&ReportEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| (&a.package_name, &a.name).cmp(&(&b.package_name, &b.name)));
+
+        let count = |status: &str| entries.iter().filter(|e| e.status == status).count();
+        let mut rows = String::new();
+        for entry in &entries {
+            write_row(&mut rows, entry);
+        }
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>snforge test report</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<h1>snforge test report</h1>
+<p>Tests: {} passed, {} failed, {} ignored</p>
+<div class="filters">
+<input id="name-filter" type="search" placeholder="Filter by test name">
+<select id="status-filter">
+<option value="">All</option>
+<option value="passed">Passed</option>
+<option value="failed">Failed</option>
+<option value="ignored">Ignored</option>
+</select>
+</div>
+<table>
+<thead><tr><th>Package</th><th>Test</th><th>Status</th><th>Gas</th><th>Duration</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<script>{SCRIPT}</script>
+</body>
+</html>
+"#,
+            count("passed"),
+            count("failed"),
+            count("ignored"),
+        )
+    }
+
+    pub fn save(&self, path: &Utf8Path) -> Result<()> {
+        fs::write(path, self.render())
+            .with_context(|| format!("Failed to save test report to {path}"))
+    }
+}
+
+fn write_row(rows: &mut String, entry: &ReportEntry) {
+    let name = escape_html(&entry.name);
+    let failure = entry.failure.as_ref().map_or_else(String::new, |failure| {
+        format!(
+            "<details><summary>Failure data</summary><pre>{}</pre></details>",
+            escape_html(failure)
+        )
+    });
+    let gas = entry.gas.map_or_else(String::new, |gas| format!("~{gas}"));
+
+    writeln!(
+        rows,
+        r#"<tr class="test {status}" data-name="{lowercase_name}" data-status="{status}"><td>{package_name}</td><td>{name}{failure}</td><td class="status">{status}</td><td>{gas}</td><td>{duration:.2} ms</td></tr>"#,
+        status = entry.status,
+        lowercase_name = name.to_lowercase(),
+        package_name = escape_html(&entry.package_name),
+        duration = entry.duration.as_secs_f64() * 1000.0,
+    )
+    .expect("Writing to a String should not fail");
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_test_results() {
+        let mut report = HtmlReport::default();
+        report.add(
+            "my_package",
+            &AnyTestCaseSummary::Single(TestCaseSummary::Failed {
+                name: "tests::failing".to_string(),
+                msg: Some("\n    0x1 ('<panic>')\n".to_string()),
+                arguments: vec![],
+                test_statistics: (),
+            }),
+            Duration::from_millis(5),
+        );
+        report.add(
+            "my_package",
+            &AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
+                name: "tests::ignored".to_string(),
+            }),
+            Duration::ZERO,
+        );
+        report.add(
+            "my_package",
+            &AnyTestCaseSummary::Single(TestCaseSummary::Skipped {}),
+            Duration::ZERO,
+        );
+
+        let html = report.render();
+
+        assert!(html.contains("Tests: 0 passed, 1 failed, 1 ignored"));
+        assert!(html.contains(r#"<tr class="test failed" data-name="tests::failing""#));
+        assert!(html.contains("<pre>0x1 (&#39;&lt;panic&gt;&#39;)</pre>"));
+        assert!(html.contains("<td>5.00 ms</td>"));
+        assert_eq!(html.matches(r#"<tr class="test"#).count(), 2);
+    }
+}
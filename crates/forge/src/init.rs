@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Context, Ok, Result};
 
+use clap::ValueEnum;
 use include_dir::{include_dir, Dir};
 
 use forge::CAIRO_EDITION;
 use scarb_api::ScarbCommand;
+use std::fmt;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::Path;
@@ -11,21 +13,48 @@ use toml_edit::{value, ArrayOfTables, Document, Item, Table};
 
 static TEMPLATE: Dir = include_dir!("starknet_forge_template");
 
+/// Project template used by `snforge init`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Template {
+    /// Simple contract with a balance that can be increased
+    #[default]
+    Basic,
+    /// ERC20 token contract
+    Erc20,
+    /// Account contract validating STARK curve signatures
+    Account,
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Template::Basic => "basic",
+            Template::Erc20 => "erc20",
+            Template::Account => "account",
+        };
+        write!(f, "{name}")
+    }
+}
+
 fn overwrite_files_from_scarb_template(
+    template: Template,
     dir_to_overwrite: &str,
     base_path: &Path,
     project_name: &str,
 ) -> Result<()> {
-    let copy_from_dir = TEMPLATE.get_dir(dir_to_overwrite).ok_or_else(|| {
+    let template_dir = format!("{template}/{dir_to_overwrite}");
+    let copy_from_dir = TEMPLATE.get_dir(&template_dir).ok_or_else(|| {
         anyhow!(
-            "Directory {} doesn't exist in the template.",
-            dir_to_overwrite
+            "Directory {} doesn't exist in the {} template.",
+            dir_to_overwrite,
+            template
         )
     })?;
 
+    fs::create_dir_all(base_path.join(Path::new(dir_to_overwrite)))?;
     for file in copy_from_dir.files() {
-        fs::create_dir_all(base_path.join(Path::new(dir_to_overwrite)))?;
-        let path = base_path.join(file.path());
+        let relative_path = file.path().strip_prefix(template.to_string())?;
+        let path = base_path.join(relative_path);
         let contents = file.contents();
         let contents = replace_project_name(contents, project_name)?;
 
@@ -56,15 +85,20 @@ fn update_config(config_path: &Path) -> Result<()> {
 }
 
 fn add_target_to_toml(document: &mut Document) {
-    let mut array_of_tables = ArrayOfTables::new();
-    let mut casm = Table::new();
     let mut contract = Table::new();
     contract.set_implicit(true);
 
+    contract.insert("starknet-contract", starknet_contract_target());
+    document.insert("target", Item::Table(contract));
+}
+
+fn starknet_contract_target() -> Item {
+    let mut array_of_tables = ArrayOfTables::new();
+    let mut casm = Table::new();
+
     casm.insert("casm", Item::Value(true.into()));
     array_of_tables.push(casm);
-    contract.insert("starknet-contract", Item::ArrayOfTables(array_of_tables));
-    document.insert("target", Item::Table(contract));
+    Item::ArrayOfTables(array_of_tables)
 }
 
 fn set_cairo_edition(document: &mut Document, cairo_edition: &str) {
@@ -80,20 +114,28 @@ fn extend_gitignore(path: &Path) -> Result<()> {
     Ok(())
 }
 
-pub fn run(project_name: &str) -> Result<()> {
-    let project_path = std::env::current_dir()?.join(project_name);
+fn extend_gitignore_if_missing(path: &Path) -> Result<()> {
+    let gitignore_path = path.join(".gitignore");
+    if gitignore_path.exists() {
+        let gitignore = fs::read_to_string(&gitignore_path)?;
+        if gitignore
+            .lines()
+            .any(|line| line.trim() == ".snfoundry_cache/")
+        {
+            return Ok(());
+        }
+    } else {
+        fs::write(&gitignore_path, "")?;
+    }
 
-    ScarbCommand::new_with_stdio()
-        .current_dir(std::env::current_dir().context("Failed to get current directory")?)
-        .arg("new")
-        .arg(&project_path)
-        .run()
-        .context("Failed to initialize a new project")?;
+    extend_gitignore(path)
+}
 
+fn add_snforge_std(project_path: &Path) -> Result<()> {
     let version = env!("CARGO_PKG_VERSION");
 
     ScarbCommand::new_with_stdio()
-        .current_dir(&project_path)
+        .current_dir(project_path)
         .offline()
         .arg("add")
         .arg("--dev")
@@ -105,19 +147,101 @@ pub fn run(project_name: &str) -> Result<()> {
         .run()
         .context("Failed to add snforge_std")?;
 
+    Ok(())
+}
+
+fn add_starknet(project_path: &Path) -> Result<()> {
     let cairo_version = ScarbCommand::version().run()?.cairo;
     ScarbCommand::new_with_stdio()
-        .current_dir(&project_path)
+        .current_dir(project_path)
         .offline()
         .arg("add")
         .arg(format!("starknet@{cairo_version}"))
         .run()
         .context("Failed to add starknet")?;
 
+    Ok(())
+}
+
+pub fn run(project_name: &str, template: Template) -> Result<()> {
+    let project_path = std::env::current_dir()?.join(project_name);
+
+    ScarbCommand::new_with_stdio()
+        .current_dir(std::env::current_dir().context("Failed to get current directory")?)
+        .arg("new")
+        .arg(&project_path)
+        .run()
+        .context("Failed to initialize a new project")?;
+
+    add_snforge_std(&project_path)?;
+    add_starknet(&project_path)?;
+
     update_config(&project_path.join("Scarb.toml"))?;
     extend_gitignore(&project_path)?;
-    overwrite_files_from_scarb_template("src", &project_path, project_name)?;
-    overwrite_files_from_scarb_template("tests", &project_path, project_name)?;
+    overwrite_files_from_scarb_template(template, "src", &project_path, project_name)?;
+    overwrite_files_from_scarb_template(template, "tests", &project_path, project_name)?;
+
+    Ok(())
+}
+
+/// Sets up `snforge` in an existing Scarb package located in the current directory
+///
+/// Existing sources, dependencies and targets of the package are left untouched.
+pub fn run_in_existing_package() -> Result<()> {
+    let project_path = std::env::current_dir().context("Failed to get current directory")?;
+    let manifest_path = project_path.join("Scarb.toml");
+    if !manifest_path.exists() {
+        return Err(anyhow!(
+            "Scarb.toml not found in {}, use `snforge init` to create a new project instead",
+            project_path.display()
+        ));
+    }
+
+    let document = fs::read_to_string(&manifest_path)?
+        .parse::<Document>()
+        .context("invalid document")?;
+
+    if !has_dependency(&document, "dev-dependencies", "snforge_std") {
+        add_snforge_std(&project_path)?;
+    }
+    if !has_dependency(&document, "dependencies", "starknet") {
+        add_starknet(&project_path)?;
+    }
+
+    update_existing_config(&manifest_path)?;
+    extend_gitignore_if_missing(&project_path)?;
+    fs::create_dir_all(project_path.join("tests"))?;
+
+    Ok(())
+}
+
+fn has_dependency(document: &Document, section: &str, name: &str) -> bool {
+    document
+        .get(section)
+        .and_then(|dependencies| dependencies.get(name))
+        .is_some()
+}
+
+fn update_existing_config(config_path: &Path) -> Result<()> {
+    let config_file = fs::read_to_string(config_path)?;
+    let mut document = config_file
+        .parse::<Document>()
+        .context("invalid document")?;
+
+    let has_contract_target = document
+        .get("target")
+        .and_then(|target| target.get("starknet-contract"))
+        .is_some();
+    if !has_contract_target {
+        match document.get_mut("target").and_then(Item::as_table_mut) {
+            Some(target) => {
+                target.insert("starknet-contract", starknet_contract_target());
+            }
+            None => add_target_to_toml(&mut document),
+        }
+    }
+
+    fs::write(config_path, document.to_string())?;
 
     Ok(())
 }
@@ -7,19 +7,30 @@ use warn::{
 use crate::scarb::load_test_artifacts;
 use forge_runner::test_case_summary::AnyTestCaseSummary;
 use std::sync::Arc;
+use std::time::Duration;
 
 use compiled_raw::{CompiledTestCrateRaw, RawForkConfig, RawForkParams};
 use forge_runner::test_crate_summary::TestCrateSummary;
 use forge_runner::{RunnerConfig, RunnerParams, TestCrateRunResult};
 
 use crate::block_number_map::BlockNumberMap;
+use crate::exit_code::ForgeError;
+use crate::gas_budget::GasBudgets;
+use crate::pretty_printing::TestSummaryByModule;
 use forge_runner::compiled_runnable::{CompiledTestCrateRunnable, TestCaseRunnable};
 
 use crate::scarb::config::ForkTarget;
 use crate::test_filter::TestsFilter;
+use cairo_lang_sierra::program::VersionedProgram;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use universal_sierra_compiler_api::{compile_sierra_to_casm, AssembledProgramWithDebugInfo};
 
 pub mod block_number_map;
 pub mod compiled_raw;
+pub mod exit_code;
+pub mod gas_budget;
+pub mod hooks;
+pub mod html_report;
 
 pub mod pretty_printing;
 pub mod scarb;
@@ -40,7 +51,9 @@ pub(crate) fn replace_id_with_params<'a>(
                 .iter()
                 .find(|fork| fork.name() == name)
                 .ok_or_else(|| {
-                    anyhow!("Fork configuration named = {name} not found in the Scarb.toml")
+                    ForgeError::Collection(anyhow!(
+                        "Fork configuration named = {name} not found in the Scarb.toml"
+                    ))
                 })?;
 
             Ok(fork_target_from_runner_config.params())
@@ -51,6 +64,7 @@ pub(crate) fn replace_id_with_params<'a>(
 async fn to_runnable(
     compiled_test_crate: CompiledTestCrateRaw,
     fork_targets: &[ForkTarget],
+    default_available_gas: Option<usize>,
     block_number_map: &mut BlockNumberMap,
 ) -> Result<CompiledTestCrateRunnable> {
     let mut test_cases = vec![];
@@ -68,7 +82,7 @@ async fn to_runnable(
 
         test_cases.push(TestCaseRunnable {
             name: case.name,
-            available_gas: case.available_gas,
+            available_gas: case.available_gas.or(default_available_gas),
             ignored: case.ignored,
             expected_result: case.expected_result,
             fork_config,
@@ -87,6 +101,19 @@ async fn to_runnable(
     })
 }
 
+/// Compiles Sierra of all test crates to CASM in parallel
+fn compile_test_crates_to_casm(
+    test_crates: &[CompiledTestCrateRaw],
+) -> Result<Vec<Arc<AssembledProgramWithDebugInfo>>> {
+    test_crates
+        .par_iter()
+        .map(|test_crate| {
+            let VersionedProgram::V1 { program, .. } = &test_crate.sierra_program;
+            compile_sierra_to_casm(&program.program).map(Arc::new)
+        })
+        .collect()
+}
+
 /// Run the tests in the package at the given path
 ///
 /// # Arguments
@@ -97,7 +124,12 @@ async fn to_runnable(
 /// * `runner_config` - A configuration of the test runner
 /// * `runner_params` - A struct with parameters required to run tests e.g. map with contracts
 /// * `fork_target` - A configuration of forks used in tests
-#[allow(clippy::implicit_hasher)]
+/// * `default_available_gas` - Gas limit for tests without the `#[available_gas]` attribute
+/// * `gas_budgets` - Soft gas limits, passed tests exceeding them are reported after the summary
+/// * `report` - Called with the summary of each test case and the time spent executing it as soon as it finishes
+///
+/// Returned summaries keep only failed test cases, results of other test cases are counted
+#[allow(clippy::implicit_hasher, clippy::too_many_arguments)]
 pub async fn run(
     package_name: &str,
     snforge_target_dir_path: &Utf8Path,
@@ -105,19 +137,34 @@ pub async fn run(
     runner_config: Arc<RunnerConfig>,
     runner_params: Arc<RunnerParams>,
     fork_targets: &[ForkTarget],
+    default_available_gas: Option<usize>,
+    gas_budgets: &GasBudgets,
     block_number_map: &mut BlockNumberMap,
+    report: &mut impl FnMut(&AnyTestCaseSummary, Duration),
 ) -> Result<Vec<TestCrateSummary>> {
     let test_crates = load_test_artifacts(snforge_target_dir_path, package_name)
-        .context("Failed to load test artifacts, make sure to use scarb >=2.5.4")?;
+        .context("Failed to load test artifacts, make sure to use scarb >=2.5.4")
+        .map_err(ForgeError::Collection)?;
     let all_tests: usize = test_crates.iter().map(|tc| tc.test_cases.len()).sum();
 
     let test_crates = test_crates
         .into_iter()
         .map(|tc| tests_filter.filter_tests(tc))
-        .collect::<Result<Vec<CompiledTestCrateRaw>>>()?;
+        .collect::<Result<Vec<CompiledTestCrateRaw>>>()
+        .map_err(ForgeError::Collection)?;
     let not_filtered: usize = test_crates.iter().map(|tc| tc.test_cases.len()).sum();
     let filtered = all_tests - not_filtered;
 
+    // Fast path for running a single test, other crates are neither compiled nor collected
+    let test_crates = if tests_filter.is_exact_match() && not_filtered == 1 {
+        test_crates
+            .into_iter()
+            .filter(|tc| !tc.test_cases.is_empty())
+            .collect()
+    } else {
+        test_crates
+    };
+
     warn_if_available_gas_used_with_incompatible_scarb_version(&test_crates)?;
     warn_if_incompatible_rpc_version(&test_crates, fork_targets).await?;
 
@@ -126,25 +173,45 @@ pub async fn run(
         package_name,
     );
 
+    let casm_programs =
+        compile_test_crates_to_casm(&test_crates).map_err(ForgeError::Compilation)?;
+
     let mut summaries = vec![];
+    let mut summary_by_module = TestSummaryByModule::default();
+    let mut any_fuzz_test_was_run = false;
+    let mut exceeded_gas_budgets = vec![];
 
-    for compiled_test_crate in test_crates {
+    for (compiled_test_crate, casm_program) in test_crates.into_iter().zip(casm_programs) {
         pretty_printing::print_running_tests(
             compiled_test_crate.tests_location,
             compiled_test_crate.test_cases.len(),
         );
 
-        let compiled_test_crate =
-            to_runnable(compiled_test_crate, fork_targets, block_number_map).await?;
+        let compiled_test_crate = to_runnable(
+            compiled_test_crate,
+            fork_targets,
+            default_available_gas,
+            block_number_map,
+        )
+        .await
+        .map_err(ForgeError::Collection)?;
         let compiled_test_crate = compiled_test_crate;
         let runner_config = runner_config.clone();
         let runner_params = runner_params.clone();
 
         let summary = forge_runner::run_tests_from_crate(
             compiled_test_crate,
+            casm_program,
             runner_config,
             runner_params,
             tests_filter,
+            &mut |result, duration| {
+                summary_by_module.add(result);
+                any_fuzz_test_was_run |= matches!(result, AnyTestCaseSummary::Fuzzing(_))
+                    && (result.is_passed() || result.is_failed());
+                exceeded_gas_budgets.extend(gas_budgets.check(result));
+                report(result, duration);
+            },
         )
         .await?;
 
@@ -164,15 +231,10 @@ pub async fn run(
     }
 
     pretty_printing::print_test_summary(&summaries, filtered);
+    summary_by_module.print(package_name);
+    pretty_printing::print_exceeded_gas_budgets(&exceeded_gas_budgets);
 
-    let any_fuzz_test_was_run = summaries.iter().any(|crate_summary| {
-        crate_summary
-            .test_case_summaries
-            .iter()
-            .filter(|summary| matches!(summary, AnyTestCaseSummary::Fuzzing(_)))
-            .any(|summary| summary.is_passed() || summary.is_failed())
-    });
-
+    // Failed tests which generated random values have the seed in their failure data
     if any_fuzz_test_was_run {
         pretty_printing::print_test_seed(runner_config.fuzzer_seed);
     }
@@ -180,6 +242,34 @@ pub async fn run(
     Ok(summaries)
 }
 
+/// Compile the tests in the package at the given path without running them
+///
+/// Returns the number of collected tests
+///
+/// # Arguments
+///
+/// * `package_name` - Name of the package specified in Scarb.toml
+/// * `snforge_target_dir_path` - Absolute path to the directory with snforge test artifacts (usually `{package_path}/target/{profile_name}/snforge`)
+pub fn check(package_name: &str, snforge_target_dir_path: &Utf8Path) -> Result<usize> {
+    let test_crates = load_test_artifacts(snforge_target_dir_path, package_name)
+        .context("Failed to load test artifacts, make sure to use scarb >=2.5.4")
+        .map_err(ForgeError::Collection)?;
+    let all_tests: usize = test_crates.iter().map(|tc| tc.test_cases.len()).sum();
+
+    pretty_printing::print_collected_tests_count(all_tests, package_name);
+
+    compile_test_crates_to_casm(&test_crates).map_err(ForgeError::Compilation)?;
+
+    for test_crate in test_crates {
+        pretty_printing::print_checked_tests(
+            test_crate.tests_location,
+            test_crate.test_cases.len(),
+        );
+    }
+
+    Ok(all_tests)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,7 +325,7 @@ mod tests {
         };
 
         assert!(
-            to_runnable(mocked_tests, &[], &mut BlockNumberMap::default())
+            to_runnable(mocked_tests, &[], None, &mut BlockNumberMap::default())
                 .await
                 .is_err()
         );
@@ -278,9 +368,42 @@ mod tests {
                     block_id_value: "120".to_string(),
                 },
             )],
+            None,
             &mut BlockNumberMap::default()
         )
         .await
         .is_err());
     }
+
+    #[tokio::test]
+    async fn to_runnable_default_available_gas() {
+        let test_case = |name: &str, available_gas| TestCaseRaw {
+            name: name.to_string(),
+            available_gas,
+            ignored: false,
+            expected_result: ExpectedTestResult::Success,
+            fork_config: None,
+            fuzzer_config: None,
+            test_details: TestDetails {
+                entry_point_offset: 100,
+                parameter_types: vec![],
+                return_types: vec![],
+            },
+        };
+        let mocked_tests = CompiledTestCrateRaw {
+            sierra_program: program_for_testing(),
+            test_cases: vec![
+                test_case("crate1::with_attribute", Some(10)),
+                test_case("crate1::without_attribute", None),
+            ],
+            tests_location: CrateLocation::Lib,
+        };
+
+        let runnable = to_runnable(mocked_tests, &[], Some(100), &mut BlockNumberMap::default())
+            .await
+            .unwrap();
+
+        assert_eq!(runnable.test_cases[0].available_gas, Some(10));
+        assert_eq!(runnable.test_cases[1].available_gas, Some(100));
+    }
 }
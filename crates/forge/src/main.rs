@@ -1,18 +1,23 @@
 use anyhow::{anyhow, Context, Result};
 use camino::Utf8Path;
-use clap::{Parser, Subcommand, ValueEnum};
-use configuration::load_package_config;
+use clap::builder::PossibleValuesParser;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use configuration::{load_global_config, load_package_config, load_profile_names, GlobalConfig};
 use forge::scarb::config::ForgeConfig;
-use forge::scarb::{build_contracts_with_scarb, build_test_artifacts_with_scarb};
+use forge::scarb::{
+    build_contracts_with_scarb, build_test_artifacts_with_scarb, save_test_artifacts_fingerprint,
+    test_artifacts_fingerprint, test_artifacts_up_to_date, FeaturesSpec,
+};
 use forge::shared_cache::{clean_cache, set_cached_failed_tests_names};
 use forge::test_filter::TestsFilter;
-use forge::{pretty_printing, run};
-use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use forge::{check, pretty_printing, run};
+use forge_runner::test_case_summary::AnyTestCaseSummary;
 use forge_runner::test_crate_summary::TestCrateSummary;
-use forge_runner::{RunnerConfig, RunnerParams, CACHE_DIR};
+use forge_runner::{OutputVerbosity, RunnerConfig, RunnerParams, CACHE_DIR};
 use rand::{thread_rng, RngCore};
 use scarb_api::{
-    get_contracts_map,
+    contract_artifacts_stale, get_contracts_map, get_lazy_contracts_map,
     metadata::{Metadata, MetadataCommandExt, PackageMetadata},
     package_matches_version_requirement, target_dir_for_workspace, ScarbCommand,
 };
@@ -20,6 +25,9 @@ use scarb_ui::args::PackagesFilter;
 
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge::block_number_map::BlockNumberMap;
+use forge::exit_code::{ExitCode, ForgeError};
+use forge::hooks::RunOutcome;
+use forge::html_report::HtmlReport;
 use semver::{Comparator, Op, Version, VersionReq};
 use shared::print::print_as_warning;
 use std::env;
@@ -31,6 +39,8 @@ use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
 
 mod init;
 
+use init::Template;
+
 fn default_fuzzer_runs() -> NonZeroU32 {
     NonZeroU32::new(256).unwrap()
 }
@@ -54,9 +64,32 @@ enum ForgeSubcommand {
     Init {
         /// Name of a new project
         name: String,
+
+        /// Template to generate the project from
+        #[arg(long, value_enum, default_value_t)]
+        template: Template,
     },
+    /// Compile tests for a project in the current directory without running them
+    Check {
+        #[command(flatten)]
+        args: CheckArgs,
+    },
+    /// Set up Forge in an existing Scarb package in the current directory
+    New {},
     /// Clean Forge cache directory
     CleanCache {},
+    /// Generate a shell completion script
+    Completions {
+        /// Shell to generate the completion script for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum ReportFormat {
+    /// Single HTML file with filterable test results
+    Html,
 }
 
 #[derive(ValueEnum, Debug, Clone)]
@@ -82,11 +115,22 @@ struct TestArgs {
     #[command(flatten)]
     packages_filter: PackagesFilter,
 
+    #[command(flatten)]
+    features: FeaturesSpec,
+
+    /// Scarb profile used to build tests and contracts
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
+
+    /// Profile from snfoundry.toml to use, `default` if not passed
+    #[arg(long, value_name = "PROFILE")]
+    config_profile: Option<String>,
+
     /// Number of fuzzer runs
     #[arg(short = 'r', long)]
     fuzzer_runs: Option<NonZeroU32>,
-    /// Seed for the fuzzer
-    #[arg(short = 's', long)]
+    /// Seed for the fuzzer and the random values of `rand_felt` and `rand_in_range`
+    #[arg(short = 's', long, visible_alias = "rng-seed")]
     fuzzer_seed: Option<u64>,
 
     /// Run only tests marked with `#[ignore]` attribute
@@ -100,6 +144,14 @@ struct TestArgs {
     #[arg(long)]
     detailed_resources: bool,
 
+    /// Print only failed tests and the summary
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Print detailed info about used resources of every passed test,
+    /// `-vv` also prints storage reads and writes made by failed tests, `-vvv` by every test
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+
     /// Control when colored output is used
     #[arg(value_enum, long, default_value_t = ColorOption::Auto, value_name="WHEN")]
     color: ColorOption,
@@ -119,6 +171,39 @@ struct TestArgs {
     /// Number of maximum steps during a single test. For fuzz tests this value is applied to each subtest separately.
     #[arg(long)]
     max_n_steps: Option<u32>,
+
+    /// Maximal depth of nested contract calls during a single test
+    #[arg(long)]
+    max_recursion_depth: Option<usize>,
+
+    /// Save a report of the test run in the given format to the snforge target directory
+    #[arg(value_enum, long, value_name = "FORMAT")]
+    report: Option<ReportFormat>,
+
+    /// Print messages and diagnostics of the Scarb build as JSON objects, one per line
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Parser, Debug)]
+struct CheckArgs {
+    #[command(flatten)]
+    packages_filter: PackagesFilter,
+
+    #[command(flatten)]
+    features: FeaturesSpec,
+
+    /// Scarb profile used to build tests and contracts
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
+
+    /// Control when colored output is used
+    #[arg(value_enum, long, default_value_t = ColorOption::Auto, value_name="WHEN")]
+    color: ColorOption,
+
+    /// Print messages and diagnostics of the Scarb build as JSON objects, one per line
+    #[arg(long)]
+    json: bool,
 }
 
 fn extract_failed_tests(
@@ -126,14 +211,7 @@ fn extract_failed_tests(
 ) -> impl Iterator<Item = AnyTestCaseSummary> {
     tests_summaries
         .into_iter()
-        .flat_map(|test_file_summary| test_file_summary.test_case_summaries)
-        .filter(|test_case_summary| {
-            matches!(
-                test_case_summary,
-                AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. })
-                    | AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
-            )
-        })
+        .flat_map(|test_file_summary| test_file_summary.failed_test_case_summaries)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -147,6 +225,8 @@ fn combine_configs(
     save_trace_data: bool,
     build_profile: bool,
     max_n_steps: Option<u32>,
+    max_recursion_depth: Option<usize>,
+    output_verbosity: OutputVerbosity,
     forge_config: &ForgeConfig,
 ) -> RunnerConfig {
     RunnerConfig::new(
@@ -162,6 +242,8 @@ fn combine_configs(
         save_trace_data || forge_config.save_trace_data,
         build_profile || forge_config.build_profile,
         max_n_steps.or(forge_config.max_n_steps),
+        max_recursion_depth.or(forge_config.max_recursion_depth),
+        output_verbosity,
     )
 }
 
@@ -191,13 +273,118 @@ fn warn_if_snforge_std_not_compatible(scarb_metadata: &Metadata) -> Result<()> {
     Ok(())
 }
 
+/// Sets colors of `snforge` output and, through env variables, of the `scarb` invocations
+///
+/// With `auto`, colors are disabled when `NO_COLOR` is set to a non-empty value
+fn set_color_option(color: &ColorOption) {
+    let colors_enabled = match color {
+        ColorOption::Always => {
+            env::set_var("CLICOLOR_FORCE", "1");
+            true
+        }
+        ColorOption::Never => {
+            env::set_var("CLICOLOR", "0");
+            false
+        }
+        ColorOption::Auto => {
+            if !env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+                return;
+            }
+            env::set_var("CLICOLOR", "0");
+            env::remove_var("CLICOLOR_FORCE");
+            false
+        }
+    };
+
+    console::set_colors_enabled(colors_enabled);
+    console::set_colors_enabled_stderr(colors_enabled);
+}
+
+fn set_scarb_profile(profile: Option<&str>) {
+    // Inherited by all `scarb` invocations, including the metadata one
+    if let Some(profile) = profile {
+        env::set_var("SCARB_PROFILE", profile);
+    }
+}
+
+fn build_workspace(
+    scarb_metadata: &Metadata,
+    packages: &[PackageMetadata],
+    features: &FeaturesSpec,
+    snforge_target_dir_path: &Utf8Path,
+    json: bool,
+) -> Result<()> {
+    let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
+
+    let fingerprint = test_artifacts_fingerprint(scarb_metadata, packages, features);
+    if !test_artifacts_up_to_date(snforge_target_dir_path, packages, &fingerprint) {
+        build_test_artifacts_with_scarb(filter.clone(), features, json)?;
+        save_test_artifacts_fingerprint(snforge_target_dir_path, &fingerprint)?;
+    }
+    build_contracts_with_scarb(filter, features, json)
+}
+
+/// Contracts are rebuilt by `scarb build` right before this check, so stale artifacts only point
+/// to a build problem or to skewed modification times. Either way tests can still run, so this is
+/// reported as a warning and errors of the check itself are ignored
+fn warn_if_contract_artifacts_stale(scarb_metadata: &Metadata, package: &PackageMetadata) {
+    if let Ok(true) = contract_artifacts_stale(scarb_metadata, &package.id, None) {
+        print_as_warning(&anyhow!(
+            "Contract artifacts of package {} are older than its sources. Make sure `scarb build` succeeds for this package, or check the modification times of its files",
+            package.name
+        ));
+    }
+}
+
+fn check_workspace(args: &CheckArgs) -> Result<bool> {
+    set_color_option(&args.color);
+    set_scarb_profile(args.profile.as_deref());
+
+    let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
+    warn_if_snforge_std_not_compatible(&scarb_metadata)?;
+
+    let snforge_target_dir_path = target_dir_for_workspace(&scarb_metadata)
+        .join(&scarb_metadata.current_profile)
+        .join("snforge");
+
+    let packages: Vec<PackageMetadata> = args
+        .packages_filter
+        .match_many(&scarb_metadata)
+        .context("Failed to find any packages matching the specified filter")?;
+
+    build_workspace(
+        &scarb_metadata,
+        &packages,
+        &args.features,
+        &snforge_target_dir_path,
+        args.json,
+    )?;
+
+    let mut checked = 0;
+    for package in &packages {
+        warn_if_contract_artifacts_stale(&scarb_metadata, package);
+        // All contracts are compiled to casm upfront, `snforge test` compiles them only when declared
+        let contracts = get_contracts_map(&scarb_metadata, &package.id, None)?;
+        ContractsData::try_from(
+            contracts
+                .into_iter()
+                .map(|(name, artifacts)| (name, artifacts.into()))
+                .collect(),
+        )
+        .map_err(ForgeError::Compilation)?;
+
+        checked += check(&package.name, &snforge_target_dir_path)?;
+    }
+
+    pretty_printing::print_check_summary(checked);
+
+    Ok(true)
+}
+
 #[allow(clippy::too_many_lines)]
 fn test_workspace(args: TestArgs) -> Result<bool> {
-    match args.color {
-        ColorOption::Always => env::set_var("CLICOLOR_FORCE", "1"),
-        ColorOption::Never => env::set_var("CLICOLOR", "0"),
-        ColorOption::Auto => (),
-    }
+    set_color_option(&args.color);
+    set_scarb_profile(args.profile.as_deref());
 
     let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
     warn_if_snforge_std_not_compatible(&scarb_metadata)?;
@@ -212,10 +399,24 @@ fn test_workspace(args: TestArgs) -> Result<bool> {
         .match_many(&scarb_metadata)
         .context("Failed to find any packages matching the specified filter")?;
 
-    let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
+    build_workspace(
+        &scarb_metadata,
+        &packages,
+        &args.features,
+        &snforge_target_dir_path,
+        args.json,
+    )?;
 
-    build_test_artifacts_with_scarb(filter.clone())?;
-    build_contracts_with_scarb(filter.clone())?;
+    let output_verbosity = if args.quiet {
+        OutputVerbosity::Quiet
+    } else {
+        match args.verbose {
+            0 => OutputVerbosity::Normal,
+            1 => OutputVerbosity::Verbose,
+            2 => OutputVerbosity::FailedTrace,
+            _ => OutputVerbosity::Trace,
+        }
+    };
 
     let cores = if let Ok(available_cores) = available_parallelism() {
         available_cores.get()
@@ -229,19 +430,30 @@ fn test_workspace(args: TestArgs) -> Result<bool> {
         .enable_all()
         .build()?;
 
+    let report_path = args
+        .report
+        .as_ref()
+        .map(|ReportFormat::Html| snforge_target_dir_path.join("report.html"));
+
     let all_failed_tests = rt.block_on({
+        let report_path = report_path.clone();
         rt.spawn(async move {
             let mut block_number_map = BlockNumberMap::default();
             let mut all_failed_tests = vec![];
+            let mut html_report = HtmlReport::default();
             for package in &packages {
                 env::set_current_dir(&package.root)?;
 
+                let global_config = load_global_config::<ForgeConfig>(&None, &args.config_profile)?;
                 let forge_config =
-                    load_package_config::<ForgeConfig>(&scarb_metadata, &package.id)?;
+                    load_package_config::<ForgeConfig>(&scarb_metadata, &package.id)?
+                        .merge_with_global(global_config);
+                warn_if_contract_artifacts_stale(&scarb_metadata, package);
                 let contracts =
-                    get_contracts_map(&scarb_metadata, &package.id, None).unwrap_or_default();
+                    get_lazy_contracts_map(&scarb_metadata, &package.id, None).unwrap_or_default();
 
-                let contracts_data = ContractsData::try_from(contracts)?;
+                let contracts_data =
+                    ContractsData::try_from(contracts).map_err(ForgeError::Compilation)?;
 
                 let runner_config = Arc::new(combine_configs(
                     &workspace_root,
@@ -252,12 +464,18 @@ fn test_workspace(args: TestArgs) -> Result<bool> {
                     args.save_trace_data,
                     args.build_profile,
                     args.max_n_steps,
+                    args.max_recursion_depth,
+                    output_verbosity,
                     &forge_config,
                 ));
+                forge_config.hooks.run_pre_run(&package.name)?;
+                let environment_variables = env::vars()
+                    .filter(|(name, _)| forge_config.is_env_var_allowed(name))
+                    .collect();
                 let runner_params =
-                    Arc::new(RunnerParams::new(contracts_data, env::vars().collect()));
+                    Arc::new(RunnerParams::new(contracts_data, environment_variables));
 
-                let tests_file_summaries = run(
+                let run_result = run(
                     &package.name,
                     &snforge_target_dir_path,
                     &TestsFilter::from_flags(
@@ -271,13 +489,33 @@ fn test_workspace(args: TestArgs) -> Result<bool> {
                     runner_config,
                     runner_params,
                     &forge_config.fork,
+                    forge_config.available_gas,
+                    &forge_config.gas_budgets,
                     &mut block_number_map,
+                    &mut |result, duration| {
+                        if report_path.is_some() {
+                            html_report.add(&package.name, result, duration);
+                        }
+                    },
                 )
-                .await?;
-
+                .await;
+
+                // `post_run` is executed even if running tests failed, so it can clean up after `pre_run`
+                let outcome = run_result
+                    .as_ref()
+                    .ok()
+                    .map(|summaries| RunOutcome::from_summaries(summaries));
+                let post_run_result = forge_config
+                    .hooks
+                    .run_post_run(&package.name, outcome.as_ref());
+                let tests_file_summaries = run_result?;
+                post_run_result?;
                 all_failed_tests.extend(extract_failed_tests(tests_file_summaries));
             }
             set_cached_failed_tests_names(&all_failed_tests, &workspace_root.join(CACHE_DIR))?;
+            if let Some(report_path) = &report_path {
+                html_report.save(report_path)?;
+            }
             pretty_printing::print_latest_blocks_numbers(
                 block_number_map.get_url_to_latest_block_number(),
             );
@@ -287,45 +525,80 @@ fn test_workspace(args: TestArgs) -> Result<bool> {
     })??;
 
     pretty_printing::print_failures(&all_failed_tests);
+    if let Some(report_path) = &report_path {
+        pretty_printing::print_report_path(report_path);
+    }
 
     Ok(all_failed_tests.is_empty())
 }
 
+/// Prints the completion script for `shell`, profile names from snfoundry.toml
+/// found at the time of generation are completed for `--config-profile` of `test`
+fn print_completions(shell: Shell) -> Result<()> {
+    let profile_names = load_profile_names(&None, ForgeConfig::tool_name())?;
+
+    let mut command = Cli::command();
+    if !profile_names.is_empty() {
+        command = command.mut_subcommand("test", |test| {
+            test.mut_arg("config_profile", |arg| {
+                arg.value_parser(PossibleValuesParser::new(profile_names))
+            })
+        });
+    }
+    clap_complete::generate(shell, &mut command, "snforge", &mut std::io::stdout());
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_lines)]
 fn main_execution() -> Result<bool> {
     let cli = Cli::parse();
 
-    ScarbCommand::new().ensure_available()?;
-    UniversalSierraCompilerCommand::ensure_available()?;
+    if !matches!(cli.subcommand, ForgeSubcommand::Completions { .. }) {
+        ScarbCommand::new().ensure_available()?;
+        UniversalSierraCompilerCommand::ensure_available()?;
+    }
 
     match cli.subcommand {
-        ForgeSubcommand::Init { name } => {
-            init::run(name.as_str())?;
+        ForgeSubcommand::Init { name, template } => {
+            init::run(name.as_str(), template)?;
+            Ok(true)
+        }
+        ForgeSubcommand::New {} => {
+            init::run_in_existing_package()?;
             Ok(true)
         }
         ForgeSubcommand::CleanCache {} => {
             clean_cache()?;
             Ok(true)
         }
+        ForgeSubcommand::Completions { shell } => {
+            print_completions(shell)?;
+            Ok(true)
+        }
         ForgeSubcommand::Test { args } => test_workspace(args),
+        ForgeSubcommand::Check { args } => check_workspace(&args),
     }
 }
 
 fn main() {
-    match main_execution() {
-        Ok(true) => std::process::exit(0),
-        Ok(false) => std::process::exit(1),
+    let exit_code = match main_execution() {
+        Ok(true) => ExitCode::Success,
+        Ok(false) => ExitCode::TestsFailed,
         Err(error) => {
             pretty_printing::print_error_message(&error);
-            std::process::exit(2);
+            ExitCode::from_error(&error)
         }
     };
+    std::process::exit(exit_code as i32);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use camino::Utf8PathBuf;
+    use forge::gas_budget::GasBudgets;
+    use forge::hooks::Hooks;
 
     #[test]
     fn fuzzer_default_seed() {
@@ -339,6 +612,8 @@ mod tests {
             false,
             false,
             None,
+            None,
+            OutputVerbosity::Normal,
             &Default::default(),
         );
         let config2 = combine_configs(
@@ -350,6 +625,8 @@ mod tests {
             false,
             false,
             None,
+            None,
+            OutputVerbosity::Normal,
             &Default::default(),
         );
 
@@ -370,6 +647,8 @@ mod tests {
             false,
             false,
             None,
+            None,
+            OutputVerbosity::Normal,
             &Default::default(),
         );
         assert_eq!(
@@ -382,7 +661,9 @@ mod tests {
                 false,
                 false,
                 false,
-                None
+                None,
+                None,
+                OutputVerbosity::Normal,
             )
         );
     }
@@ -398,6 +679,11 @@ mod tests {
             save_trace_data: true,
             build_profile: true,
             max_n_steps: Some(1_000_000),
+            max_recursion_depth: Some(100),
+            available_gas: None,
+            gas_budgets: GasBudgets::default(),
+            hooks: Hooks::default(),
+            allowed_env_vars: None,
         };
         let workspace_root: Utf8PathBuf = Default::default();
 
@@ -410,6 +696,8 @@ mod tests {
             false,
             false,
             None,
+            None,
+            OutputVerbosity::Normal,
             &config_from_scarb,
         );
         assert_eq!(
@@ -422,7 +710,9 @@ mod tests {
                 true,
                 true,
                 true,
-                Some(1_000_000)
+                Some(1_000_000),
+                Some(100),
+                OutputVerbosity::Normal,
             )
         );
     }
@@ -440,6 +730,11 @@ mod tests {
             save_trace_data: false,
             build_profile: false,
             max_n_steps: Some(1234),
+            max_recursion_depth: Some(100),
+            available_gas: None,
+            gas_budgets: GasBudgets::default(),
+            hooks: Hooks::default(),
+            allowed_env_vars: None,
         };
         let config = combine_configs(
             &workspace_root,
@@ -450,6 +745,8 @@ mod tests {
             true,
             true,
             Some(1_000_000),
+            Some(200),
+            OutputVerbosity::Verbose,
             &config_from_scarb,
         );
 
@@ -463,7 +760,9 @@ mod tests {
                 true,
                 true,
                 true,
-                Some(1_000_000)
+                Some(1_000_000),
+                Some(200),
+                OutputVerbosity::Verbose,
             )
         );
     }
@@ -1,15 +1,77 @@
 use crate::compiled_raw::CrateLocation;
+use crate::gas_budget::ExceededGasBudget;
 use anyhow::Error;
+use camino::Utf8Path;
 use console::style;
 use forge_runner::{test_case_summary::AnyTestCaseSummary, test_crate_summary::TestCrateSummary};
+use scarb_api::messages::{ScarbMessage, Severity};
+use serde_json::{json, Value};
 use starknet_api::block::BlockNumber;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 pub fn print_error_message(error: &Error) {
     let error_tag = style("ERROR").red();
     println!("[{error_tag}] {error:#}");
 }
 
+/// Prints a message forwarded from a `scarb` build, diagnostics are tagged like other `snforge` errors and warnings
+///
+/// With `json` set, every message is printed as a single line JSON object instead
+pub(crate) fn print_scarb_message(message: &ScarbMessage, json: bool) {
+    if json {
+        println!("{}", scarb_message_to_json(message));
+        return;
+    }
+
+    match message {
+        ScarbMessage::Status { status, message } => {
+            let mut chars = status.chars();
+            let status = chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default();
+            println!("{:>12} {message}", style(status).green().bold());
+        }
+        ScarbMessage::Diagnostic(diagnostic) => {
+            let tag = match diagnostic.severity {
+                Severity::Error => style("ERROR").red(),
+                Severity::Warning => style("WARNING").color256(11),
+            };
+            println!("[{tag}] {}", diagnostic.message);
+        }
+        ScarbMessage::Text(text) => println!("{text}"),
+    }
+}
+
+fn scarb_message_to_json(message: &ScarbMessage) -> Value {
+    match message {
+        ScarbMessage::Status { status, message } => json!({
+            "type": "status",
+            "status": status,
+            "message": message,
+        }),
+        ScarbMessage::Diagnostic(diagnostic) => {
+            let severity = match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            let location = diagnostic.location.as_ref();
+            json!({
+                "type": "diagnostic",
+                "severity": severity,
+                "message": diagnostic.message,
+                "file": location.map(|location| &location.file),
+                "line": location.map(|location| location.line),
+                "column": location.map(|location| location.column),
+            })
+        }
+        ScarbMessage::Text(text) => json!({
+            "type": "text",
+            "message": text,
+        }),
+    }
+}
+
 pub(crate) fn print_collected_tests_count(tests_num: usize, package_name: &str) {
     let plain_text = format!("\n\nCollected {tests_num} test(s) from {package_name} package");
     println!("{}", style(plain_text).bold());
@@ -25,6 +87,23 @@ pub(crate) fn print_running_tests(test_crate_file: CrateLocation, tests_num: usi
     println!("{}", style(plain_text).bold());
 }
 
+pub(crate) fn print_checked_tests(test_crate_file: CrateLocation, tests_num: usize) {
+    let dir_name = match test_crate_file {
+        CrateLocation::Lib => "src",
+        CrateLocation::Tests => "tests",
+    };
+    let plain_text = format!("Checked {tests_num} test(s) from {dir_name}/");
+
+    println!("{}", style(plain_text).bold());
+}
+
+pub fn print_check_summary(checked: usize) {
+    println!(
+        "{}: {checked} test(s) compiled successfully",
+        style("Check").bold()
+    );
+}
+
 pub(crate) fn print_test_summary(summaries: &[TestCrateSummary], filtered: usize) {
     let passed: usize = summaries.iter().map(TestCrateSummary::count_passed).sum();
     let failed: usize = summaries.iter().map(TestCrateSummary::count_failed).sum();
@@ -42,10 +121,90 @@ pub(crate) fn print_test_summary(summaries: &[TestCrateSummary], filtered: usize
     );
 }
 
+#[derive(Default)]
+struct ModuleSummary {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    failed_names: BTreeSet<String>,
+}
+
+/// Module path of a test, e.g. `tests::test_simple` for `tests::test_simple::test_two`
+fn module_path(test_name: &str) -> &str {
+    test_name
+        .rsplit_once("::")
+        .map_or(test_name, |(module, _)| module)
+}
+
+/// Per-module counts of test results, collected as test cases finish
+#[derive(Default)]
+pub(crate) struct TestSummaryByModule {
+    modules: BTreeMap<String, ModuleSummary>,
+}
+
+impl TestSummaryByModule {
+    pub(crate) fn add(&mut self, test_case: &AnyTestCaseSummary) {
+        let Some(name) = test_case.name() else {
+            return;
+        };
+        let module = self
+            .modules
+            .entry(module_path(name).to_string())
+            .or_default();
+        if test_case.is_passed() {
+            module.passed += 1;
+        } else if test_case.is_failed() {
+            module.failed += 1;
+            module.failed_names.insert(name.to_string());
+        } else if test_case.is_ignored() {
+            module.ignored += 1;
+        }
+    }
+
+    pub(crate) fn print(&self, package_name: &str) {
+        // A single group adds nothing over the overall summary
+        if self.modules.len() < 2 {
+            return;
+        }
+
+        println!(
+            "{}",
+            style(format!("Tests by module in {package_name}:")).bold()
+        );
+        for (module, summary) in &self.modules {
+            println!(
+                "    {module}: {} passed, {} failed, {} ignored",
+                summary.passed, summary.failed, summary.ignored
+            );
+            for name in &summary.failed_names {
+                println!("        [{}] {name}", style("FAIL").red());
+            }
+        }
+    }
+}
+
+pub(crate) fn print_exceeded_gas_budgets(exceeded_gas_budgets: &[ExceededGasBudget]) {
+    if exceeded_gas_budgets.is_empty() {
+        return;
+    }
+
+    println!("{}", style("Gas budget exceeded:").yellow().bold());
+    for exceeded in exceeded_gas_budgets {
+        println!(
+            "    {} (gas: ~{}, budget: {})",
+            exceeded.test_name, exceeded.gas, exceeded.budget
+        );
+    }
+}
+
 pub(crate) fn print_test_seed(seed: u64) {
     println!("{}: {seed}", style("Fuzzer seed").bold());
 }
 
+pub fn print_report_path(report_path: &Utf8Path) {
+    println!("\n{}: {report_path}", style("Test report saved to").bold());
+}
+
 pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
     if all_failed_tests.is_empty() {
         return;
@@ -58,6 +217,41 @@ pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
     for name in failed_tests_names {
         println!("    {name}");
     }
+
+    print_repeated_failures(all_failed_tests);
+}
+
+fn print_repeated_failures(all_failed_tests: &[AnyTestCaseSummary]) {
+    let mut failures_by_msg: Vec<(&str, Vec<&str>)> = vec![];
+    for failed_test in all_failed_tests {
+        let (Some(msg), Some(name)) = (failed_test.msg(), failed_test.name()) else {
+            continue;
+        };
+        match failures_by_msg.iter_mut().find(|(other, _)| *other == msg) {
+            Some((_, names)) => names.push(name),
+            None => failures_by_msg.push((msg, vec![name])),
+        }
+    }
+
+    let repeated_failures: Vec<_> = failures_by_msg
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .collect();
+    if repeated_failures.is_empty() {
+        return;
+    }
+
+    println!("\nRepeated failures:");
+    for (msg, names) in repeated_failures {
+        println!(
+            "    {} tests failed with the same data, e.g. {}:",
+            names.len(),
+            names[0]
+        );
+        for line in msg.lines().filter(|line| !line.trim().is_empty()) {
+            println!("    {line}");
+        }
+    }
 }
 
 #[allow(clippy::implicit_hasher)]
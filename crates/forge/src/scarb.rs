@@ -1,10 +1,18 @@
 use crate::compiled_raw::CompiledTestCrateRaw;
+use crate::exit_code::ForgeError;
+use crate::pretty_printing::print_scarb_message;
 use crate::scarb::config::{ForgeConfig, RawForgeConfig};
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use camino::Utf8Path;
-use configuration::PackageConfig;
-use scarb_api::ScarbCommand;
+use clap::Args;
+use configuration::{GlobalConfig, PackageConfig};
+use itertools::Itertools;
+use scarb_api::messages::{ScarbMessage, Severity};
+use scarb_api::{package_source_files, ScarbCommand, ScarbCommandError};
+use scarb_metadata::{Metadata, PackageMetadata};
 use scarb_ui::args::PackagesFilter;
+use sha3::{Digest, Sha3_256};
+use std::fs;
 
 pub mod config;
 
@@ -25,24 +33,216 @@ impl PackageConfig for ForgeConfig {
     }
 }
 
-pub fn build_contracts_with_scarb(filter: PackagesFilter) -> Result<()> {
-    ScarbCommand::new_with_stdio()
-        .arg("build")
-        .packages_filter(filter)
-        .run()
-        .context("Failed to build contracts with Scarb")?;
+impl GlobalConfig for ForgeConfig {
+    fn tool_name() -> &'static str {
+        "snforge"
+    }
+
+    fn from_raw(config: serde_json::Value) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let raw_config = serde_json::from_value::<RawForgeConfig>(config)?;
+
+        raw_config
+            .try_into()
+            .context("Invalid config in snfoundry.toml: ")
+    }
+}
+
+/// Scarb features used when building test artifacts and contracts
+#[derive(Args, Debug, Clone, Default, Hash)]
+pub struct FeaturesSpec {
+    /// Comma separated list of features to activate
+    #[arg(short = 'F', long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Activate all available features
+    #[arg(long, conflicts_with = "features")]
+    pub all_features: bool,
+
+    /// Do not activate the `default` feature
+    #[arg(long)]
+    pub no_default_features: bool,
+}
+
+impl FeaturesSpec {
+    /// Environment variables passing the features to `scarb`
+    #[must_use]
+    pub fn to_env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut env_vars = vec![];
+        if !self.features.is_empty() {
+            env_vars.push(("SCARB_FEATURES", self.features.join(",")));
+        }
+        if self.all_features {
+            env_vars.push(("SCARB_ALL_FEATURES", "true".to_string()));
+        }
+        if self.no_default_features {
+            env_vars.push(("SCARB_NO_DEFAULT_FEATURES", "true".to_string()));
+        }
+        env_vars
+    }
+}
+
+/// Runs a `scarb` build, forwarding its output and diagnostics to `snforge` output, as JSON if `json` is set
+///
+/// If the build fails, the returned error lists locations of reported errors
+fn run_scarb_build(command: &ScarbCommand, json: bool) -> Result<()> {
+    let mut errors = vec![];
+    let result = command.run_with_messages(|message| {
+        print_scarb_message(&message, json);
+        if let ScarbMessage::Diagnostic(diagnostic) = message {
+            if diagnostic.severity == Severity::Error {
+                errors.push(diagnostic);
+            }
+        }
+    });
+
+    match result {
+        Err(ScarbCommandError::ScarbError) if !errors.is_empty() => {
+            let locations = errors
+                .iter()
+                .filter_map(|error| error.location.as_ref())
+                .join(", ");
+            let summary = format!("`scarb` reported {} error(s)", errors.len());
+            if locations.is_empty() {
+                Err(anyhow!(summary))
+            } else {
+                Err(anyhow!("{summary} in {locations}"))
+            }
+        }
+        result => result.map_err(Into::into),
+    }
+}
+
+pub fn build_contracts_with_scarb(
+    filter: PackagesFilter,
+    features: &FeaturesSpec,
+    json: bool,
+) -> Result<()> {
+    run_scarb_build(
+        ScarbCommand::new_with_stdio()
+            .arg("build")
+            .packages_filter(filter)
+            .envs(features.to_env_vars()),
+        json,
+    )
+    .context("Failed to build contracts with Scarb")
+    .map_err(ForgeError::Compilation)?;
     Ok(())
 }
 
-pub fn build_test_artifacts_with_scarb(filter: PackagesFilter) -> Result<()> {
-    ScarbCommand::new_with_stdio()
-        .arg("snforge-test-collector")
-        .packages_filter(filter)
-        .run()
-        .context("Failed to build test artifacts with Scarb")?;
+pub fn build_test_artifacts_with_scarb(
+    filter: PackagesFilter,
+    features: &FeaturesSpec,
+    json: bool,
+) -> Result<()> {
+    run_scarb_build(
+        ScarbCommand::new_with_stdio()
+            .arg("snforge-test-collector")
+            .packages_filter(filter)
+            .envs(features.to_env_vars()),
+        json,
+    )
+    .context("Failed to build test artifacts with Scarb")
+    .map_err(ForgeError::Compilation)?;
     Ok(())
 }
 
+const TEST_ARTIFACTS_FINGERPRINT: &str = "test_artifacts.fingerprint";
+
+/// Computes a fingerprint of everything test artifacts of `packages` are built from:
+/// sources of path dependencies, ids of other dependencies, enabled features and versions of the compilers
+#[must_use]
+pub fn test_artifacts_fingerprint(
+    scarb_metadata: &Metadata,
+    packages: &[PackageMetadata],
+    features: &FeaturesSpec,
+) -> String {
+    let mut hasher = Sha3_256::new();
+
+    update_with_field(&mut hasher, env!("CARGO_PKG_VERSION"));
+    update_with_field(
+        &mut hasher,
+        scarb_metadata.app_version_info.version.to_string(),
+    );
+    update_with_field(
+        &mut hasher,
+        scarb_metadata.app_version_info.cairo.version.to_string(),
+    );
+    update_with_field(&mut hasher, &scarb_metadata.current_profile);
+    update_with_field(&mut hasher, features.features.join(","));
+    update_with_field(
+        &mut hasher,
+        [
+            u8::from(features.all_features),
+            u8::from(features.no_default_features),
+        ],
+    );
+
+    for package in packages {
+        update_with_field(&mut hasher, &package.id.repr);
+    }
+
+    let mut all_packages: Vec<&PackageMetadata> = scarb_metadata.packages.iter().collect();
+    all_packages.sort_by(|a, b| a.id.repr.cmp(&b.id.repr));
+
+    for package in all_packages {
+        update_with_field(&mut hasher, &package.id.repr);
+        // Packages from registries and git are immutable for a given id
+        if package.source.repr.starts_with("path+") {
+            hash_package_sources(&package.root, &mut hasher);
+        }
+    }
+
+    base16ct::lower::encode_string(&hasher.finalize())
+}
+
+/// Feeds `bytes` to `hasher` prefixed with their length, so adjacent fields can't be confused
+fn update_with_field(hasher: &mut Sha3_256, bytes: impl AsRef<[u8]>) {
+    let bytes = bytes.as_ref();
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+fn hash_package_sources(package_root: &Utf8Path, hasher: &mut Sha3_256) {
+    for path in package_source_files(package_root) {
+        update_with_field(hasher, path.as_str());
+        update_with_field(hasher, fs::read(&path).unwrap_or_default());
+    }
+}
+
+/// Checks if test artifacts of all `packages` exist and were built from sources matching `fingerprint`
+#[must_use]
+pub fn test_artifacts_up_to_date(
+    snforge_target_dir_path: &Utf8Path,
+    packages: &[PackageMetadata],
+    fingerprint: &str,
+) -> bool {
+    let fingerprint_matches =
+        fs::read_to_string(snforge_target_dir_path.join(TEST_ARTIFACTS_FINGERPRINT))
+            .is_ok_and(|saved_fingerprint| saved_fingerprint == fingerprint);
+
+    fingerprint_matches
+        && packages.iter().all(|package| {
+            snforge_target_dir_path
+                .join(format!("{}.snforge_sierra.json", package.name))
+                .exists()
+        })
+}
+
+pub fn save_test_artifacts_fingerprint(
+    snforge_target_dir_path: &Utf8Path,
+    fingerprint: &str,
+) -> Result<()> {
+    fs::create_dir_all(snforge_target_dir_path)?;
+    fs::write(
+        snforge_target_dir_path.join(TEST_ARTIFACTS_FINGERPRINT),
+        fingerprint,
+    )
+    .context("Failed to save test artifacts fingerprint")
+}
+
 pub(crate) fn load_test_artifacts(
     snforge_target_dir_path: &Utf8Path,
     package_name: &str,
@@ -59,15 +259,19 @@ pub(crate) fn load_test_artifacts(
 mod tests {
     use super::*;
     use crate::compiled_raw::RawForkParams;
+    use crate::gas_budget::GasBudgets;
+    use crate::hooks::Hooks;
     use crate::scarb::config::ForkTarget;
     use assert_fs::fixture::{FileWriteStr, PathChild, PathCopy};
     use assert_fs::TempDir;
     use camino::Utf8PathBuf;
-    use configuration::load_package_config;
+    use configuration::{load_global_config, load_package_config};
     use indoc::{formatdoc, indoc};
     use scarb_api::metadata::MetadataCommandExt;
     use scarb_metadata::PackageId;
+    use std::collections::HashMap;
     use std::env;
+    use std::num::NonZeroU32;
     use std::str::FromStr;
     use test_utils::tempdir_with_tool_versions;
 
@@ -173,11 +377,202 @@ mod tests {
                 fuzzer_runs: None,
                 fuzzer_seed: None,
                 max_n_steps: None,
+                max_recursion_depth: None,
+                available_gas: None,
+                gas_budgets: GasBudgets::default(),
+                hooks: Hooks::default(),
+                allowed_env_vars: None,
+                detailed_resources: false,
+                save_trace_data: false,
+                build_profile: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_artifacts_fingerprint_changes_with_sources() {
+        let temp = setup_package("simple_package");
+        let scarb_metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+        let snforge_target_dir_path =
+            Utf8PathBuf::try_from(temp.path().join("target/dev/snforge")).unwrap();
+
+        let fingerprint =
+            test_artifacts_fingerprint(&scarb_metadata, &[], &FeaturesSpec::default());
+        assert_eq!(
+            fingerprint,
+            test_artifacts_fingerprint(&scarb_metadata, &[], &FeaturesSpec::default())
+        );
+        assert!(!test_artifacts_up_to_date(
+            &snforge_target_dir_path,
+            &[],
+            &fingerprint
+        ));
+
+        save_test_artifacts_fingerprint(&snforge_target_dir_path, &fingerprint).unwrap();
+        assert!(test_artifacts_up_to_date(
+            &snforge_target_dir_path,
+            &[],
+            &fingerprint
+        ));
+
+        temp.child("src/lib.cairo")
+            .write_str("fn changed() {}")
+            .unwrap();
+        let changed_fingerprint =
+            test_artifacts_fingerprint(&scarb_metadata, &[], &FeaturesSpec::default());
+
+        assert_ne!(fingerprint, changed_fingerprint);
+        assert!(!test_artifacts_up_to_date(
+            &snforge_target_dir_path,
+            &[],
+            &changed_fingerprint
+        ));
+    }
+
+    #[test]
+    fn test_artifacts_fingerprint_changes_with_features() {
+        let temp = setup_package("simple_package");
+        let scarb_metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+
+        let features = FeaturesSpec {
+            features: vec!["enable_for_tests".to_string()],
+            ..Default::default()
+        };
+
+        assert_ne!(
+            test_artifacts_fingerprint(&scarb_metadata, &[], &FeaturesSpec::default()),
+            test_artifacts_fingerprint(&scarb_metadata, &[], &features)
+        );
+        assert_eq!(
+            features.to_env_vars(),
+            vec![("SCARB_FEATURES", "enable_for_tests".to_string())]
+        );
+    }
+
+    #[test]
+    fn get_forge_config_from_snfoundry_toml() {
+        let temp = setup_package("simple_package");
+        temp.child("snfoundry.toml")
+            .write_str(indoc!(
+                r#"
+                [snforge.default]
+                fuzzer_runs = 10
+
+                [snforge.ci]
+                exit_first = true
+                fuzzer_runs = 1000
+                max_n_steps = 500
+                max_recursion_depth = 100
+                gas_budget = 1000
+                allowed_env_vars = ["CI_SEED"]
+
+                [snforge.ci.test_gas_budgets]
+                "tests::heavy" = 5000
+
+                [snforge.ci.hooks]
+                pre_run = "echo pre"
+
+                [[snforge.ci.fork]]
+                name = "SNFOUNDRY_FORK"
+                url = "http://some.rpc.url"
+                block_id.number = "1"
+                "#
+            ))
+            .unwrap();
+        let snfoundry_dir = Utf8PathBuf::try_from(temp.path().to_path_buf()).unwrap();
+
+        let config =
+            load_global_config::<ForgeConfig>(&Some(snfoundry_dir.clone()), &None).unwrap();
+        assert_eq!(config.fuzzer_runs, Some(NonZeroU32::new(10).unwrap()));
+        assert!(!config.exit_first);
+
+        let config =
+            load_global_config::<ForgeConfig>(&Some(snfoundry_dir), &Some("ci".to_string()))
+                .unwrap();
+        assert_eq!(
+            config,
+            ForgeConfig {
+                exit_first: true,
+                fork: vec![ForkTarget::new(
+                    "SNFOUNDRY_FORK".to_string(),
+                    RawForkParams {
+                        url: "http://some.rpc.url".to_string(),
+                        block_id_type: "number".to_string(),
+                        block_id_value: "1".to_string(),
+                    },
+                )],
+                fuzzer_runs: Some(NonZeroU32::new(1000).unwrap()),
+                fuzzer_seed: None,
+                max_n_steps: Some(500),
+                max_recursion_depth: Some(100),
+                available_gas: None,
+                gas_budgets: GasBudgets {
+                    package: Some(1000),
+                    tests: HashMap::from([("tests::heavy".to_string(), 5000)]),
+                },
+                hooks: Hooks {
+                    pre_run: Some("echo pre".to_string()),
+                    post_run: None,
+                },
+                allowed_env_vars: Some(vec!["CI_SEED".to_string()]),
                 detailed_resources: false,
                 save_trace_data: false,
                 build_profile: false
             }
         );
+        assert!(config.is_env_var_allowed("CI_SEED"));
+        assert!(!config.is_env_var_allowed("HOME"));
+    }
+
+    #[test]
+    fn package_config_takes_precedence_over_global_config() {
+        let fork = |name: &str, url: &str| {
+            ForkTarget::new(
+                name.to_string(),
+                RawForkParams {
+                    url: url.to_string(),
+                    block_id_type: "number".to_string(),
+                    block_id_value: "1".to_string(),
+                },
+            )
+        };
+        let package_config = ForgeConfig {
+            fuzzer_runs: Some(NonZeroU32::new(10).unwrap()),
+            fork: vec![fork("SHARED", "http://package.url")],
+            ..Default::default()
+        };
+        let global_config = ForgeConfig {
+            exit_first: true,
+            fuzzer_runs: Some(NonZeroU32::new(1000).unwrap()),
+            max_n_steps: Some(500),
+            fork: vec![
+                fork("SHARED", "http://global.url"),
+                fork("GLOBAL", "http://global.url"),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            package_config.merge_with_global(global_config),
+            ForgeConfig {
+                exit_first: true,
+                fuzzer_runs: Some(NonZeroU32::new(10).unwrap()),
+                max_n_steps: Some(500),
+                fork: vec![
+                    fork("SHARED", "http://package.url"),
+                    fork("GLOBAL", "http://global.url"),
+                ],
+                ..Default::default()
+            }
+        );
     }
 
     #[test]
@@ -403,6 +798,11 @@ mod tests {
                 fuzzer_runs: None,
                 fuzzer_seed: None,
                 max_n_steps: None,
+                max_recursion_depth: None,
+                available_gas: None,
+                gas_budgets: GasBudgets::default(),
+                hooks: Hooks::default(),
+                allowed_env_vars: None,
                 detailed_resources: false,
                 save_trace_data: false,
                 build_profile: false
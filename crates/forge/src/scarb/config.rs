@@ -1,4 +1,6 @@
 use crate::compiled_raw::RawForkParams;
+use crate::gas_budget::GasBudgets;
+use crate::hooks::Hooks;
 use anyhow::{bail, Result};
 use itertools::Itertools;
 use serde::Deserialize;
@@ -27,6 +29,64 @@ pub struct ForgeConfig {
     pub fork: Vec<ForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Limit of nested calls
+    pub max_recursion_depth: Option<usize>,
+    /// Gas limit applied to tests without the `#[available_gas]` attribute
+    pub available_gas: Option<usize>,
+    /// Soft gas limits, tests exceeding them are reported in the summary
+    pub gas_budgets: GasBudgets,
+    /// Shell commands executed before and after running tests
+    pub hooks: Hooks,
+    /// Names of environment variables readable with `var`, all are readable if not set
+    pub allowed_env_vars: Option<Vec<String>>,
+}
+
+impl ForgeConfig {
+    /// Combines config from Scarb.toml with the one from snfoundry.toml
+    ///
+    /// Values set in Scarb.toml take precedence, forks from snfoundry.toml are added
+    /// unless a fork with the same name is defined in Scarb.toml
+    #[must_use]
+    pub fn merge_with_global(self, global: ForgeConfig) -> Self {
+        let mut fork = self.fork;
+        for global_fork in global.fork {
+            if !fork.iter().any(|f| f.name == global_fork.name) {
+                fork.push(global_fork);
+            }
+        }
+
+        ForgeConfig {
+            exit_first: self.exit_first || global.exit_first,
+            fuzzer_runs: self.fuzzer_runs.or(global.fuzzer_runs),
+            fuzzer_seed: self.fuzzer_seed.or(global.fuzzer_seed),
+            detailed_resources: self.detailed_resources || global.detailed_resources,
+            save_trace_data: self.save_trace_data || global.save_trace_data,
+            build_profile: self.build_profile || global.build_profile,
+            fork,
+            max_n_steps: self.max_n_steps.or(global.max_n_steps),
+            max_recursion_depth: self.max_recursion_depth.or(global.max_recursion_depth),
+            available_gas: self.available_gas.or(global.available_gas),
+            gas_budgets: GasBudgets {
+                package: self.gas_budgets.package.or(global.gas_budgets.package),
+                tests: global
+                    .gas_budgets
+                    .tests
+                    .into_iter()
+                    .chain(self.gas_budgets.tests)
+                    .collect(),
+            },
+            hooks: self.hooks.merge_with_global(global.hooks),
+            allowed_env_vars: self.allowed_env_vars.or(global.allowed_env_vars),
+        }
+    }
+
+    /// Checks if tests can read the environment variable `name` with `var`
+    #[must_use]
+    pub fn is_env_var_allowed(&self, name: &str) -> bool {
+        self.allowed_env_vars.as_ref().map_or(true, |allowed| {
+            allowed.iter().any(|allowed| allowed == name)
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -77,6 +137,20 @@ pub(crate) struct RawForgeConfig {
     pub fork: Vec<RawForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Limit of nested calls
+    pub max_recursion_depth: Option<usize>,
+    /// Gas limit applied to tests without the `#[available_gas]` attribute
+    pub available_gas: Option<usize>,
+    /// Soft gas limit of every test in the package
+    pub gas_budget: Option<u64>,
+    #[serde(default)]
+    /// Soft gas limits of single tests, by their fully qualified names
+    pub test_gas_budgets: HashMap<String, u64>,
+    #[serde(default)]
+    /// Shell commands executed before and after running tests
+    pub hooks: Hooks,
+    /// Names of environment variables readable with `var`, all are readable if not set
+    pub allowed_env_vars: Option<Vec<String>>,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Default, Clone)]
@@ -145,6 +219,14 @@ impl TryFrom<RawForgeConfig> for ForgeConfig {
             build_profile: value.build_profile,
             fork: fork_targets,
             max_n_steps: value.max_n_steps,
+            max_recursion_depth: value.max_recursion_depth,
+            available_gas: value.available_gas,
+            gas_budgets: GasBudgets {
+                package: value.gas_budget,
+                tests: value.test_gas_budgets,
+            },
+            hooks: value.hooks,
+            allowed_env_vars: value.allowed_env_vars,
         })
     }
 }
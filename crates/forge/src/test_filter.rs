@@ -14,6 +14,8 @@ pub struct TestsFilter {
     ignored_filter: IgnoredFilter,
     // based on rerun_failed flag
     last_failed_filter: bool,
+    // tests failed in the previous run, scheduled before other tests
+    previously_failed: Vec<String>,
 
     cache_dir_path: Utf8PathBuf,
 }
@@ -67,14 +69,25 @@ impl TestsFilter {
             NameFilter::All
         };
 
+        let previously_failed = cached_failed_tests_names(&cache_dir_path)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
         Self {
             name_filter,
             ignored_filter,
             last_failed_filter: rerun_failed,
+            previously_failed,
             cache_dir_path,
         }
     }
 
+    /// Whether tests are selected by their exact name
+    pub(crate) fn is_exact_match(&self) -> bool {
+        matches!(self.name_filter, NameFilter::ExactMatch(_))
+    }
+
     pub(crate) fn filter_tests(
         &self,
         test_crate: CompiledTestCrateRaw,
@@ -123,15 +136,22 @@ impl TestCaseFilter for TestsFilter {
             IgnoredFilter::NotIgnored => !test_case.ignored,
         }
     }
+
+    fn should_be_run_first(&self, test_case: &TestCaseRunnable) -> bool {
+        self.previously_failed.contains(&test_case.name)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::compiled_raw::{CompiledTestCrateRaw, CrateLocation, TestCaseRaw};
+    use crate::shared_cache::PREV_TESTS_FAILED;
     use crate::test_filter::TestsFilter;
     use cairo_lang_sierra::program::{Program, ProgramArtifact, Version, VersionedProgram};
-    use forge_runner::compiled_runnable::TestDetails;
+    use camino::Utf8PathBuf;
+    use forge_runner::compiled_runnable::{TestCaseRunnable, TestDetails};
     use forge_runner::expected_result::ExpectedTestResult;
+    use forge_runner::TestCaseFilter;
 
     fn program_for_testing() -> VersionedProgram {
         VersionedProgram::V1 {
@@ -704,4 +724,35 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn previously_failed_tests_are_run_first() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            cache_dir.path().join(PREV_TESTS_FAILED),
+            "crate1::failing\n",
+        )
+        .unwrap();
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            false,
+            false,
+            false,
+            Utf8PathBuf::try_from(cache_dir.path().to_path_buf()).unwrap(),
+        );
+
+        let test_case = |name: &str| TestCaseRunnable {
+            name: name.to_string(),
+            available_gas: None,
+            ignored: false,
+            expected_result: ExpectedTestResult::Success,
+            fork_config: None,
+            fuzzer_config: None,
+            test_details: TestDetails::default(),
+        };
+
+        assert!(tests_filter.should_be_run_first(&test_case("crate1::failing")));
+        assert!(!tests_filter.should_be_run_first(&test_case("crate1::passing")));
+    }
 }
@@ -6,13 +6,11 @@ use assert_fs::{
 };
 use blockifier::execution::deprecated_syscalls::DeprecatedSyscallSelector;
 use camino::Utf8PathBuf;
-use forge_runner::{
-    test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
-    test_crate_summary::TestCrateSummary,
-};
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
 use indoc::formatdoc;
 use scarb_api::{
-    get_contracts_map, metadata::MetadataCommandExt, ScarbCommand, StarknetContractArtifacts,
+    get_contracts_map, metadata::MetadataCommandExt, LazyStarknetContractArtifacts, ScarbCommand,
+    StarknetContractArtifacts,
 };
 use shared::command::CommandExt;
 use std::{
@@ -189,7 +187,7 @@ impl<'a> TestCase {
         ]
     }
 
-    pub fn contracts(&self) -> Result<HashMap<String, StarknetContractArtifacts>> {
+    pub fn contracts(&self) -> Result<HashMap<String, LazyStarknetContractArtifacts>> {
         self.contracts
             .clone()
             .into_iter()
@@ -197,18 +195,10 @@ impl<'a> TestCase {
                 let name = contract.name.clone();
                 let (sierra, casm) = contract.generate_sierra_and_casm()?;
 
-                Ok((name, StarknetContractArtifacts { sierra, casm }))
+                Ok((name, StarknetContractArtifacts { sierra, casm }.into()))
             })
             .collect()
     }
-
-    #[must_use]
-    pub fn find_test_result(results: &[TestCrateSummary]) -> &TestCrateSummary {
-        results
-            .iter()
-            .find(|tc| !tc.test_case_summaries.is_empty())
-            .unwrap()
-    }
 }
 
 #[macro_export]
@@ -225,9 +215,7 @@ macro_rules! test_case {
     });
 }
 
-pub fn assert_passed(result: &[TestCrateSummary]) {
-    let result = &TestCase::find_test_result(result).test_case_summaries;
-
+pub fn assert_passed(result: &[AnyTestCaseSummary]) {
     assert!(!result.is_empty(), "No test results found");
     assert!(
         result.iter().all(AnyTestCaseSummary::is_passed),
@@ -235,9 +223,7 @@ pub fn assert_passed(result: &[TestCrateSummary]) {
     );
 }
 
-pub fn assert_failed(result: &[TestCrateSummary]) {
-    let result = &TestCase::find_test_result(result).test_case_summaries;
-
+pub fn assert_failed(result: &[AnyTestCaseSummary]) {
     assert!(!result.is_empty(), "No test results found");
     assert!(
         result.iter().all(AnyTestCaseSummary::is_failed),
@@ -246,15 +232,13 @@ pub fn assert_failed(result: &[TestCrateSummary]) {
 }
 
 pub fn assert_case_output_contains(
-    result: &[TestCrateSummary],
+    result: &[AnyTestCaseSummary],
     test_case_name: &str,
     asserted_msg: &str,
 ) {
     let test_name_suffix = format!("::{test_case_name}");
 
-    let result = TestCase::find_test_result(result);
-
-    assert!(result.test_case_summaries.iter().any(|any_case| {
+    assert!(result.iter().any(|any_case| {
         if any_case.is_passed() || any_case.is_failed() {
             return any_case.msg().unwrap().contains(asserted_msg)
                 && any_case
@@ -266,12 +250,10 @@ pub fn assert_case_output_contains(
     }));
 }
 
-pub fn assert_gas(result: &[TestCrateSummary], test_case_name: &str, asserted_gas: u128) {
+pub fn assert_gas(result: &[AnyTestCaseSummary], test_case_name: &str, asserted_gas: u128) {
     let test_name_suffix = format!("::{test_case_name}");
 
-    let result = TestCase::find_test_result(result);
-
-    assert!(result.test_case_summaries.iter().any(|any_case| {
+    assert!(result.iter().any(|any_case| {
         match any_case {
             AnyTestCaseSummary::Fuzzing(_) => {
                 panic!("Cannot use assert_gas! for fuzzing tests")
@@ -291,16 +273,14 @@ pub fn assert_gas(result: &[TestCrateSummary], test_case_name: &str, asserted_ga
 }
 
 pub fn assert_syscall(
-    result: &[TestCrateSummary],
+    result: &[AnyTestCaseSummary],
     test_case_name: &str,
     syscall: DeprecatedSyscallSelector,
     expected_count: usize,
 ) {
     let test_name_suffix = format!("::{test_case_name}");
 
-    let result = TestCase::find_test_result(result);
-
-    assert!(result.test_case_summaries.iter().any(|any_case| {
+    assert!(result.iter().any(|any_case| {
         match any_case {
             AnyTestCaseSummary::Fuzzing(_) => {
                 panic!("Cannot use assert_syscall! for fuzzing tests")
@@ -320,7 +300,7 @@ pub fn assert_syscall(
 }
 
 pub fn assert_builtin(
-    result: &[TestCrateSummary],
+    result: &[AnyTestCaseSummary],
     test_case_name: &str,
     builtin: &str,
     expected_count: usize,
@@ -328,9 +308,7 @@ pub fn assert_builtin(
     let test_name_suffix = format!("::{test_case_name}");
     let builtin = builtin.to_string();
 
-    let result = TestCase::find_test_result(result);
-
-    assert!(result.test_case_summaries.iter().any(|any_case| {
+    assert!(result.iter().any(|any_case| {
         match any_case {
             AnyTestCaseSummary::Fuzzing(_) => {
                 panic!("Cannot use assert_builtin! for fuzzing tests")
@@ -2,9 +2,10 @@ use crate::runner::TestCase;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge::block_number_map::BlockNumberMap;
+use forge::gas_budget::GasBudgets;
 use forge::run;
 use forge::test_filter::TestsFilter;
-use forge_runner::test_crate_summary::TestCrateSummary;
+use forge_runner::test_case_summary::AnyTestCaseSummary;
 use forge_runner::{RunnerConfig, RunnerParams};
 use shared::command::CommandExt;
 use std::num::NonZeroU32;
@@ -16,7 +17,7 @@ use tempfile::tempdir;
 use tokio::runtime::Runtime;
 
 #[must_use]
-pub fn run_test_case(test: &TestCase) -> Vec<TestCrateSummary> {
+pub fn run_test_case(test: &TestCase) -> Vec<AnyTestCaseSummary> {
     Command::new("scarb")
         .current_dir(test.path().unwrap())
         .arg("snforge-test-collector")
@@ -26,6 +27,7 @@ pub fn run_test_case(test: &TestCase) -> Vec<TestCrateSummary> {
         .unwrap();
 
     let rt = Runtime::new().expect("Could not instantiate Runtime");
+    let mut results = vec![];
 
     rt.block_on(run(
         "test_package",
@@ -40,13 +42,20 @@ pub fn run_test_case(test: &TestCase) -> Vec<TestCrateSummary> {
             false,
             false,
             None,
+            None,
+            Default::default(),
         )),
         Arc::new(RunnerParams::new(
             ContractsData::try_from(test.contracts().unwrap()).unwrap(),
             test.env().clone(),
         )),
         &[],
+        None,
+        &GasBudgets::default(),
         &mut BlockNumberMap::default(),
+        &mut |result, _| results.push(result.clone()),
     ))
-    .expect("Runner fail")
+    .expect("Runner fail");
+
+    results
 }
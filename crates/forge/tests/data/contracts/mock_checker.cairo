@@ -11,6 +11,7 @@ trait IMockChecker<TContractState> {
     fn get_constant_thing(ref self: TContractState) -> felt252;
     fn get_struct_thing(ref self: TContractState) -> StructThing;
     fn get_arr_thing(ref self: TContractState) -> Array<StructThing>;
+    fn get_thing_plus(ref self: TContractState, amount: felt252) -> felt252;
 }
 
 #[starknet::contract]
@@ -50,5 +51,9 @@ mod MockChecker {
         fn get_arr_thing(ref self: ContractState) -> Array<StructThing> {
             array![StructThing {item_one: 12, item_two: 21}]
         }
+
+        fn get_thing_plus(ref self: ContractState, amount: felt252) -> felt252 {
+            self.stored_thing.read() + amount
+        }
     }
 }
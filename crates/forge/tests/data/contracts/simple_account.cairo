@@ -0,0 +1,80 @@
+use starknet::ContractAddress;
+
+#[derive(Drop, Serde)]
+struct Call {
+    to: ContractAddress,
+    selector: felt252,
+    calldata: Array<felt252>,
+}
+
+#[starknet::interface]
+trait ISimpleAccount<TContractState> {
+    fn __validate__(self: @TContractState, calls: Array<Call>) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+    fn is_valid_signature(
+        self: @TContractState, hash: felt252, signature: Array<felt252>
+    ) -> felt252;
+}
+
+#[starknet::contract]
+mod SimpleAccount {
+    use super::Call;
+    use starknet::{get_caller_address, get_tx_info, call_contract_syscall, SyscallResultTrait};
+    use ecdsa::check_ecdsa_signature;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[abi(embed_v0)]
+    impl SimpleAccountImpl of super::ISimpleAccount<ContractState> {
+        fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+            let tx_info = get_tx_info().unbox();
+            let mut signature = array![];
+            signature.append_span(tx_info.signature);
+            self.is_valid_signature(tx_info.transaction_hash, signature)
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let caller: felt252 = get_caller_address().into();
+            assert(caller == 0, 'Invalid caller');
+
+            let mut calls = calls;
+            let mut results = array![];
+            loop {
+                match calls.pop_front() {
+                    Option::Some(call) => {
+                        let result = call_contract_syscall(
+                            call.to, call.selector, call.calldata.span()
+                        )
+                            .unwrap_syscall();
+                        results.append(result);
+                    },
+                    Option::None => { break; },
+                };
+            };
+            results
+        }
+
+        fn is_valid_signature(
+            self: @ContractState, hash: felt252, signature: Array<felt252>
+        ) -> felt252 {
+            if signature.len() != 2 {
+                return 0;
+            }
+            if check_ecdsa_signature(
+                hash, self.public_key.read(), *signature.at(0), *signature.at(1)
+            ) {
+                'VALID'
+            } else {
+                0
+            }
+        }
+    }
+}
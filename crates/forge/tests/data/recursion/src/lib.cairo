@@ -0,0 +1,25 @@
+#[starknet::interface]
+trait IRecursive<TContractState> {
+    fn recurse(self: @TContractState, depth: felt252);
+}
+
+#[starknet::contract]
+mod Recursive {
+    use starknet::get_contract_address;
+    use super::{IRecursiveDispatcher, IRecursiveDispatcherTrait};
+
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl IRecursiveImpl of super::IRecursive<ContractState> {
+        // Calls itself until the given depth is reached
+        fn recurse(self: @ContractState, depth: felt252) {
+            if depth == 0 {
+                return;
+            }
+            let dispatcher = IRecursiveDispatcher { contract_address: get_contract_address() };
+            dispatcher.recurse(depth - 1);
+        }
+    }
+}
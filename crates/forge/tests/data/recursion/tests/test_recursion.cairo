@@ -0,0 +1,23 @@
+use result::ResultTrait;
+use array::ArrayTrait;
+use snforge_std::{declare, ContractClassTrait};
+
+use recursion::{IRecursiveDispatcher, IRecursiveDispatcherTrait};
+
+fn deploy_recursive() -> IRecursiveDispatcher {
+    let contract = declare("Recursive");
+    let contract_address = contract.deploy(@array![]).unwrap();
+    IRecursiveDispatcher { contract_address }
+}
+
+#[test]
+fn recursion_within_limit() {
+    let dispatcher = deploy_recursive();
+    dispatcher.recurse(5);
+}
+
+#[test]
+fn recursion_exceeding_limit() {
+    let dispatcher = deploy_recursive();
+    dispatcher.recurse(20);
+}
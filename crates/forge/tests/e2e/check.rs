@@ -0,0 +1,62 @@
+use super::common::runner::{runner, setup_package};
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[test]
+fn check_simple_package() {
+    let temp = setup_package("simple_package");
+    let output = runner(&temp).arg("check").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 13 test(s) from simple_package package
+        Checked 2 test(s) from src/
+        Checked 11 test(s) from tests/
+        Check: 13 test(s) compiled successfully
+        "},
+    );
+}
+
+#[test]
+fn check_does_not_run_tests() {
+    let temp = setup_package("simple_package");
+    let output = runner(&temp).arg("check").assert().success();
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("[PASS]"));
+    assert!(!stdout.contains("[FAIL]"));
+    assert!(!stdout.contains("Tests:"));
+}
+
+#[test]
+fn check_with_failing_scarb_build() {
+    let temp = setup_package("simple_package");
+    temp.child("src/lib.cairo")
+        .write_str(indoc!(
+            r"
+        mod hello_starknet;
+        mods erc20;
+    "
+        ))
+        .unwrap();
+
+    runner(&temp)
+        .arg("check")
+        .assert()
+        .code(3)
+        .stdout_eq(indoc! {r"
+            ...
+            [ERROR] Skipped tokens. Expected: Module/Use/FreeFunction.
+             --> [..]lib.cairo:2:1
+            mods erc20;
+            ^**^
+            ...
+            [ERROR] Failed to build test artifacts with Scarb: `scarb` reported [..] error(s) in [..]lib.cairo:2:1[..]
+        "});
+}
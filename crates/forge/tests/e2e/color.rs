@@ -35,3 +35,27 @@ fn color_never() {
         "output not expected to be colored but it is"
     );
 }
+
+#[test]
+fn no_color_env() {
+    let temp = setup_package("simple_package");
+    let snapbox = runner_color("auto")
+        .env("CLICOLOR_FORCE", "1")
+        .env("NO_COLOR", "1");
+    let output = snapbox.current_dir(&temp).assert().code(1);
+    assert!(
+        !is_colored(&output),
+        "output not expected to be colored but it is"
+    );
+}
+
+#[test]
+fn color_always_overrides_no_color_env() {
+    let temp = setup_package("simple_package");
+    let snapbox = runner_color("always").env("NO_COLOR", "1");
+    let output = snapbox.current_dir(&temp).assert().code(1);
+    assert!(
+        is_colored(&output),
+        "output expected to be colored but it is not"
+    );
+}
@@ -0,0 +1,41 @@
+use super::common::runner::{runner, setup_package};
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+
+#[test]
+fn completions_for_all_shells() {
+    let temp = setup_package("simple_package");
+
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let output = runner(&temp)
+            .args(["completions", shell])
+            .assert()
+            .success();
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+        assert!(stdout.contains("snforge"));
+        assert!(stdout.contains("clean-cache"));
+    }
+}
+
+#[test]
+fn completions_include_config_profiles() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r"
+            [snforge.default]
+            fuzzer_runs = 10
+
+            [snforge.ci_profile]
+            exit_first = true
+        "})
+        .unwrap();
+
+    let output = runner(&temp)
+        .args(["completions", "bash"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("ci_profile"));
+}
@@ -8,7 +8,7 @@ fn print_error_if_attributes_incorrect() {
     let mock_tests_dir_path = mock_tests_dir.path().canonicalize().unwrap();
     let mock_tests_dir_path_str = mock_tests_dir_path.to_str().unwrap();
 
-    let output = test_runner(&mock_tests_dir).assert().code(2);
+    let output = test_runner(&mock_tests_dir).assert().code(3);
     assert_stderr_contains(
         output,
         formatdoc! {r#"
@@ -1,4 +1,5 @@
 use super::common::runner::{setup_package, test_runner};
+use assert_fs::fixture::{FileWriteStr, PathChild};
 use indoc::indoc;
 use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 
@@ -238,3 +239,46 @@ fn fuzzing_exit_first_single_fail() {
         "},
     );
 }
+
+#[test]
+fn random_values_reproducible_with_rng_seed() {
+    let temp = setup_package("simple_package");
+    temp.child("tests/random.cairo")
+        .write_str(indoc!(
+            r"
+        use snforge_std::rand_felt;
+
+        #[test]
+        fn failing_with_random_value() {
+            let value = rand_felt();
+            assert(value == 0, value);
+        }
+    "
+        ))
+        .unwrap();
+
+    let run_with_seed = |seed: &str| {
+        let output = test_runner(&temp)
+            .args(["failing_with_random_value", "--rng-seed", seed])
+            .assert()
+            .code(1);
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+        let mut lines = stdout.lines();
+        lines.find(|line| line.trim() == "Failure data:").unwrap();
+        (lines.next().unwrap().to_string(), output)
+    };
+
+    let (first_value, output) = run_with_seed("1234");
+    let (second_value, _) = run_with_seed("1234");
+    let (other_seed_value, _) = run_with_seed("4321");
+
+    assert_eq!(first_value, second_value);
+    assert_ne!(first_value, other_seed_value);
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] tests::random::failing_with_random_value
+        [..]Random values were generated with seed = 1234, pass it to `--rng-seed` to reproduce them
+        "},
+    );
+}
@@ -2,8 +2,10 @@ pub(crate) mod common;
 
 mod build_profile;
 mod build_trace_data;
+mod check;
 mod collection;
 mod color;
+mod completions;
 mod components;
 mod diagnostics_and_plugins;
 mod env;
@@ -11,6 +13,7 @@ mod fork_warning;
 mod forking;
 mod fuzzing;
 mod io_operations;
+mod recursion;
 mod running;
 mod steps;
 mod trace_print;
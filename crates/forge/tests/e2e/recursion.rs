@@ -0,0 +1,64 @@
+use super::common::runner::{setup_package, test_runner};
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[test]
+fn should_fail_when_recursion_depth_exceeded() {
+    let temp = setup_package("recursion");
+
+    let output = test_runner(&temp)
+        .args(["--max-recursion-depth", "10"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc!(
+            r"
+                [..]Compiling[..]
+                [..]Finished[..]
+
+
+                Collected 2 test(s) from recursion package
+                Running 2 test(s) from tests/
+                [PASS] tests::test_recursion::recursion_within_limit (gas: ~[..])
+                [FAIL] tests::test_recursion::recursion_exceeding_limit
+
+                Failure data:
+                [..]recursion depth exceeded[..]
+
+                Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+
+                Failures:
+                    tests::test_recursion::recursion_exceeding_limit
+            "
+        ),
+    );
+}
+
+#[test]
+fn should_allow_recursion_within_default_limit() {
+    let temp = setup_package("recursion");
+
+    let output = test_runner(&temp)
+        .arg("tests::test_recursion::recursion_exceeding_limit")
+        .arg("--exact")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc!(
+            r"
+                [..]Compiling[..]
+                [..]Finished[..]
+
+
+                Collected 1 test(s) from recursion package
+                Running 1 test(s) from tests/
+                [PASS] tests::test_recursion::recursion_exceeding_limit (gas: ~[..])
+                Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 1 filtered out
+            "
+        ),
+    );
+}
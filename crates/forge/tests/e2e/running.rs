@@ -47,10 +47,22 @@ fn simple_package() {
     
     [PASS] tests::without_prefix::five [..]
     Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+    Tests by module in simple_package:
+        simple_package::tests: 1 passed, 0 failed, 1 ignored
+        tests::contract: 1 passed, 0 failed, 0 ignored
+        tests::ext_function_test: 2 passed, 0 failed, 1 ignored
+        tests::test_simple: 4 passed, 2 failed, 0 ignored
+            [FAIL] tests::test_simple::test_failing
+            [FAIL] tests::test_simple::test_another_failing
+        tests::without_prefix: 1 passed, 0 failed, 0 ignored
     
     Failures:
         tests::test_simple::test_failing
         tests::test_simple::test_another_failing
+
+    Repeated failures:
+        2 tests failed with the same data, e.g. tests::test_simple::test_[..]:
+            0x6661696c696e6720636865636b ('failing check')
     "},
     );
 }
@@ -143,11 +155,149 @@ fn with_failing_scarb_build() {
         ))
         .unwrap();
 
-    test_runner(&temp).assert().code(2).stdout_eq(indoc! {r"
-            [ERROR] Failed to build test artifacts with Scarb: `scarb` exited with error
+    test_runner(&temp).assert().code(3).stdout_eq(indoc! {r"
+            ...
+            [ERROR] Skipped tokens. Expected: Module/Use/FreeFunction.
+             --> [..]lib.cairo:2:1
+            mods erc20;
+            ^**^
+            ...
+            [ERROR] Failed to build test artifacts with Scarb: `scarb` reported [..] error(s) in [..]lib.cairo:2:1[..]
         "});
 }
 
+#[test]
+fn with_failing_scarb_build_json() {
+    let temp = setup_package("simple_package");
+    let lib_file = temp.child("src/lib.cairo");
+    lib_file
+        .write_str(indoc!(
+            r"
+        mod hello_starknet;
+        mods erc20;
+    "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp).arg("--json").assert().code(3);
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let diagnostic = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|message| message["type"] == "diagnostic" && message["line"] == 2)
+        .expect("Diagnostic with location was not printed");
+
+    assert_eq!(diagnostic["severity"], "error");
+    assert_eq!(diagnostic["column"], 1);
+    assert!(diagnostic["file"].as_str().unwrap().ends_with("lib.cairo"));
+    assert!(diagnostic["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Skipped tokens. Expected: Module/Use/FreeFunction."));
+}
+
+#[test]
+fn with_missing_fork_configuration() {
+    let temp = setup_package("simple_package");
+    temp.child("tests/missing_fork.cairo")
+        .write_str(indoc!(
+            r#"
+        #[test]
+        #[fork("MISSING_FORK")]
+        fn missing_fork() {
+            assert(1 == 1, 'simple check');
+        }
+    "#
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp).arg("missing_fork").assert().code(4);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        [ERROR] Fork configuration named = MISSING_FORK not found in the Scarb.toml
+        "},
+    );
+}
+
+#[test]
+fn with_contract_declared_from_file() {
+    let temp = setup_package("simple_package");
+    temp.child("tests/declare_from_file.cairo")
+        .write_str(indoc!(
+            r#"
+        use snforge_std::{declare_from_file, ContractClassTrait};
+
+        use simple_package::hello_starknet::{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait};
+
+        #[test]
+        fn declare_and_deploy_from_file() {
+            let contract = declare_from_file(
+                "target/dev/simple_package_HelloStarknet.contract_class.json"
+            );
+            let contract_address = contract.deploy(@array![]).unwrap();
+            let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+            dispatcher.increase_balance(100);
+            assert(dispatcher.get_balance() == 100, 'balance == 100');
+        }
+    "#
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("declare_and_deploy_from_file")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] tests::declare_from_file::declare_and_deploy_from_file [..]
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 13 filtered out
+        "},
+    );
+}
+
+#[test]
+fn with_release_profile() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .args(["--profile", "release", "two"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_simple::test_two [..]
+        [PASS] tests::test_simple::test_two_and_two [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 11 filtered out
+        "},
+    );
+    assert!(temp.child("target/release/snforge").path().is_dir());
+    assert!(!temp.child("target/dev/snforge").path().exists());
+}
+
 #[test]
 fn with_filter() {
     let temp = setup_package("simple_package");
@@ -208,6 +358,10 @@ fn with_exact_filter() {
         .assert()
         .success();
 
+    // Crates without the matched test are skipped
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("from src/"));
+
     assert_stdout_contains(
         output,
         indoc! {r"
@@ -216,7 +370,6 @@ fn with_exact_filter() {
 
 
         Collected 1 test(s) from simple_package package
-        Running 0 test(s) from src/
         Running 1 test(s) from tests/
         [PASS] tests::test_simple::test_two [..]
         Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 12 filtered out
@@ -241,7 +394,6 @@ fn with_gas_usage() {
 
 
         Collected 1 test(s) from simple_package package
-        Running 0 test(s) from src/
         Running 1 test(s) from tests/
         [PASS] tests::test_simple::test_two (gas: ~1)
         Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 12 filtered out
@@ -249,6 +401,164 @@ fn with_gas_usage() {
     );
 }
 
+#[test]
+fn with_exceeded_gas_budget() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r#"
+            [snforge.default]
+            gas_budget = 100
+
+            [snforge.default.test_gas_budgets]
+            "tests::test_simple::test_two_and_two" = 0
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp).arg("two").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_simple::test_two [..]
+        [PASS] tests::test_simple::test_two_and_two [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 11 filtered out
+        Gas budget exceeded:
+            tests::test_simple::test_two_and_two (gas: ~1, budget: 0)
+        "},
+    );
+}
+
+#[test]
+fn with_html_report() {
+    let temp = setup_package("simple_package");
+
+    let output = test_runner(&temp)
+        .args(["two", "--report", "html"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_simple::test_two [..]
+        [PASS] tests::test_simple::test_two_and_two [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 11 filtered out
+
+        Test report saved to: [..]report.html
+        "},
+    );
+
+    let report = fs::read_to_string(temp.child("target/dev/snforge/report.html")).unwrap();
+    assert!(report.contains("Tests: 2 passed, 0 failed, 0 ignored"));
+    assert!(report.contains("tests::test_simple::test_two_and_two"));
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn with_hooks() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r#"
+            [snforge.default.hooks]
+            pre_run = "echo pre_run $SNFORGE_PACKAGE_NAME"
+            post_run = "echo post_run $SNFORGE_TESTS_RESULT $SNFORGE_TESTS_PASSED $SNFORGE_TESTS_FAILED"
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp).arg("two").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        pre_run simple_package
+
+
+        Collected 2 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] tests::test_simple::test_two [..]
+        [PASS] tests::test_simple::test_two_and_two [..]
+        Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 11 filtered out
+        post_run passed 2 0
+        "},
+    );
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn post_run_hook_executed_on_error() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r#"
+            [snforge.default.hooks]
+            post_run = "echo post_run $SNFORGE_PACKAGE_NAME $SNFORGE_TESTS_RESULT"
+        "#})
+        .unwrap();
+    temp.child("tests/missing_fork.cairo")
+        .write_str(indoc!(
+            r#"
+        #[test]
+        #[fork("MISSING_FORK")]
+        fn missing_fork() {
+            assert(1 == 1, 'simple check');
+        }
+    "#
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp).arg("missing_fork").assert().code(4);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        post_run simple_package error
+        [ERROR] Fork configuration named = MISSING_FORK not found in the Scarb.toml
+        "},
+    );
+}
+
+#[test]
+fn with_failing_pre_run_hook() {
+    let temp = setup_package("simple_package");
+    temp.child("snfoundry.toml")
+        .write_str(indoc! {r#"
+            [snforge.default.hooks]
+            pre_run = "exit 1"
+        "#})
+        .unwrap();
+
+    let output = test_runner(&temp).assert().code(2);
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("Collected"));
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+        [ERROR] `pre_run` hook `exit 1` failed with exit status: 1
+        "},
+    );
+}
+
 #[test]
 fn with_non_matching_filter() {
     let temp = setup_package("simple_package");
@@ -768,6 +1078,129 @@ fn init_new_project_test() {
     );
 }
 
+#[test]
+fn new_in_existing_package_test() {
+    let temp = tempdir_with_tool_versions().unwrap();
+    temp.child("Scarb.toml")
+        .write_str(indoc!(
+            r#"
+            [package]
+            name = "existing_package"
+            version = "0.1.0"
+
+            [dependencies]
+            starknet = "2.5.4"
+            "#
+        ))
+        .unwrap();
+    temp.child("src/lib.cairo")
+        .write_str("fn existing() {}\n")
+        .unwrap();
+
+    runner(&temp).arg("new").assert().success();
+
+    let manifest = fs::read_to_string(temp.child("Scarb.toml").path()).unwrap();
+    let document = manifest.parse::<Document>().unwrap();
+    assert!(document["dev-dependencies"].get("snforge_std").is_some());
+    assert_eq!(document["dependencies"]["starknet"].as_str(), Some("2.5.4"));
+    assert_eq!(
+        document["target"]["starknet-contract"][0]["casm"].as_bool(),
+        Some(true)
+    );
+
+    let lib = fs::read_to_string(temp.child("src/lib.cairo").path()).unwrap();
+    assert_eq!(lib, "fn existing() {}\n");
+
+    let gitignore = fs::read_to_string(temp.child(".gitignore").path()).unwrap();
+    assert!(gitignore.contains(".snfoundry_cache/"));
+    assert!(temp.child("tests").path().is_dir());
+
+    // Running again does not duplicate any entries
+    runner(&temp).arg("new").assert().success();
+    let gitignore = fs::read_to_string(temp.child(".gitignore").path()).unwrap();
+    assert_eq!(gitignore.matches(".snfoundry_cache/").count(), 1);
+}
+
+#[test]
+fn new_without_scarb_toml_test() {
+    let temp = tempdir_with_tool_versions().unwrap();
+
+    let output = runner(&temp).arg("new").assert().code(2);
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [ERROR] Scarb.toml not found in [..], use `snforge init` to create a new project instead
+        "},
+    );
+}
+
+#[test]
+fn init_new_project_from_erc20_template_test() {
+    let temp = tempdir_with_tool_versions().unwrap();
+
+    runner(&temp)
+        .args(["init", "test_name", "--template", "erc20"])
+        .assert()
+        .success();
+
+    let lib = std::fs::read_to_string(temp.child("test_name/src/lib.cairo").path()).unwrap();
+    assert!(lib.contains("mod ERC20 {"));
+
+    let tests =
+        std::fs::read_to_string(temp.child("test_name/tests/test_erc20.cairo").path()).unwrap();
+    assert!(tests.contains("use test_name::IERC20Dispatcher;"));
+    assert!(!tests.contains("{{ PROJECT_NAME }}"));
+    assert!(!temp
+        .child("test_name/tests/test_contract.cairo")
+        .path()
+        .exists());
+
+    let remote_url = get_remote_url();
+    let branch = get_current_branch();
+    temp.child("test_name/Scarb.toml")
+        .write_str(&formatdoc!(
+            r#"
+        [package]
+        name = "test_name"
+        version = "0.1.0"
+
+        [[target.starknet-contract]]
+        casm = true
+
+        [dependencies]
+        starknet = "2.5.4"
+
+        [dev-dependencies]
+        snforge_std = {{ git = "https://github.com/{}", branch = "{}" }}
+        "#,
+            remote_url,
+            branch
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .current_dir(temp.child(Path::new("test_name")))
+        .assert()
+        .success();
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 4 test(s) from test_name package
+        Running 0 test(s) from src/
+        Running 4 test(s) from tests/
+        [PASS] tests::test_erc20::test_constructor [..]
+        [PASS] tests::test_erc20::test_transfer [..]
+        [PASS] tests::test_erc20::test_transfer_from [..]
+        [PASS] tests::test_erc20::test_cannot_transfer_more_than_balance [..]
+        Tests: 4 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+    "},
+    );
+}
+
 #[test]
 fn should_panic() {
     let temp = tempdir_with_tool_versions().unwrap();
@@ -844,7 +1277,7 @@ fn printing_in_contracts() {
         output,
         indoc! {r#"
         [..]Compiling[..]
-        warn: libfunc `print` is not allowed in the libfuncs list `Default libfunc list`
+        [WARNING] libfunc `print` is not allowed in the libfuncs list `Default libfunc list`
          --> contract: HelloStarknet
         help: try compiling with the `experimental` list
          --> Scarb.toml
@@ -942,3 +1375,110 @@ fn detailed_resources_flag() {
         "},
     );
 }
+
+#[test]
+fn quiet_flag() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp).arg("--quiet").assert().code(1);
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("[PASS]"));
+    assert!(!stdout.contains("[IGNORE]"));
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 13 test(s) from simple_package package
+        Running 2 test(s) from src/
+        Running 11 test(s) from tests/
+        [FAIL] tests::test_simple::test_failing
+        [FAIL] tests::test_simple::test_another_failing
+        Tests: 9 passed, 2 failed, 0 skipped, 2 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn verbose_flag() {
+    let temp = setup_package("erc20_package");
+    let output = test_runner(&temp).arg("-v").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from erc20_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] tests::test_complex::complex[..]
+                steps: [..]
+                memory holes: [..]
+                builtins: ([..])
+                syscalls: ([..])
+                
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn storage_trace_flag() {
+    let temp = setup_package("erc20_package");
+    let output = test_runner(&temp).arg("-vvv").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from erc20_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] tests::test_complex::complex[..]
+        Success data:
+            Storage accesses:
+              read [..]
+              write [..]
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn failed_tests_storage_trace_flag() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["test_simple::test_failing", "-vv"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from simple_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [FAIL] tests::test_simple::test_failing
+
+        Failure data:
+            0x6661696c696e6720636865636b ('failing check')
+
+            Storage accesses: none
+
+        Tests: 0 passed, 1 failed, 0 skipped, 0 ignored, 12 filtered out
+        "},
+    );
+}
@@ -236,3 +236,43 @@ fn store_load_cairo0_contract() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn storage_accesses_cairo0_contract() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use starknet::{{contract_address_const}};
+            use snforge_std::get_storage_accesses;
+
+            #[starknet::interface]
+            trait IERC20<TContractState> {{
+                fn name(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{}", block_id: BlockId::Number(54060))]
+            fn storage_accesses_cairo0_contract() {{
+                let eth_dispatcher = IERC20Dispatcher {{
+                    contract_address: contract_address_const::<
+                        0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7
+                    >()
+                }};
+
+                assert(eth_dispatcher.name() == 'Ether', 'invalid name');
+
+                let accesses = get_storage_accesses();
+                let read = accesses.at(accesses.len() - 1);
+                assert(*read.contract_address == eth_dispatcher.contract_address, 'wrong address');
+                assert(*read.key == selector!("ERC20_name"), 'wrong key');
+                assert(!*read.is_write, 'not a read');
+                assert(*read.value == 'Ether', 'wrong read value');
+            }}
+        "#,
+        CHEATNET_RPC_URL,
+    )
+    .as_str());
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,65 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn deal_erc20() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use array::ArrayTrait;
+        use result::ResultTrait;
+        use starknet::{ContractAddress, contract_address_const};
+        use snforge_std::{ declare, ContractClassTrait, deal, deal_with_balances_variable, load,
+            map_entry_address };
+
+        #[starknet::interface]
+        trait IERC20<TContractState> {
+            fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
+            fn transfer(ref self: TContractState, recipient: ContractAddress, amount: u256);
+        }
+
+        #[test]
+        fn deal_with_custom_balances_variable() {
+            let user_address = contract_address_const::<1234>();
+            let calldata = array!['token', 'TKN', 18, 1111, 0, user_address.into()];
+
+            let contract = declare("ERC20");
+            let contract_address = contract.deploy(@calldata).unwrap();
+            let dispatcher = IERC20Dispatcher { contract_address };
+
+            let other_address = contract_address_const::<9999>();
+            let amount = 0x1_00000000000000000000000000000002_u256;
+            deal_with_balances_variable(contract_address, other_address, amount, selector!("balances"));
+
+            assert(dispatcher.balance_of(other_address) == amount, 'Wrong balance');
+            assert(dispatcher.balance_of(user_address) == 1111, 'Balance changed');
+        }
+
+        #[test]
+        fn deal_fee_token() {
+            let fee_token = contract_address_const::<0x1001>();
+            let account = contract_address_const::<1234>();
+
+            deal(fee_token, account, 1000_u256);
+
+            let balance = load(
+                fee_token, map_entry_address(selector!("ERC20_balances"), array![account.into()].span()), 2
+            );
+            assert(balance == array![1000, 0], 'Wrong balance');
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "ERC20".to_string(),
+            Path::new("tests/data/contracts/erc20.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
@@ -244,6 +244,126 @@ fn handling_bytearray_based_errors() {
     assert_passed(&result);
 }
 
+#[test]
+fn decoding_panic_data() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use starknet::ContractAddress;
+        use snforge_std::{ declare, ContractClassTrait };
+        use snforge_std::errors::{ decode_panic_data, PanicDataItem };
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+            fn do_a_panic_with_bytearray(self: @TContractState);
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn decoding_panic_data() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IHelloStarknetSafeDispatcher { contract_address };
+
+            let selector = selector!("InsufficientBalance");
+            match safe_dispatcher.do_a_panic_with(array!['Not owner', selector, 10]) {
+                Result::Ok(_) => panic_with_felt252('shouldve panicked'),
+                Result::Err(panic_data) => {
+                    assert(
+                        decode_panic_data(@panic_data) == array![
+                            PanicDataItem::ShortString('Not owner'),
+                            PanicDataItem::Felt(selector),
+                            PanicDataItem::Felt(10),
+                        ],
+                        'wrong panic data'
+                    );
+                }
+            };
+
+            match safe_dispatcher.do_a_panic_with_bytearray() {
+                Result::Ok(_) => panic_with_felt252('shouldve panicked'),
+                Result::Err(panic_data) => {
+                    assert(
+                        decode_panic_data(@panic_data) == array![
+                            PanicDataItem::String(
+                                "This is a very long\n and multiline message that is certain to fill the buffer"
+                            )
+                        ],
+                        'wrong panic data'
+                    );
+                }
+            };
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn decoding_panic_data_with_bytearray_and_felts() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use starknet::ContractAddress;
+        use snforge_std::{ declare, ContractClassTrait };
+        use snforge_std::errors::{ decode_panic_data, PanicDataItem };
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn decoding_panic_data_with_bytearray_and_felts() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IHelloStarknetSafeDispatcher { contract_address };
+
+            let mut panic_data = array![core::byte_array::BYTE_ARRAY_MAGIC];
+            let message: ByteArray = "Amount too large";
+            message.serialize(ref panic_data);
+            panic_data.append('Limit');
+            panic_data.append(1000);
+
+            match safe_dispatcher.do_a_panic_with(panic_data) {
+                Result::Ok(_) => panic_with_felt252('shouldve panicked'),
+                Result::Err(panic_data) => {
+                    assert(
+                        decode_panic_data(@panic_data) == array![
+                            PanicDataItem::String("Amount too large"),
+                            PanicDataItem::ShortString('Limit'),
+                            PanicDataItem::Felt(1000),
+                        ],
+                        'wrong panic data'
+                    );
+                }
+            };
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
 #[test]
 fn serding() {
     let test = test_case!(
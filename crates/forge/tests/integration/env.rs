@@ -69,6 +69,29 @@ fn read_overflow_felt252() {
     assert_passed(&result);
 }
 
+#[test]
+fn read_byte_array() {
+    let mut test = test_case!(indoc!(
+        r#"
+        use snforge_std::env::var_byte_array;
+
+        #[test]
+        fn read_byte_array() {
+            let result = var_byte_array("MY_ENV_VAR");
+            assert(result == "longer than thirty one characters env var value", 'failed reading env var');
+        }
+    "#
+    ));
+    test.set_env(
+        "MY_ENV_VAR",
+        r#""longer than thirty one characters env var value""#,
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
 #[test]
 fn read_invalid_short_string() {
     let mut test = test_case!(indoc!(
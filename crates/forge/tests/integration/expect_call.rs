@@ -0,0 +1,113 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn expect_call_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_call, expect_call_with_calldata };
+
+        #[starknet::interface]
+        trait IMockChecker<TContractState> {
+            fn get_thing(ref self: TContractState) -> felt252;
+            fn get_thing_wrapper(ref self: TContractState) -> felt252;
+            fn get_thing_plus(ref self: TContractState, amount: felt252) -> felt252;
+        }
+
+        #[test]
+        fn expect_call_any_calldata() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            expect_call(contract_address, selector!("get_thing"));
+            expect_call(contract_address, selector!("get_thing_plus"));
+
+            dispatcher.get_thing();
+            dispatcher.get_thing_plus(5);
+        }
+
+        #[test]
+        fn expect_call_with_matching_calldata() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            expect_call_with_calldata(contract_address, selector!("get_thing_plus"), array![5]);
+
+            dispatcher.get_thing_plus(4);
+            dispatcher.get_thing_plus(5);
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_call_not_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_call, expect_call_with_calldata };
+
+        #[starknet::interface]
+        trait IMockChecker<TContractState> {
+            fn get_thing(ref self: TContractState) -> felt252;
+            fn get_thing_plus(ref self: TContractState, amount: felt252) -> felt252;
+        }
+
+        #[test]
+        fn expect_call_never_made() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            expect_call(contract_address, selector!("get_thing_plus"));
+
+            dispatcher.get_thing();
+        }
+
+        #[test]
+        fn expect_call_with_other_calldata() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            expect_call_with_calldata(contract_address, selector!("get_thing_plus"), array![5]);
+
+            dispatcher.get_thing_plus(4);
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "expect_call_never_made", "was not made");
+    assert_case_output_contains(
+        &result,
+        "expect_call_with_other_calldata",
+        "with calldata [0x",
+    );
+}
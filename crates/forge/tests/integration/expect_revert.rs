@@ -0,0 +1,234 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn expect_revert_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_revert };
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn increase_balance(ref self: TContractState, amount: felt252);
+            fn get_balance(self: @TContractState) -> felt252;
+            fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn expect_revert_with_panic_data() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IHelloStarknetSafeDispatcher { contract_address };
+
+            expect_revert(array!['error', 'data']);
+            match safe_dispatcher.do_a_panic_with(array!['error', 'data']) {
+                Result::Ok(_) => panic_with_felt252('shouldve panicked'),
+                Result::Err(panic_data) => {
+                    assert(panic_data == array!['error', 'data'], 'Wrong panic_data');
+                }
+            };
+
+            // Only the next call is affected
+            safe_dispatcher.increase_balance(5).unwrap();
+            assert(safe_dispatcher.get_balance().unwrap() == 5, 'Wrong balance');
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_revert_met_continues_test() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_revert };
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn code_after_expected_revert_runs() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IHelloStarknetSafeDispatcher { contract_address };
+
+            expect_revert(array!['error']);
+            let _ = safe_dispatcher.do_a_panic_with(array!['error']);
+
+            panic_with_felt252('Reached');
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "code_after_expected_revert_runs", "Reached");
+}
+
+#[test]
+fn expect_revert_not_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_revert };
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn increase_balance(ref self: TContractState, amount: felt252);
+            fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+        }
+
+        #[test]
+        fn call_does_not_revert() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+            expect_revert(array!['error']);
+            dispatcher.increase_balance(5);
+        }
+
+        #[test]
+        fn call_reverts_with_other_data() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+            expect_revert(array!['error']);
+            dispatcher.do_a_panic_with(array!['other error']);
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn safe_call_does_not_revert() {
+            let contract = declare("HelloStarknet");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IHelloStarknetSafeDispatcher { contract_address };
+
+            expect_revert(array!['error']);
+            let result = safe_dispatcher.increase_balance(5);
+
+            assert(result.is_ok(), 'Call reverted');
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "call_does_not_revert",
+        "Expected revert did not occur",
+    );
+    assert_case_output_contains(&result, "call_reverts_with_other_data", "other error");
+    assert_case_output_contains(
+        &result,
+        "safe_call_does_not_revert",
+        "Expected revert did not occur",
+    );
+}
+
+#[test]
+fn expect_revert_met_in_function_returning_value() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, expect_revert };
+
+        #[starknet::interface]
+        trait IVault<TContractState> {
+            fn withdraw(ref self: TContractState, amount: u128) -> u128;
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn expect_revert_with_return_value() {
+            let contract = declare("Vault");
+            let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+            let safe_dispatcher = IVaultSafeDispatcher { contract_address };
+
+            expect_revert(array!['Insufficient balance']);
+            match safe_dispatcher.withdraw(1000) {
+                Result::Ok(_) => panic_with_felt252('shouldve panicked'),
+                Result::Err(panic_data) => {
+                    assert(*panic_data.at(0) == 'Insufficient balance', *panic_data.at(0));
+                }
+            };
+
+            assert(safe_dispatcher.withdraw(0).unwrap() == 0, 'Wrong remaining balance');
+        }
+    "#
+        ),
+        Contract::new(
+            "Vault",
+            indoc!(
+                r"
+                #[starknet::interface]
+                trait IVault<TContractState> {
+                    fn withdraw(ref self: TContractState, amount: u128) -> u128;
+                }
+
+                #[starknet::contract]
+                mod Vault {
+                    #[storage]
+                    struct Storage {
+                        balance: u128,
+                    }
+
+                    #[abi(embed_v0)]
+                    impl VaultImpl of super::IVault<ContractState> {
+                        // Withdraws the given amount and returns the remaining balance.
+                        fn withdraw(ref self: ContractState, amount: u128) -> u128 {
+                            let balance = self.balance.read();
+                            assert(balance >= amount, 'Insufficient balance');
+                            let remaining = balance - amount;
+                            self.balance.write(remaining);
+                            remaining
+                        }
+                    }
+                }
+                "
+            )
+        )
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
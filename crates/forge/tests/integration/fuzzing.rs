@@ -1,6 +1,6 @@
 use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
 use indoc::indoc;
-use test_utils::runner::{assert_passed, TestCase};
+use test_utils::runner::assert_passed;
 use test_utils::running_tests::run_test_case;
 use test_utils::test_case;
 
@@ -69,10 +69,7 @@ fn fuzzed_loop() {
 
     let result = run_test_case(&test);
 
-    let crate_summary = TestCase::find_test_result(&result);
-    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) =
-        &crate_summary.test_case_summaries[0]
-    else {
+    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) = &result[0] else {
         panic!()
     };
 
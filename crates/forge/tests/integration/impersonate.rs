@@ -0,0 +1,92 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+
+#[test]
+fn impersonate() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{ declare, ContractClassTrait, start_impersonate, stop_impersonate };
+
+            #[derive(Drop, Serde)]
+            struct Call {
+                to: ContractAddress,
+                selector: felt252,
+                calldata: Array<felt252>,
+            }
+
+            #[starknet::interface]
+            trait ISimpleAccount<TContractState> {
+                fn __validate__(self: @TContractState, calls: Array<Call>) -> felt252;
+                fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+                fn is_valid_signature(
+                    self: @TContractState, hash: felt252, signature: Array<felt252>
+                ) -> felt252;
+            }
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn get_balance(ref self: TContractState) -> felt252;
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            fn deploy_account() -> ISimpleAccountDispatcher {
+                let contract_address = declare("SimpleAccount").deploy(@array![123]).unwrap();
+                ISimpleAccountDispatcher { contract_address }
+            }
+
+            fn increase_balance_calls(contract_address: ContractAddress) -> Array<Call> {
+                array![
+                    Call {
+                        to: contract_address,
+                        selector: selector!("increase_balance"),
+                        calldata: array![5],
+                    }
+                ]
+            }
+
+            #[test]
+            fn execute_with_impersonated_account() {
+                let account = deploy_account();
+                let hello_address = declare("HelloStarknet").deploy(@array![]).unwrap();
+
+                start_impersonate(account.contract_address);
+                assert(account.__validate__(increase_balance_calls(hello_address)) == 'VALID', 'not validated');
+                account.__execute__(increase_balance_calls(hello_address));
+
+                let hello = IHelloStarknetDispatcher { contract_address: hello_address };
+                assert(hello.get_balance() == 5, 'call not executed');
+            }
+
+            #[test]
+            fn signature_validation() {
+                let account = deploy_account();
+                assert(account.is_valid_signature(1, array![]) == 0, 'invalid signature accepted');
+
+                start_impersonate(account.contract_address);
+                assert(account.is_valid_signature(1, array![]) == 'VALID', 'signature validated');
+
+                stop_impersonate(account.contract_address);
+                assert(account.is_valid_signature(1, array![]) == 0, 'invalid signature accepted');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
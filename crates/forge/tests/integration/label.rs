@@ -0,0 +1,211 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn label_and_get_label() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use snforge_std::{ declare, ContractClassTrait, label, get_label };
+
+            #[test]
+            fn label_and_get_label() {
+                let contract = declare("HelloStarknet");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let other_address = contract.deploy(@ArrayTrait::new()).unwrap();
+
+                label(contract_address, "MyToken");
+
+                assert(get_label(contract_address) == Option::Some("MyToken"), 'Wrong label');
+                assert(get_label(other_address) == Option::None, 'Unexpected label');
+
+                label(contract_address, "MyOtherToken");
+                assert(get_label(contract_address) == Option::Some("MyOtherToken"), 'Label not replaced');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn label_in_event_assertion() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::ContractAddress;
+            use snforge_std::{ declare, ContractClassTrait, spy_events, EventSpy,
+                EventAssertions, SpyOn, label };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn do_not_emit(ref self: TContractState);
+            }
+
+            #[starknet::contract]
+            mod SpyEventsChecker {
+                use starknet::ContractAddress;
+
+                #[storage]
+                struct Storage {}
+
+                #[event]
+                #[derive(Drop, starknet::Event)]
+                enum Event {
+                    FirstEvent: FirstEvent
+                }
+
+                #[derive(Drop, starknet::Event)]
+                struct FirstEvent {
+                    some_data: felt252
+                }
+            }
+
+            #[test]
+            fn assert_emitted_fails_with_label() {
+                let contract = declare("SpyEventsChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = ISpyEventsCheckerDispatcher { contract_address };
+                label(contract_address, "EventsChecker");
+
+                let mut spy = spy_events(SpyOn::One(contract_address));
+                dispatcher.do_not_emit();
+
+                spy.assert_emitted(@array![
+                    (
+                        contract_address,
+                        SpyEventsChecker::Event::FirstEvent(
+                            SpyEventsChecker::FirstEvent { some_data: 123 }
+                        )
+                    )
+                ]);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "assert_emitted_fails_with_label",
+        "keys was not emitted from",
+    );
+    assert_case_output_contains(&result, "assert_emitted_fails_with_label", "EventsChecker");
+}
+
+#[test]
+fn address_formatting_in_event_assertion() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::ContractAddress;
+            use snforge_std::{ declare, ContractClassTrait, spy_events, EventSpy,
+                EventAssertions, SpyOn, label };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+            }
+
+            #[starknet::contract]
+            mod SpyEventsChecker {
+                use starknet::ContractAddress;
+
+                #[storage]
+                struct Storage {}
+
+                #[event]
+                #[derive(Drop, starknet::Event)]
+                enum Event {
+                    FirstEvent: FirstEvent
+                }
+
+                #[derive(Drop, starknet::Event)]
+                struct FirstEvent {
+                    some_data: felt252
+                }
+            }
+
+            fn emit_and_assert_not_emitted(contract_address: ContractAddress) {
+                let dispatcher = ISpyEventsCheckerDispatcher { contract_address };
+
+                let mut spy = spy_events(SpyOn::One(contract_address));
+                dispatcher.emit_one_event(123);
+
+                spy.assert_not_emitted(@array![
+                    (
+                        contract_address,
+                        SpyEventsChecker::Event::FirstEvent(
+                            SpyEventsChecker::FirstEvent { some_data: 123 }
+                        )
+                    )
+                ]);
+            }
+
+            #[test]
+            fn with_label() {
+                let contract = declare("SpyEventsChecker");
+                let contract_address = contract
+                    .deploy_at(@ArrayTrait::new(), 123.try_into().unwrap())
+                    .unwrap();
+                label(contract_address, "EventsChecker");
+
+                emit_and_assert_not_emitted(contract_address);
+            }
+
+            #[test]
+            fn without_label() {
+                let contract = declare("SpyEventsChecker");
+                let contract_address = contract
+                    .deploy_at(@ArrayTrait::new(), 0xabc.try_into().unwrap())
+                    .unwrap();
+
+                emit_and_assert_not_emitted(contract_address);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "with_label",
+        "Event with matching data and keys was emitted from EventsChecker (0x7b)",
+    );
+    assert_case_output_contains(
+        &result,
+        "without_label",
+        "Event with matching data and keys was emitted from 0xabc",
+    );
+}
@@ -0,0 +1,156 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn measure_calls() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::ContractAddress;
+            use snforge_std::{ declare, ContractClassTrait, start_measure, stop_measure, Measurement };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            fn syscall_count(measurement: @Measurement, name: felt252) -> usize {
+                let mut syscalls = measurement.syscalls.span();
+                loop {
+                    match syscalls.pop_front() {
+                        Option::Some(entry) => {
+                            let (syscall, count) = *entry;
+                            if syscall == name {
+                                break count;
+                            }
+                        },
+                        Option::None => { break 0; }
+                    };
+                }
+            }
+
+            #[test]
+            fn measure_calls() {
+                let contract = declare("HelloStarknet");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                start_measure();
+                dispatcher.increase_balance(5);
+                let single_call = stop_measure();
+
+                assert(single_call.steps > 0, 'No steps measured');
+                assert(syscall_count(@single_call, 'StorageWrite') == 1, 'Wrong syscalls');
+
+                start_measure();
+                dispatcher.increase_balance(5);
+                dispatcher.increase_balance(5);
+                let two_calls = stop_measure();
+
+                assert(two_calls.steps > single_call.steps, 'Wrong steps');
+                assert(syscall_count(@two_calls, 'StorageWrite') == 2, 'Wrong syscalls');
+
+                start_measure();
+                let empty = stop_measure();
+
+                assert(empty.steps == 0, 'Steps measured');
+                assert(empty.syscalls.len() == 0, 'Syscalls measured');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn syscall_counts() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use snforge_std::{ declare, ContractClassTrait, get_syscall_counts };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            fn syscall_count(name: felt252) -> usize {
+                let syscalls = get_syscall_counts();
+                let mut syscalls = syscalls.span();
+                loop {
+                    match syscalls.pop_front() {
+                        Option::Some(entry) => {
+                            let (syscall, count) = *entry;
+                            if syscall == name {
+                                break count;
+                            }
+                        },
+                        Option::None => { break 0; }
+                    };
+                }
+            }
+
+            #[test]
+            fn syscall_counts() {
+                let contract = declare("HelloStarknet");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                assert(syscall_count('StorageWrite') == 0, 'Unexpected storage write');
+
+                dispatcher.increase_balance(5);
+                assert(syscall_count('StorageWrite') == 1, 'Wrong storage writes');
+                assert(syscall_count('StorageRead') == 1, 'Wrong storage reads');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn stop_measure_without_start() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::stop_measure;
+
+            #[test]
+            fn stop_measure_without_start() {
+                stop_measure();
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "stop_measure_without_start",
+        "`stop_measure` called without `start_measure`",
+    );
+}
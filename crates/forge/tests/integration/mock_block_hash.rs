@@ -0,0 +1,117 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn mock_block_hash() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use box::BoxTrait;
+            use starknet::{get_block_info, get_block_hash_syscall, SyscallResultTrait};
+            use snforge_std::{
+                declare, ContractClassTrait, CheatTarget, test_address, start_mock_block_hash,
+                stop_mock_block_hash
+            };
+
+            #[starknet::interface]
+            trait IBlockHashChecker<TContractState> {
+                fn write_block(ref self: TContractState);
+                fn read_block_hash(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn mock_block_hash_in_contract() {
+                let contract = declare("BlockHashChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let other_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IBlockHashCheckerDispatcher { contract_address };
+                let other_dispatcher = IBlockHashCheckerDispatcher { contract_address: other_address };
+                let block_number = get_block_info().unbox().block_number - 10;
+
+                start_mock_block_hash(CheatTarget::One(contract_address), block_number, 123);
+
+                dispatcher.write_block();
+                assert(dispatcher.read_block_hash() == 123, 'Wrong block hash');
+                other_dispatcher.write_block();
+                assert(other_dispatcher.read_block_hash() == 0, 'Other contract affected');
+
+                stop_mock_block_hash(CheatTarget::One(contract_address), block_number);
+
+                dispatcher.write_block();
+                assert(dispatcher.read_block_hash() == 0, 'Block hash not restored');
+            }
+
+            #[test]
+            fn mock_block_hash_all() {
+                let contract = declare("BlockHashChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IBlockHashCheckerDispatcher { contract_address };
+                let block_number = get_block_info().unbox().block_number - 10;
+
+                start_mock_block_hash(CheatTarget::All, block_number, 456);
+
+                dispatcher.write_block();
+                assert(dispatcher.read_block_hash() == 456, 'Wrong block hash');
+                let block_hash = get_block_hash_syscall(block_number).unwrap_syscall();
+                assert(block_hash == 456, 'Wrong block hash in test');
+
+                stop_mock_block_hash(CheatTarget::All, block_number);
+
+                let block_hash = get_block_hash_syscall(block_number).unwrap_syscall();
+                assert(block_hash == 0, 'Block hash not restored');
+            }
+
+            #[test]
+            fn mock_block_hash_in_test() {
+                let block_number = get_block_info().unbox().block_number - 10;
+
+                start_mock_block_hash(CheatTarget::One(test_address()), block_number, 789);
+
+                let block_hash = get_block_hash_syscall(block_number).unwrap_syscall();
+                assert(block_hash == 789, 'Wrong block hash');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "BlockHashChecker".to_string(),
+            Path::new("tests/data/contracts/block_hash_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_block_hash_block_number_out_of_range() {
+    let test = test_case!(indoc!(
+        r"
+            use starknet::testing::cheatcode;
+            use array::ArrayTrait;
+
+            #[test]
+            fn mock_block_hash_block_number_out_of_range() {
+                // CheatTarget::All, block number equal to 2^64, block hash
+                cheatcode::<'start_mock_block_hash'>(
+                    array![0, 18446744073709551616, 123].span()
+                );
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "mock_block_hash_block_number_out_of_range",
+        "Block number = 18446744073709551616 does not fit in u64",
+    );
+}
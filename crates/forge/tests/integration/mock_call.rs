@@ -207,3 +207,74 @@ fn mock_calls() {
     let result = run_test_case(&test);
     assert_passed(&result);
 }
+
+#[test]
+fn mock_calls_when() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use result::ResultTrait;
+        use snforge_std::{ declare, ContractClassTrait, start_mock_call, mock_call_when,
+            start_mock_call_when, stop_mock_call_when };
+
+        #[starknet::interface]
+        trait IMockChecker<TContractState> {
+            fn get_thing_plus(ref self: TContractState, amount: felt252) -> felt252;
+        }
+
+        #[test]
+        fn mock_call_when_matching_calldata() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            start_mock_call_when(contract_address, selector!("get_thing_plus"), array![1], 999);
+
+            assert_eq!(dispatcher.get_thing_plus(1), 999);
+            assert_eq!(dispatcher.get_thing_plus(2), 422);
+
+            stop_mock_call_when(contract_address, selector!("get_thing_plus"), array![1]);
+            assert_eq!(dispatcher.get_thing_plus(1), 421);
+        }
+
+        #[test]
+        fn mock_call_when_n_times() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            mock_call_when(contract_address, selector!("get_thing_plus"), array![1], 999, 2);
+
+            assert_eq!(dispatcher.get_thing_plus(1), 999);
+            assert_eq!(dispatcher.get_thing_plus(1), 999);
+            assert_eq!(dispatcher.get_thing_plus(1), 421);
+        }
+
+        #[test]
+        fn mock_call_when_takes_precedence() {
+            let contract = declare("MockChecker");
+            let contract_address = contract.deploy(@array![420]).unwrap();
+
+            let dispatcher = IMockCheckerDispatcher { contract_address };
+
+            start_mock_call(contract_address, selector!("get_thing_plus"), 0);
+            mock_call_when(contract_address, selector!("get_thing_plus"), array![1], 999, 1);
+
+            assert_eq!(dispatcher.get_thing_plus(1), 999);
+            assert_eq!(dispatcher.get_thing_plus(1), 0);
+            assert_eq!(dispatcher.get_thing_plus(2), 0);
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+    assert_passed(&result);
+}
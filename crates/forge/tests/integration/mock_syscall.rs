@@ -0,0 +1,167 @@
+use indoc::indoc;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn mock_syscall() {
+    let test = test_case!(indoc!(
+        r#"
+            use starknet::{SyscallResultTrait, storage_base_address_from_felt252, storage_address_from_base};
+            use starknet::syscalls::{keccak_syscall, storage_read_syscall};
+            use snforge_std::{
+                CheatTarget, test_address, MockableSyscall, start_mock_syscall, stop_mock_syscall
+            };
+
+            #[test]
+            fn mock_keccak() {
+                start_mock_syscall(
+                    CheatTarget::One(test_address()), MockableSyscall::Keccak, Result::Ok(array![1, 2])
+                );
+
+                let hash = keccak_syscall(array![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17].span())
+                    .unwrap_syscall();
+                assert(hash == u256 { low: 1, high: 2 }, 'Wrong hash');
+
+                stop_mock_syscall(CheatTarget::One(test_address()), MockableSyscall::Keccak);
+
+                let hash = keccak_syscall(array![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17].span())
+                    .unwrap_syscall();
+                assert(hash != u256 { low: 1, high: 2 }, 'Keccak not restored');
+            }
+
+            #[test]
+            fn mock_storage_read_failure() {
+                start_mock_syscall(
+                    CheatTarget::All, MockableSyscall::StorageRead, Result::Err(array!['read failed'])
+                );
+
+                let address = storage_address_from_base(storage_base_address_from_felt252(1));
+                match storage_read_syscall(0, address) {
+                    Result::Ok(_) => panic_with_felt252('Should have failed'),
+                    Result::Err(error_data) => {
+                        assert(error_data == array!['read failed'], 'Wrong error data');
+                    }
+                };
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_syscall_wrong_response_length() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::{CheatTarget, MockableSyscall, start_mock_syscall};
+
+            #[test]
+            fn mock_syscall_wrong_response_length() {
+                start_mock_syscall(
+                    CheatTarget::All, MockableSyscall::Keccak, Result::Ok(array![1])
+                );
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "mock_syscall_wrong_response_length",
+        "Response of Keccak syscall has to consist of 2 felt(s), got 1",
+    );
+}
+
+#[test]
+fn mock_execution_info_and_block_hash() {
+    let test = test_case!(indoc!(
+        r#"
+            use starknet::{SyscallResultTrait, get_execution_info};
+            use starknet::syscalls::get_block_hash_syscall;
+            use snforge_std::{
+                CheatTarget, test_address, MockableSyscall, start_mock_syscall, start_mock_block_hash
+            };
+
+            #[test]
+            fn mock_execution_info() {
+                let response = array![
+                    // BlockInfo
+                    123, 456, 789,
+                    // TxInfo
+                    1, 0x111, 1000, 2, 'r', 's', 0x222, 'SN_TEST', 5,
+                    1, 'L1_GAS', 10, 20, 0, 0, 0, 0, 0,
+                    // caller address, contract address, entry point selector
+                    0x333, 0x444, 'selector'
+                ];
+                start_mock_syscall(
+                    CheatTarget::One(test_address()), MockableSyscall::GetExecutionInfo, Result::Ok(response)
+                );
+
+                let execution_info = get_execution_info().unbox();
+                let block_info = execution_info.block_info.unbox();
+                assert(block_info.block_number == 123, 'Wrong block number');
+                assert(block_info.block_timestamp == 456, 'Wrong block timestamp');
+
+                let tx_info = execution_info.tx_info.unbox();
+                assert(tx_info.signature == array!['r', 's'].span(), 'Wrong signature');
+                assert(tx_info.chain_id == 'SN_TEST', 'Wrong chain id');
+                assert(tx_info.nonce == 5, 'Wrong nonce');
+                assert(tx_info.resource_bounds.len() == 1, 'Wrong resource bounds');
+                assert(*tx_info.resource_bounds.at(0).max_amount == 10, 'Wrong max amount');
+                assert(tx_info.paymaster_data.len() == 0, 'Wrong paymaster data');
+
+                let caller_address: felt252 = execution_info.caller_address.into();
+                assert(caller_address == 0x333, 'Wrong caller address');
+                assert(execution_info.entry_point_selector == 'selector', 'Wrong selector');
+            }
+
+            #[test]
+            fn mock_block_hash_takes_precedence() {
+                start_mock_syscall(
+                    CheatTarget::All, MockableSyscall::GetBlockHash, Result::Ok(array![0x123])
+                );
+                start_mock_block_hash(CheatTarget::All, 100, 0x456);
+
+                let block_hash = get_block_hash_syscall(100).unwrap_syscall();
+                assert(block_hash == 0x456, 'Wrong mocked block hash');
+
+                let block_hash = get_block_hash_syscall(101).unwrap_syscall();
+                assert(block_hash == 0x123, 'Wrong mocked syscall');
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_execution_info_wrong_response() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::{CheatTarget, MockableSyscall, start_mock_syscall};
+
+            #[test]
+            fn mock_execution_info_wrong_response() {
+                start_mock_syscall(
+                    CheatTarget::All, MockableSyscall::GetExecutionInfo, Result::Ok(array![1, 2, 3])
+                );
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "mock_execution_info_wrong_response",
+        "Response of GetExecutionInfo syscall has to be a serialized ExecutionInfo",
+    );
+}
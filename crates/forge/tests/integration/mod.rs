@@ -1,5 +1,6 @@
 mod available_gas;
 mod cheat_fork;
+mod deal;
 mod declare;
 mod deploy;
 mod deploy_at;
@@ -7,23 +8,33 @@ mod dict;
 mod dispatchers;
 mod elect;
 mod env;
+mod expect_call;
+mod expect_revert;
 mod fuzzing;
 mod gas;
 mod get_class_hash;
+mod impersonate;
 mod l1_handler_executor;
+mod label;
+mod measure;
+mod mock_block_hash;
 mod mock_call;
+mod mock_syscall;
 mod prank;
 mod precalculate_address;
 mod pure_cairo;
+mod random;
 mod replace_bytecode;
 mod resources;
 mod roll;
 mod runtime;
+mod set_contract_nonce;
 mod setup_fork;
 mod should_panic;
 mod signing;
 mod spoof;
 mod spy_events;
+mod spy_messages_to_l1;
 mod store_load;
 mod syscalls;
 mod test_state;
@@ -1,7 +1,7 @@
 use indoc::indoc;
 use test_utils::running_tests::run_test_case;
 use test_utils::{
-    runner::{assert_failed, assert_passed},
+    runner::{assert_case_output_contains, assert_failed, assert_passed},
     test_case,
 };
 
@@ -34,3 +34,25 @@ fn failing() {
 
     assert_failed(&result);
 }
+
+#[test]
+fn failing_assert_eq_on_arrays_prints_diff() {
+    let test = test_case!(indoc!(
+        r"#[test]
+        fn failing_assert_eq_on_arrays() {
+            let actual = array![1, 'hello', 3];
+            let expected = array![1, 2];
+            assert_eq!(actual, expected);
+        }
+    "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "failing_assert_eq_on_arrays",
+        "✗ [1]  448378203247 ('hello')  2\n",
+    );
+}
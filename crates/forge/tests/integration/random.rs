@@ -0,0 +1,76 @@
+use indoc::indoc;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn rand_felt_simple() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::rand_felt;
+
+            #[test]
+            fn rand_felt_simple() {
+                let first = rand_felt();
+                let second = rand_felt();
+
+                assert(first != second, 'Values should differ');
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn rand_in_range_simple() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::rand_in_range;
+
+            #[test]
+            fn rand_in_range_simple() {
+                let mut i = 0;
+                loop {
+                    if i == 20 {
+                        break;
+                    }
+                    let value: u256 = rand_in_range(10, 15).into();
+                    assert(value >= 10 && value <= 15, 'Value out of range');
+                    i += 1;
+                };
+
+                assert(rand_in_range(7, 7) == 7, 'Wrong value');
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn rand_in_range_invalid_bounds() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::rand_in_range;
+
+            #[test]
+            fn rand_in_range_invalid_bounds() {
+                rand_in_range(2, 1);
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "rand_in_range_invalid_bounds",
+        "`rand_in_range` called with low = 2 greater than high = 1",
+    );
+}
@@ -48,6 +48,50 @@ fn override_entrypoint() {
     assert_passed(&result);
 }
 
+#[test]
+fn reset_bytecode() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, replace_bytecode, reset_bytecode, ContractClassTrait};
+
+            #[starknet::interface]
+            trait IReplaceBytecode<TContractState> {
+                fn get(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn reset_bytecode() {
+                let contract = declare("ReplaceBytecodeA");
+                let contract_b_class = declare("ReplaceBytecodeB").class_hash;
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IReplaceBytecodeDispatcher { contract_address };
+
+                replace_bytecode(contract_address, contract_b_class);
+                assert(dispatcher.get() == 420, '');
+
+                reset_bytecode(contract_address);
+                assert(dispatcher.get() == 2137, '');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "ReplaceBytecodeB",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
 #[test]
 fn libcall_in_cheated() {
     let test = test_case!(
@@ -1,7 +1,7 @@
 use indoc::indoc;
 use test_utils::running_tests::run_test_case;
 use test_utils::{
-    runner::{assert_case_output_contains, assert_failed},
+    runner::{assert_case_output_contains, assert_failed, assert_passed},
     test_case,
 };
 
@@ -87,3 +87,47 @@ fn cheatcode_invalid_args() {
     );
     assert_failed(&result);
 }
+
+#[test]
+fn snforge_version() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::version::{cheatcode_version, is_snforge_at_least, require_snforge_version};
+
+            #[test]
+            fn snforge_version() {
+                let version = cheatcode_version();
+                assert(is_snforge_at_least(version.major, version.minor, version.patch), 'same version');
+                assert(is_snforge_at_least(0, 0, 1), 'older version');
+                assert(!is_snforge_at_least(version.major + 1, 0, 0), 'newer version');
+                require_snforge_version(0, 0, 1);
+            }
+        "
+    ));
+    let result = run_test_case(&test);
+    assert_passed(&result);
+}
+
+#[test]
+fn snforge_version_too_old() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::version::require_snforge_version;
+
+            #[test]
+            fn snforge_version_too_old() {
+                require_snforge_version(999, 0, 0);
+            }
+        "
+    ));
+    let result = run_test_case(&test);
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "snforge_version_too_old",
+        &format!(
+            "snforge 999.0.0 or newer is required, tests are run with snforge {}",
+            env!("CARGO_PKG_VERSION")
+        ),
+    );
+}
@@ -0,0 +1,48 @@
+use indoc::indoc;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn set_contract_nonce_simple() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::{ set_contract_nonce, test_address };
+
+            #[test]
+            fn set_contract_nonce_simple() {
+                set_contract_nonce(test_address(), 10);
+                set_contract_nonce(test_address(), 10);
+                set_contract_nonce(test_address(), 12);
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn set_contract_nonce_lower() {
+    let test = test_case!(indoc!(
+        r#"
+            use snforge_std::{ set_contract_nonce, test_address };
+
+            #[test]
+            fn set_contract_nonce_lower() {
+                set_contract_nonce(test_address(), 10);
+                set_contract_nonce(test_address(), 9);
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "set_contract_nonce_lower",
+        "can't be decreased from 10 to 9",
+    );
+}
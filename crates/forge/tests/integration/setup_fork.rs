@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 use camino::Utf8PathBuf;
 use forge::block_number_map::BlockNumberMap;
+use forge::gas_budget::GasBudgets;
 use forge::run;
 use forge::scarb::config::ForkTarget;
 use forge::test_filter::TestsFilter;
@@ -112,36 +113,41 @@ fn fork_aliased_decorator() {
         .output_checked()
         .unwrap();
 
-    let result = rt
-        .block_on(run(
-            &String::from("test_package"),
-            &test.path().unwrap().join("target/dev/snforge"),
-            &TestsFilter::from_flags(None, false, false, false, false, Default::default()),
-            Arc::new(RunnerConfig::new(
-                Utf8PathBuf::from_path_buf(PathBuf::from(tempdir().unwrap().path())).unwrap(),
-                false,
-                256.try_into().unwrap(),
-                12345,
-                false,
-                false,
-                false,
-                None,
-            )),
-            Arc::new(RunnerParams::new(
-                ContractsData::try_from(test.contracts().unwrap()).unwrap(),
-                test.env().clone(),
-            )),
-            &[ForkTarget::new(
-                "FORK_NAME_FROM_SCARB_TOML".to_string(),
-                RawForkParams {
-                    url: TESTNET_RPC_URL.to_string(),
-                    block_id_type: "Tag".to_string(),
-                    block_id_value: "Latest".to_string(),
-                },
-            )],
-            &mut BlockNumberMap::default(),
-        ))
-        .expect("Runner fail");
+    let mut result = vec![];
+    rt.block_on(run(
+        &String::from("test_package"),
+        &test.path().unwrap().join("target/dev/snforge"),
+        &TestsFilter::from_flags(None, false, false, false, false, Default::default()),
+        Arc::new(RunnerConfig::new(
+            Utf8PathBuf::from_path_buf(PathBuf::from(tempdir().unwrap().path())).unwrap(),
+            false,
+            256.try_into().unwrap(),
+            12345,
+            false,
+            false,
+            false,
+            None,
+            None,
+            Default::default(),
+        )),
+        Arc::new(RunnerParams::new(
+            ContractsData::try_from(test.contracts().unwrap()).unwrap(),
+            test.env().clone(),
+        )),
+        &[ForkTarget::new(
+            "FORK_NAME_FROM_SCARB_TOML".to_string(),
+            RawForkParams {
+                url: TESTNET_RPC_URL.to_string(),
+                block_id_type: "Tag".to_string(),
+                block_id_value: "Latest".to_string(),
+            },
+        )],
+        None,
+        &GasBudgets::default(),
+        &mut BlockNumberMap::default(),
+        &mut |summary, _| result.push(summary.clone()),
+    ))
+    .expect("Runner fail");
 
     assert_passed(&result);
 }
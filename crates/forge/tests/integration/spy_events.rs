@@ -418,12 +418,12 @@ fn event_emitted_wrong_data_asserted() {
     assert_case_output_contains(
         &result,
         "event_emitted_wrong_data_asserted",
-        "Event with matching data and",
+        "Event with matching data and keys was not emitted from",
     );
     assert_case_output_contains(
         &result,
         "event_emitted_wrong_data_asserted",
-        "keys was not emitted from",
+        "Emitted event with the same selector differs at data[0]: expected 0x7c, emitted 0x7b",
     );
 }
 
@@ -714,3 +714,58 @@ fn capture_cairo0_event() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn spy_events_with_selectors() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::{ContractAddress, contract_address_const};
+            use snforge_std::{ declare, ContractClassTrait, spy_events_with_selectors, EventSpy,
+                EventFetcher, SpyOn };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_two_events(ref self: TContractState, some_data: felt252, some_more_data: ContractAddress);
+            }
+
+            #[test]
+            fn spy_events_with_selectors() {
+                let contract = declare("SpyEventsChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = ISpyEventsCheckerDispatcher { contract_address };
+
+                let mut spy = spy_events_with_selectors(
+                    SpyOn::One(contract_address), array![selector!("SecondEvent")]
+                );
+                let mut other_spy = spy_events_with_selectors(
+                    SpyOn::One(contract_address_const::<123>()), array![selector!("SecondEvent")]
+                );
+
+                dispatcher.emit_two_events(123, contract_address_const::<234>());
+
+                spy.fetch_events();
+                other_spy.fetch_events();
+
+                assert(spy.events.len() == 1, 'There should be one event');
+                assert(other_spy.events.len() == 0, 'There should be no events');
+
+                let (from, event) = spy.events.at(0);
+                assert(from == @contract_address, 'Emitted from wrong address');
+                assert(*event.keys[0] == selector!("SecondEvent"), 'Wrong event selector');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,118 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn spy_messages_to_l1_simple() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::{ContractAddress, EthAddress};
+            use snforge_std::{ declare, ContractClassTrait, spy_messages_to_l1, MessageToL1,
+                MessageToL1Spy, MessageToL1SpyTrait, MessageToL1SpyAssertions };
+
+            #[starknet::interface]
+            trait IGasChecker<TContractState> {
+                fn send_l1_message(self: @TContractState);
+            }
+
+            #[test]
+            fn assert_sent() {
+                let contract = declare("GasChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IGasCheckerDispatcher { contract_address };
+
+                let mut spy = spy_messages_to_l1();
+                dispatcher.send_l1_message();
+
+                spy.assert_sent(@array![
+                    (
+                        contract_address,
+                        MessageToL1 { to_address: 1.try_into().unwrap(), payload: array![1, 2, 3] }
+                    )
+                ]);
+                assert(spy.messages.len() == 0, 'Message should be removed');
+            }
+
+            #[test]
+            fn fetch_messages() {
+                let contract = declare("GasChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IGasCheckerDispatcher { contract_address };
+
+                dispatcher.send_l1_message();
+                let mut spy = spy_messages_to_l1();
+                dispatcher.send_l1_message();
+                dispatcher.send_l1_message();
+
+                spy.fetch_messages();
+                assert(spy.messages.len() == 2, 'There should be two messages');
+
+                let (from, message) = spy.messages.at(0);
+                assert(from == @contract_address, 'Sent from wrong address');
+                assert(message.payload == @array![1, 2, 3], 'Wrong payload');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "GasChecker".to_string(),
+            Path::new("tests/data/contracts/gas_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn assert_sent_fails() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use result::ResultTrait;
+            use starknet::{ContractAddress, EthAddress};
+            use snforge_std::{ declare, ContractClassTrait, spy_messages_to_l1, MessageToL1,
+                MessageToL1Spy, MessageToL1SpyAssertions };
+
+            #[starknet::interface]
+            trait IGasChecker<TContractState> {
+                fn send_l1_message(self: @TContractState);
+            }
+
+            #[test]
+            fn assert_sent_fails() {
+                let contract = declare("GasChecker");
+                let contract_address = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IGasCheckerDispatcher { contract_address };
+
+                let mut spy = spy_messages_to_l1();
+                dispatcher.send_l1_message();
+
+                spy.assert_sent(@array![
+                    (
+                        contract_address,
+                        MessageToL1 { to_address: 1.try_into().unwrap(), payload: array![1, 2] }
+                    )
+                ]);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "GasChecker".to_string(),
+            Path::new("tests/data/contracts/gas_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "assert_sent_fails", "receiver was not sent from");
+}
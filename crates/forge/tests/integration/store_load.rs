@@ -54,6 +54,53 @@ fn store_load_simple() {
     assert_passed(&result);
 }
 
+#[test]
+fn store_reset() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{ declare, ContractClassTrait, store, reset_store };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn get_balance(ref self: TContractState) -> felt252;
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            #[test]
+            fn store_reset() {
+                let contract = declare("HelloStarknet");
+                let contract_address = contract.deploy(@array![]).unwrap();
+                let deployed = IHelloStarknetDispatcher { contract_address };
+                deployed.increase_balance(5);
+
+                store(contract_address, selector!("balance"), array![420].span());
+                store(contract_address, selector!("balance"), array![421].span());
+                assert(deployed.get_balance() == 421, 'wrong balance stored');
+
+                reset_store(contract_address, selector!("balance"), 1);
+                assert(deployed.get_balance() == 5, 'balance not reset');
+
+                // Resetting a slot which is not overridden has no effect
+                deployed.increase_balance(1);
+                reset_store(contract_address, selector!("balance"), 1);
+                assert(deployed.get_balance() == 6, 'balance reset twice');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
 #[test]
 fn store_load_wrong_selector() {
     let test = test_utils::test_case!(
@@ -594,3 +641,53 @@ fn fork_store_load() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn storage_accesses() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{ declare, ContractClassTrait, store, get_storage_accesses };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn get_balance(ref self: TContractState) -> felt252;
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            #[test]
+            fn storage_accesses() {
+                let contract = declare("HelloStarknet");
+                let contract_address = contract.deploy(@array![]).unwrap();
+                let deployed = IHelloStarknetDispatcher { contract_address };
+
+                deployed.increase_balance(5);
+                store(contract_address, selector!("balance"), array![7].span());
+                assert(deployed.get_balance() == 7, 'wrong balance');
+
+                let accesses = get_storage_accesses();
+                assert(accesses.len() == 3, 'wrong number of accesses');
+
+                let write = accesses.at(1);
+                assert(*write.contract_address == contract_address, 'wrong address');
+                assert(*write.key == selector!("balance"), 'wrong key');
+                assert(*write.is_write, 'not a write');
+                assert(*write.value == 5, 'wrong written value');
+
+                let read = accesses.at(2);
+                assert(!*read.is_write, 'not a read');
+                assert(*read.value == 7, 'wrong read value');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
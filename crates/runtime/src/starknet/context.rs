@@ -31,6 +31,14 @@ pub const ERC20_CONTRACT_ADDRESS: &str = "0x1001";
 
 #[must_use]
 pub fn build_block_context(block_info: &BlockInfo) -> BlockContext {
+    build_block_context_with_constants(block_info, VersionedConstants::latest_constants())
+}
+
+#[must_use]
+pub fn build_block_context_with_constants(
+    block_info: &BlockInfo,
+    versioned_constants: &VersionedConstants,
+) -> BlockContext {
     BlockContext::new_unchecked(
         block_info,
         &ChainInfo {
@@ -40,10 +48,28 @@ pub fn build_block_context(block_info: &BlockInfo) -> BlockContext {
                 eth_fee_token_address: contract_address!(ERC20_CONTRACT_ADDRESS),
             },
         },
-        VersionedConstants::latest_constants(), // 0.13.1
+        versioned_constants,
     )
 }
 
+/// Latest (0.13.1) [`VersionedConstants`] with execution limits overridden where set
+#[must_use]
+pub fn build_versioned_constants(
+    max_n_steps: Option<u32>,
+    max_recursion_depth: Option<usize>,
+) -> VersionedConstants {
+    let mut versioned_constants = VersionedConstants::latest_constants().clone();
+
+    if let Some(max_n_steps) = max_n_steps {
+        versioned_constants.invoke_tx_max_n_steps = max_n_steps;
+    }
+    if let Some(max_recursion_depth) = max_recursion_depth {
+        versioned_constants.max_recursion_depth = max_recursion_depth;
+    }
+
+    versioned_constants
+}
+
 fn build_tx_info() -> TransactionInfo {
     TransactionInfo::Current(CurrentTransactionInfo {
         common_fields: CommonAccountFields {
@@ -88,7 +114,18 @@ pub fn build_transaction_context(block_info: &BlockInfo) -> TransactionContext {
 
 #[must_use]
 pub fn build_context(block_info: &BlockInfo) -> EntryPointExecutionContext {
-    let transaction_context = Arc::new(build_transaction_context(block_info));
+    build_context_with_constants(block_info, VersionedConstants::latest_constants())
+}
+
+#[must_use]
+pub fn build_context_with_constants(
+    block_info: &BlockInfo,
+    versioned_constants: &VersionedConstants,
+) -> EntryPointExecutionContext {
+    let transaction_context = Arc::new(TransactionContext {
+        block_context: build_block_context_with_constants(block_info, versioned_constants),
+        tx_info: build_tx_info(),
+    });
 
     EntryPointExecutionContext::new(transaction_context, ExecutionMode::Execute, false).unwrap()
 }
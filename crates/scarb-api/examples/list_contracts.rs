@@ -0,0 +1,21 @@
+//! Lists contracts compiled by Scarb for every package of the workspace in the current directory.
+//!
+//! Run `scarb build` first, then `cargo run -p scarb-api --example list_contracts`.
+
+use anyhow::Result;
+use scarb_api::{get_contracts_map, metadata::MetadataCommandExt, ScarbCommand};
+
+fn main() -> Result<()> {
+    let metadata = ScarbCommand::metadata().inherit_stderr().run()?;
+
+    for package in &metadata.workspace.members {
+        let contracts = get_contracts_map(&metadata, package, None)?;
+
+        println!("{package}:");
+        for name in contracts.keys() {
+            println!("    {name}");
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
+use crate::messages::{MessageCollector, ScarbMessage};
 use crate::metadata::MetadataCommand;
 use crate::version::VersionCommand;
 use anyhow::Context;
 use scarb_ui::args::PackagesFilter;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
+use std::io::{BufRead, BufReader, Read};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
 use std::{env, io};
 use thiserror::Error;
 
@@ -231,4 +235,61 @@ impl ScarbCommand {
             Err(ScarbCommandError::ScarbError)
         }
     }
+
+    /// Runs configured `scarb` command with `--json` output, passing every printed message to `on_message`.
+    ///
+    /// Both standard output and standard error of the command are always captured, regardless of
+    /// [`ScarbCommand::inherit_stdout`] and [`ScarbCommand::inherit_stderr`], as diagnostics may be printed to either.
+    pub fn run_with_messages(
+        &self,
+        mut on_message: impl FnMut(ScarbMessage),
+    ) -> Result<(), ScarbCommandError> {
+        let mut cmd = self.clone().json().command();
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let mut child = cmd.spawn()?;
+        let stdout = child.stdout.take().expect("stdout of `scarb` is piped");
+        let stderr = child.stderr.take().expect("stderr of `scarb` is piped");
+
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            let stderr_sender = sender.clone();
+            scope.spawn(move || forward_messages(stdout, &sender));
+            scope.spawn(move || forward_messages(stderr, &stderr_sender));
+
+            for message in receiver {
+                on_message(message?);
+            }
+            Ok::<_, io::Error>(())
+        })?;
+
+        if child.wait()?.success() {
+            Ok(())
+        } else {
+            Err(ScarbCommandError::ScarbError)
+        }
+    }
+}
+
+/// Reads `output` line by line and sends collected messages until it is closed
+fn forward_messages(output: impl Read, sender: &Sender<io::Result<ScarbMessage>>) {
+    let mut collector = MessageCollector::default();
+    for line in BufReader::new(output).lines() {
+        match line {
+            Ok(line) => {
+                for message in collector.push_line(&line) {
+                    // Receiver is only dropped after a failed read, nothing more can be reported then
+                    let _ = sender.send(Ok(message));
+                }
+            }
+            Err(error) => {
+                let _ = sender.send(Err(error));
+                return;
+            }
+        }
+    }
+    if let Some(message) = collector.finish() {
+        let _ = sender.send(Ok(message));
+    }
 }
@@ -1,15 +1,26 @@
+//! Programmatic access to [Scarb](https://docs.swmansion.com/scarb).
+//!
+//! Provides [`ScarbCommand`] for invoking `scarb`, helpers for reading Scarb metadata
+//! and functions for loading compiled Starknet contract artifacts of a package.
+//!
+//! The public API of this crate follows semantic versioning, see the `examples` directory
+//! for typical usage.
+
 use anyhow::{anyhow, Context, Result};
 use camino::{Utf8Path, Utf8PathBuf};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use scarb_metadata::{CompilationUnitMetadata, Metadata, PackageId};
 use semver::VersionReq;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
 use universal_sierra_compiler_api::{compile_sierra_at_path, SierraType};
+use walkdir::WalkDir;
 
 pub use command::*;
 
 mod command;
+pub mod messages;
 pub mod metadata;
 pub mod version;
 
@@ -44,25 +55,70 @@ pub struct StarknetContractArtifacts {
     pub casm: String,
 }
 
-impl StarknetContractArtifacts {
+/// Casm of a contract, read or compiled only when [`CasmArtifact::load`] is called
+#[derive(Debug, PartialEq, Clone)]
+pub enum CasmArtifact {
+    /// Casm already loaded to memory
+    Loaded(String),
+    /// Path to a casm artifact generated by Scarb
+    File(Utf8PathBuf),
+    /// Path to a sierra artifact, compiled to casm with `universal-sierra-compiler`
+    Sierra(Utf8PathBuf),
+}
+
+impl CasmArtifact {
+    pub fn load(&self) -> Result<String> {
+        match self {
+            CasmArtifact::Loaded(casm) => Ok(casm.clone()),
+            CasmArtifact::File(casm_path) => fs::read_to_string(casm_path)
+                .with_context(|| format!("Failed to read casm artifact at {casm_path}")),
+            CasmArtifact::Sierra(sierra_path) => {
+                compile_sierra_at_path(sierra_path.as_str(), None, &SierraType::Contract)
+            }
+        }
+    }
+}
+
+/// Contains compiled Starknet artifacts, with casm loaded only when needed
+#[derive(Debug, PartialEq, Clone)]
+pub struct LazyStarknetContractArtifacts {
+    /// Compiled sierra code
+    pub sierra: String,
+    /// Compiled casm code, loaded on demand
+    pub casm: CasmArtifact,
+}
+
+impl LazyStarknetContractArtifacts {
     fn from_scarb_contract_artifact(
         starknet_contract: &StarknetContract,
         base_path: &Utf8Path,
     ) -> Result<Self> {
-        let sierra_path = base_path.join(starknet_contract.artifacts.sierra.clone());
-        let sierra = fs::read_to_string(sierra_path)?;
+        let sierra_path = base_path.join(&starknet_contract.artifacts.sierra);
+        let sierra = fs::read_to_string(&sierra_path)?;
 
         let casm = match &starknet_contract.artifacts.casm {
-            None => compile_sierra_at_path(
-                starknet_contract.artifacts.sierra.as_str(),
-                Some(base_path.as_std_path()),
-                &SierraType::Contract,
-            )?,
-            Some(casm_path) => fs::read_to_string(base_path.join(casm_path))?,
+            None => CasmArtifact::Sierra(sierra_path),
+            Some(casm_path) => CasmArtifact::File(base_path.join(casm_path)),
         };
 
         Ok(Self { sierra, casm })
     }
+
+    pub fn load(&self) -> Result<StarknetContractArtifacts> {
+        Ok(StarknetContractArtifacts {
+            sierra: self.sierra.clone(),
+            casm: self.casm.load()?,
+        })
+    }
+}
+
+impl From<StarknetContractArtifacts> for LazyStarknetContractArtifacts {
+    fn from(artifacts: StarknetContractArtifacts) -> Self {
+        Self {
+            sierra: artifacts.sierra,
+            casm: CasmArtifact::Loaded(artifacts.casm),
+        }
+    }
 }
 
 /// Get deserialized contents of `starknet_artifacts.json` file generated by Scarb
@@ -118,6 +174,21 @@ pub fn get_contracts_map(
     package: &PackageId,
     profile: Option<&str>,
 ) -> Result<HashMap<String, StarknetContractArtifacts>> {
+    // Contracts without casm artifacts are compiled here, so it's done in parallel
+    get_lazy_contracts_map(metadata, package, profile)?
+        .par_iter()
+        .map(|(name, artifacts)| Ok((name.clone(), artifacts.load()?)))
+        .collect()
+}
+
+/// Get the map with `LazyStarknetContractArtifacts` for the given package
+///
+/// Only sierra of the contracts is read, casm is read or compiled when it's loaded
+pub fn get_lazy_contracts_map(
+    metadata: &Metadata,
+    package: &PackageId,
+    profile: Option<&str>,
+) -> Result<HashMap<String, LazyStarknetContractArtifacts>> {
     let target_name = target_name_for_package(metadata, package)?;
     let target_dir = target_dir_for_workspace(metadata);
     let maybe_contracts_path = try_get_starknet_artifacts_path(
@@ -135,20 +206,78 @@ pub fn get_contracts_map(
 
 fn load_contract_artifacts(
     contracts_path: &Utf8PathBuf,
-) -> Result<HashMap<String, StarknetContractArtifacts>> {
+) -> Result<HashMap<String, LazyStarknetContractArtifacts>> {
     let base_path = contracts_path
         .parent()
         .ok_or_else(|| anyhow!("Failed to get parent for path = {}", &contracts_path))?;
     let artifacts = artifacts_for_package(contracts_path)?;
-    let mut map = HashMap::new();
 
-    for ref contract in artifacts.contracts {
-        let name = contract.contract_name.clone();
-        let contract_artifacts =
-            StarknetContractArtifacts::from_scarb_contract_artifact(contract, base_path)?;
-        map.insert(name, contract_artifacts);
+    artifacts
+        .contracts
+        .iter()
+        .map(|contract| {
+            let contract_artifacts =
+                LazyStarknetContractArtifacts::from_scarb_contract_artifact(contract, base_path)?;
+            Ok((contract.contract_name.clone(), contract_artifacts))
+        })
+        .collect()
+}
+
+/// Get paths to source files (`*.cairo` and `Scarb.toml`) of the package located at `package_root`
+///
+/// Target directory and hidden directories are skipped. Returned paths are sorted.
+#[must_use]
+pub fn package_source_files(package_root: &Utf8Path) -> Vec<Utf8PathBuf> {
+    let mut source_files: Vec<Utf8PathBuf> = WalkDir::new(package_root)
+        .into_iter()
+        .filter_entry(|entry| {
+            let file_name = entry.file_name().to_string_lossy();
+            entry.depth() == 0 || !(file_name == "target" || file_name.starts_with('.'))
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let file_name = entry.file_name().to_string_lossy();
+            entry.file_type().is_file()
+                && (file_name.ends_with(".cairo") || file_name == "Scarb.toml")
+        })
+        .filter_map(|entry| Utf8PathBuf::from_path_buf(entry.into_path()).ok())
+        .collect();
+    source_files.sort();
+    source_files
+}
+
+/// Checks if `starknet_artifacts.json` of the given package is older than any of its source files
+///
+/// Stale artifacts mean that contracts were not rebuilt after the sources changed.
+/// If the package has no starknet artifacts, `false` is returned.
+pub fn contract_artifacts_stale(
+    metadata: &Metadata,
+    package: &PackageId,
+    profile: Option<&str>,
+) -> Result<bool> {
+    let target_name = target_name_for_package(metadata, package)?;
+    let target_dir = target_dir_for_workspace(metadata);
+    let Some(contracts_path) = try_get_starknet_artifacts_path(
+        &target_dir,
+        &target_name,
+        profile.unwrap_or(metadata.current_profile.as_str()),
+    )?
+    else {
+        return Ok(false);
+    };
+    let artifacts_modified = fs::metadata(&contracts_path)?.modified()?;
+
+    let package_root = &metadata
+        .get_package(package)
+        .ok_or_else(|| anyhow!("Failed to find metadata for package = {package}"))?
+        .root;
+
+    for source_file in package_source_files(package_root) {
+        if fs::metadata(&source_file)?.modified()? > artifacts_modified {
+            return Ok(true);
+        }
     }
-    Ok(map)
+    Ok(false)
 }
 
 fn compilation_unit_for_package<'a>(
@@ -218,6 +347,7 @@ mod tests {
     use camino::Utf8PathBuf;
     use indoc::{formatdoc, indoc};
     use std::str::FromStr;
+    use std::time::{Duration, SystemTime};
 
     fn setup_package(package_name: &str) -> TempDir {
         let temp = TempDir::new().unwrap();
@@ -297,6 +427,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contract_artifacts_stale_after_source_change() {
+        let temp = setup_package("basic_package");
+
+        ScarbCommand::new_with_stdio()
+            .current_dir(temp.path())
+            .arg("build")
+            .run()
+            .unwrap();
+
+        let metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .current_dir(temp.path())
+            .run()
+            .unwrap();
+        let package = &metadata.workspace.members[0];
+
+        assert!(!contract_artifacts_stale(&metadata, package, None).unwrap());
+
+        fs::File::options()
+            .write(true)
+            .open(temp.path().join("src/lib.cairo"))
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        assert!(contract_artifacts_stale(&metadata, package, None).unwrap());
+    }
+
     #[test]
     fn package_matches_version_requirement_test() {
         let temp = setup_package("basic_package");
@@ -518,6 +677,38 @@ mod tests {
         assert_eq!(&casm_contents_erc20, &contract.casm);
     }
 
+    #[test]
+    fn get_lazy_contracts() {
+        let temp = setup_package("basic_package");
+
+        ScarbCommand::new_with_stdio()
+            .current_dir(temp.path())
+            .arg("build")
+            .run()
+            .unwrap();
+
+        let metadata = ScarbCommand::metadata()
+            .inherit_stderr()
+            .manifest_path(temp.join("Scarb.toml"))
+            .run()
+            .unwrap();
+
+        let package = metadata.packages.first().unwrap();
+        let contracts = get_lazy_contracts_map(&metadata, &package.id, None).unwrap();
+
+        let sierra_contents_erc20 =
+            fs::read_to_string(temp.join("target/dev/basic_package_ERC20.contract_class.json"))
+                .unwrap();
+        let casm_contents_erc20 = fs::read_to_string(
+            temp.join("target/dev/basic_package_ERC20.compiled_contract_class.json"),
+        )
+        .unwrap();
+        let contract = contracts.get("ERC20").unwrap();
+        assert_eq!(&sierra_contents_erc20, &contract.sierra);
+        assert!(matches!(contract.casm, CasmArtifact::File(_)));
+        assert_eq!(casm_contents_erc20, contract.casm.load().unwrap());
+    }
+
     #[test]
     fn get_name_for_package() {
         let temp = setup_package("basic_package");
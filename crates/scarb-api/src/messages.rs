@@ -0,0 +1,285 @@
+//! Messages printed by `scarb` run with `--json` output.
+
+use serde::Deserialize;
+
+/// Single message printed by `scarb` run with `--json` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScarbMessage {
+    /// Progress status, e.g. `Compiling` or `Finished`.
+    Status { status: String, message: String },
+    /// Diagnostic reported by the compiler or Scarb itself.
+    Diagnostic(Diagnostic),
+    /// Any other output.
+    Text(String),
+}
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Diagnostic reported by `scarb`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Message without the `error: ` or `warn: ` prefix, including the code snippet if present.
+    pub message: String,
+    pub location: Option<DiagnosticLocation>,
+}
+
+/// Place in a source file a [`Diagnostic`] points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticLocation {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for DiagnosticLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawMessage {
+    Status {
+        status: String,
+        message: String,
+    },
+    Typed {
+        #[serde(rename = "type")]
+        message_type: String,
+        message: String,
+    },
+    Text(String),
+}
+
+impl ScarbMessage {
+    /// Parses a single line of `scarb --json` output, lines which are not JSON are returned as [`ScarbMessage::Text`].
+    #[must_use]
+    pub fn parse(line: &str) -> Self {
+        match serde_json::from_str::<RawMessage>(line) {
+            Ok(RawMessage::Status { status, message }) => ScarbMessage::Status { status, message },
+            Ok(RawMessage::Typed {
+                message_type,
+                message,
+            }) => match message_type.as_str() {
+                "error" => Diagnostic::new(Severity::Error, &message).into(),
+                "warn" | "warning" => Diagnostic::new(Severity::Warning, &message).into(),
+                _ => ScarbMessage::Text(message),
+            },
+            Ok(RawMessage::Text(text)) => Self::from_text(text),
+            Err(_) => Self::from_text(line.to_string()),
+        }
+    }
+
+    fn from_text(text: String) -> Self {
+        if text.starts_with("error: ") {
+            Diagnostic::new(Severity::Error, &text).into()
+        } else if text.starts_with("warn: ") || text.starts_with("warning: ") {
+            Diagnostic::new(Severity::Warning, &text).into()
+        } else {
+            ScarbMessage::Text(text)
+        }
+    }
+}
+
+/// Collects lines of `scarb` output into messages.
+///
+/// Diagnostics printed as plain text span multiple lines, e.g. with the location and code snippet,
+/// so lines following a diagnostic are joined with it until an empty line, a JSON message or the next diagnostic.
+#[derive(Debug, Default)]
+pub(crate) struct MessageCollector {
+    pending_diagnostic: Option<String>,
+}
+
+impl MessageCollector {
+    /// Adds a single line of output, returns messages completed by it
+    pub(crate) fn push_line(&mut self, line: &str) -> Vec<ScarbMessage> {
+        if line.trim().is_empty() {
+            return self.finish().into_iter().collect();
+        }
+
+        let is_json = serde_json::from_str::<RawMessage>(line).is_ok();
+        let starts_diagnostic = !is_json && is_diagnostic_start(line);
+
+        if !is_json && !starts_diagnostic {
+            if let Some(pending) = &mut self.pending_diagnostic {
+                pending.push('\n');
+                pending.push_str(line);
+                return vec![];
+            }
+        }
+
+        let mut messages: Vec<ScarbMessage> = self.finish().into_iter().collect();
+        if starts_diagnostic {
+            self.pending_diagnostic = Some(line.to_string());
+        } else {
+            messages.push(ScarbMessage::parse(line));
+        }
+        messages
+    }
+
+    /// Returns the diagnostic still being collected, if any
+    pub(crate) fn finish(&mut self) -> Option<ScarbMessage> {
+        self.pending_diagnostic.take().map(ScarbMessage::from_text)
+    }
+}
+
+fn is_diagnostic_start(line: &str) -> bool {
+    ["error: ", "warn: ", "warning: "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+}
+
+impl From<Diagnostic> for ScarbMessage {
+    fn from(diagnostic: Diagnostic) -> Self {
+        ScarbMessage::Diagnostic(diagnostic)
+    }
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, message: &str) -> Self {
+        let message = ["error: ", "warn: ", "warning: "]
+            .iter()
+            .find_map(|prefix| message.strip_prefix(prefix))
+            .unwrap_or(message)
+            .trim_end()
+            .to_string();
+        let location = message
+            .lines()
+            .find_map(|line| line.trim_start().strip_prefix("--> "))
+            .and_then(parse_location);
+
+        Self {
+            severity,
+            message,
+            location,
+        }
+    }
+}
+
+/// Parses `path:line:column`, splitting from the end as the path itself may contain colons
+fn parse_location(location: &str) -> Option<DiagnosticLocation> {
+    let mut parts = location.trim().rsplitn(3, ':');
+    let column = parts.next()?.parse().ok()?;
+    let line = parts.next()?.parse().ok()?;
+    let file = parts.next()?.to_string();
+
+    Some(DiagnosticLocation { file, line, column })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status() {
+        assert_eq!(
+            ScarbMessage::parse(r#"{"status":"compiling","message":"hello v0.1.0"}"#),
+            ScarbMessage::Status {
+                status: "compiling".to_string(),
+                message: "hello v0.1.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_diagnostics_with_location() {
+        let message = ScarbMessage::parse(
+            r#""error: Skipped tokens. Expected: Module/Use/FreeFunction.\n --> /home/hello/src/lib.cairo:2:1\nmods erc20;\n^**^\n""#,
+        );
+
+        assert_eq!(
+            message,
+            ScarbMessage::Diagnostic(Diagnostic {
+                severity: Severity::Error,
+                message: "Skipped tokens. Expected: Module/Use/FreeFunction.\n --> /home/hello/src/lib.cairo:2:1\nmods erc20;\n^**^".to_string(),
+                location: Some(DiagnosticLocation {
+                    file: "/home/hello/src/lib.cairo".to_string(),
+                    line: 2,
+                    column: 1,
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_typed_messages() {
+        assert_eq!(
+            ScarbMessage::parse(r#"{"type":"warn","message":"unused variable"}"#),
+            ScarbMessage::Diagnostic(Diagnostic {
+                severity: Severity::Warning,
+                message: "unused variable".to_string(),
+                location: None,
+            })
+        );
+        assert_eq!(
+            ScarbMessage::parse(r#"{"type":"print","message":"hello"}"#),
+            ScarbMessage::Text("hello".to_string())
+        );
+        assert_eq!(
+            ScarbMessage::parse("not a json"),
+            ScarbMessage::Text("not a json".to_string())
+        );
+    }
+
+    #[test]
+    fn collects_multiline_text_diagnostics() {
+        let mut collector = MessageCollector::default();
+        let lines = [
+            r#"{"status":"compiling","message":"hello v0.1.0"}"#,
+            "error: Skipped tokens. Expected: Module/Use/FreeFunction.",
+            " --> /home/hello/src/lib.cairo:2:1",
+            "mods erc20;",
+            "^**^",
+            "",
+            "error: could not compile `hello` due to previous error",
+        ];
+        let mut messages: Vec<ScarbMessage> = lines
+            .iter()
+            .flat_map(|line| collector.push_line(line))
+            .collect();
+        messages.extend(collector.finish());
+
+        assert_eq!(
+            messages,
+            vec![
+                ScarbMessage::Status {
+                    status: "compiling".to_string(),
+                    message: "hello v0.1.0".to_string(),
+                },
+                ScarbMessage::Diagnostic(Diagnostic {
+                    severity: Severity::Error,
+                    message: "Skipped tokens. Expected: Module/Use/FreeFunction.\n --> /home/hello/src/lib.cairo:2:1\nmods erc20;\n^**^".to_string(),
+                    location: Some(DiagnosticLocation {
+                        file: "/home/hello/src/lib.cairo".to_string(),
+                        line: 2,
+                        column: 1,
+                    }),
+                }),
+                ScarbMessage::Diagnostic(Diagnostic {
+                    severity: Severity::Error,
+                    message: "could not compile `hello` due to previous error".to_string(),
+                    location: None,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_windows_location() {
+        assert_eq!(
+            parse_location(r"C:\hello\src\lib.cairo:10:5"),
+            Some(DiagnosticLocation {
+                file: r"C:\hello\src\lib.cairo".to_string(),
+                line: 10,
+                column: 5,
+            })
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use axum::{extract::State, routing::post, Json, Router};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::TcpListener as StdTcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tokio::net::TcpListener;
+use tokio::runtime::Builder;
+
+/// Error code returned for methods without a programmed response
+pub const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+#[derive(Default)]
+struct MockRpcState {
+    responses: HashMap<String, Value>,
+    received_requests: Vec<Value>,
+}
+
+/// JSON-RPC server replying with canned responses, meant to replace a live node in tests.
+///
+/// The server runs on its own thread, so it can be used in both synchronous and asynchronous tests.
+pub struct MockRpcServer {
+    url: String,
+    state: Arc<Mutex<MockRpcState>>,
+}
+
+impl MockRpcServer {
+    /// Starts the server on a free local port
+    #[must_use]
+    pub fn start() -> Self {
+        let listener = StdTcpListener::bind("127.0.0.1:0").expect("Failed to bind mock RPC server");
+        listener
+            .set_nonblocking(true)
+            .expect("Failed to set mock RPC server listener as non-blocking");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let state = Arc::new(Mutex::new(MockRpcState::default()));
+        let app = Router::new()
+            .route("/", post(handle_request))
+            .with_state(state.clone());
+
+        thread::spawn(move || {
+            Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create mock RPC server runtime")
+                .block_on(async {
+                    let listener = TcpListener::from_std(listener).unwrap();
+                    axum::serve(listener, app).await.unwrap();
+                });
+        });
+
+        Self { url, state }
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Makes the server reply to every call of `method` with `result`
+    #[must_use]
+    pub fn with_response(self, method: &str, result: Value) -> Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert(method.to_string(), result);
+        self
+    }
+
+    /// Returns all requests received so far, in the order they arrived
+    #[must_use]
+    pub fn received_requests(&self) -> Vec<Value> {
+        self.state.lock().unwrap().received_requests.clone()
+    }
+
+    /// Returns params of every received call of `method`
+    #[must_use]
+    pub fn received_params(&self, method: &str) -> Vec<Value> {
+        self.received_requests()
+            .into_iter()
+            .filter(|request| request["method"] == method)
+            .map(|request| request["params"].clone())
+            .collect()
+    }
+
+    pub fn assert_called(&self, method: &str) {
+        assert!(
+            !self.received_params(method).is_empty(),
+            "Method {method} was not called, received requests: {:?}",
+            self.received_requests()
+        );
+    }
+}
+
+#[allow(clippy::unused_async)]
+async fn handle_request(
+    State(state): State<Arc<Mutex<MockRpcState>>>,
+    Json(request): Json<Value>,
+) -> Json<Value> {
+    let mut state = state.lock().unwrap();
+    let id = request["id"].clone();
+
+    let response = match request["method"]
+        .as_str()
+        .and_then(|method| state.responses.get(method))
+    {
+        Some(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        None => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": METHOD_NOT_FOUND_CODE, "message": "Method not found" }
+        }),
+    };
+    state.received_requests.push(request);
+
+    Json(response)
+}
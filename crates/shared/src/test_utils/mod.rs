@@ -1 +1,2 @@
+pub mod mock_rpc;
 pub mod output_assert;
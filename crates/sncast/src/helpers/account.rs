@@ -0,0 +1,182 @@
+use crate::helpers::external_signer::TransactionDetails;
+use crate::helpers::signer::{AccountSigner, AccountSignerError, TransactionSigner};
+use async_trait::async_trait;
+use starknet::accounts::single_owner::SignError;
+use starknet::accounts::{
+    Account, Call, ConnectedAccount, ExecutionEncoder, ExecutionEncoding, RawDeclaration,
+    RawExecution, RawLegacyDeclaration,
+};
+use starknet::core::types::{BlockId, BlockTag, FieldElement};
+use starknet::providers::Provider;
+use starknet::signers::Signer;
+
+/// Account controlled by a single [`AccountSigner`]
+///
+/// Behaves like `SingleOwnerAccount`, but passes fields of the signed transactions
+/// to the signer instead of their hashes only
+#[derive(Debug)]
+pub struct CastAccount<P> {
+    provider: P,
+    signer: AccountSigner,
+    address: FieldElement,
+    chain_id: FieldElement,
+    block_id: BlockId,
+    encoding: ExecutionEncoding,
+}
+
+impl<P> CastAccount<P> {
+    #[must_use]
+    pub fn new(
+        provider: P,
+        signer: AccountSigner,
+        address: FieldElement,
+        chain_id: FieldElement,
+        encoding: ExecutionEncoding,
+    ) -> Self {
+        Self {
+            provider,
+            signer,
+            address,
+            chain_id,
+            block_id: BlockId::Tag(BlockTag::Latest),
+            encoding,
+        }
+    }
+
+    pub fn set_block_id(&mut self, block_id: BlockId) -> &Self {
+        self.block_id = block_id;
+        self
+    }
+
+    async fn sign(
+        &self,
+        hash: &FieldElement,
+        transaction: &TransactionDetails,
+    ) -> Result<Vec<FieldElement>, SignError<AccountSignerError>> {
+        let signature = self
+            .signer
+            .sign_transaction(hash, transaction)
+            .await
+            .map_err(SignError::Signer)?;
+
+        Ok(vec![signature.r, signature.s])
+    }
+}
+
+#[async_trait]
+impl<P> Account for CastAccount<P>
+where
+    P: Provider + Sync + Send,
+{
+    type SignError = SignError<AccountSignerError>;
+
+    fn address(&self) -> FieldElement {
+        self.address
+    }
+
+    fn chain_id(&self) -> FieldElement {
+        self.chain_id
+    }
+
+    async fn sign_execution(
+        &self,
+        execution: &RawExecution,
+        query_only: bool,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash = execution.transaction_hash(self.chain_id, self.address, query_only, self);
+        let transaction = TransactionDetails::Invoke {
+            sender_address: self.address,
+            calldata: self.encode_calls(execution.calls()),
+            max_fee: execution.max_fee(),
+            nonce: execution.nonce(),
+            chain_id: self.chain_id,
+            query_only,
+        };
+
+        self.sign(&tx_hash, &transaction).await
+    }
+
+    async fn sign_declaration(
+        &self,
+        declaration: &RawDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash = declaration.transaction_hash(self.chain_id, self.address, query_only);
+        let transaction = TransactionDetails::Declare {
+            sender_address: self.address,
+            class_hash: declaration.contract_class().class_hash(),
+            compiled_class_hash: declaration.compiled_class_hash(),
+            max_fee: declaration.max_fee(),
+            nonce: declaration.nonce(),
+            chain_id: self.chain_id,
+            query_only,
+        };
+
+        self.sign(&tx_hash, &transaction).await
+    }
+
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        // Cairo 0 classes are not declared by sncast, their hash is signed as is
+        let tx_hash = legacy_declaration
+            .transaction_hash(self.chain_id, self.address, query_only)
+            .map_err(SignError::ClassHash)?;
+        let signature = self
+            .signer
+            .sign_hash(&tx_hash)
+            .await
+            .map_err(SignError::Signer)?;
+
+        Ok(vec![signature.r, signature.s])
+    }
+}
+
+impl<P> ExecutionEncoder for CastAccount<P> {
+    fn encode_calls(&self, calls: &[Call]) -> Vec<FieldElement> {
+        let mut execute_calldata: Vec<FieldElement> = vec![calls.len().into()];
+
+        match self.encoding {
+            ExecutionEncoding::Legacy => {
+                let mut concated_calldata: Vec<FieldElement> = vec![];
+                for call in calls {
+                    execute_calldata.push(call.to);
+                    execute_calldata.push(call.selector);
+                    execute_calldata.push(concated_calldata.len().into()); // data_offset
+                    execute_calldata.push(call.calldata.len().into()); // data_len
+                    concated_calldata.extend_from_slice(&call.calldata);
+                }
+
+                execute_calldata.push(concated_calldata.len().into()); // calldata_len
+                execute_calldata.extend_from_slice(&concated_calldata);
+            }
+            ExecutionEncoding::New => {
+                for call in calls {
+                    execute_calldata.push(call.to);
+                    execute_calldata.push(call.selector);
+                    execute_calldata.push(call.calldata.len().into());
+                    execute_calldata.extend_from_slice(&call.calldata);
+                }
+            }
+        }
+
+        execute_calldata
+    }
+}
+
+impl<P> ConnectedAccount for CastAccount<P>
+where
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+}
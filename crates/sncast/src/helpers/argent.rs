@@ -0,0 +1,85 @@
+use crate::helpers::external_signer::TransactionDetails;
+use crate::helpers::signer::TransactionSigner;
+use async_trait::async_trait;
+use starknet::accounts::{AccountFactory, PreparedAccountDeployment, RawAccountDeployment};
+use starknet::core::types::{BlockId, BlockTag, FieldElement};
+use starknet::providers::Provider;
+use starknet::signers::Signer;
+
+/// Factory of Cairo 1 Argent accounts, constructed with an owner and a guardian key
+pub struct ArgentAccountFactory<S, P> {
+    class_hash: FieldElement,
+    chain_id: FieldElement,
+    owner_public_key: FieldElement,
+    guardian_public_key: FieldElement,
+    signer: S,
+    provider: P,
+    block_id: BlockId,
+}
+
+impl<S, P> ArgentAccountFactory<S, P>
+where
+    S: Signer,
+{
+    pub async fn new(
+        class_hash: FieldElement,
+        chain_id: FieldElement,
+        guardian_public_key: FieldElement,
+        signer: S,
+        provider: P,
+    ) -> Result<Self, S::GetPublicKeyError> {
+        let owner_public_key = signer.get_public_key().await?;
+
+        Ok(Self {
+            class_hash,
+            chain_id,
+            owner_public_key: owner_public_key.scalar(),
+            guardian_public_key,
+            signer,
+            provider,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        })
+    }
+}
+
+#[async_trait]
+impl<S, P> AccountFactory for ArgentAccountFactory<S, P>
+where
+    S: TransactionSigner + Sync + Send,
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+    type SignError = S::SignError;
+
+    fn class_hash(&self) -> FieldElement {
+        self.class_hash
+    }
+
+    fn calldata(&self) -> Vec<FieldElement> {
+        vec![self.owner_public_key, self.guardian_public_key]
+    }
+
+    fn chain_id(&self) -> FieldElement {
+        self.chain_id
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn sign_deployment(
+        &self,
+        deployment: &RawAccountDeployment,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash =
+            PreparedAccountDeployment::from_raw(deployment.clone(), self).transaction_hash();
+        let transaction = TransactionDetails::deploy_account(self, deployment);
+        let signature = self.signer.sign_transaction(&tx_hash, &transaction).await?;
+
+        Ok(vec![signature.r, signature.s])
+    }
+}
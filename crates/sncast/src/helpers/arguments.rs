@@ -0,0 +1,885 @@
+use crate::helpers::token::u256_to_felts;
+use anyhow::{bail, Context, Result};
+use conversions::byte_array::ByteArray;
+use conversions::FromConv;
+use primitive_types::U256;
+use serde_json::Value;
+use starknet::core::types::{ContractClass, FieldElement};
+use starknet::core::utils::cairo_short_string_to_felt;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Parameter of a function or a constructor declared in a contract ABI
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiParameter {
+    pub name: String,
+    pub ty: String,
+}
+
+/// Argument parsed from the `--arguments` string, serialized once its type is known from the ABI
+#[derive(Debug, PartialEq)]
+enum Argument {
+    Number(String),
+    String(String),
+    Bool(bool),
+    /// Enum variant without a value, e.g. `None` or `Direction::North`
+    Path(String),
+    /// Argument annotated with its type, e.g. `u256:1000`
+    Typed(String, Box<Argument>),
+    /// `[1, 2]` or `array![1, 2]`
+    Array(Vec<Argument>),
+    /// `(1, 2)`
+    Tuple(Vec<Argument>),
+    /// `Point { x: 1, y: 2 }`
+    Struct(String, Vec<(String, Argument)>),
+    /// Enum variant with a value, e.g. `Some(1)`
+    Variant(String, Box<Argument>),
+}
+
+impl fmt::Display for Argument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Argument::Number(number) => write!(f, "{number}"),
+            Argument::String(string) => write!(f, "{string:?}"),
+            Argument::Bool(value) => write!(f, "{value}"),
+            Argument::Path(path) => write!(f, "{path}"),
+            Argument::Typed(ty, value) => write!(f, "{ty}:{value}"),
+            Argument::Array(elements) => write!(f, "[{}]", join(elements)),
+            Argument::Tuple(elements) => write!(f, "({})", join(elements)),
+            Argument::Struct(name, fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(field, value)| format!("{field}: {value}"))
+                    .collect();
+                write!(f, "{name} {{ {} }}", fields.join(", "))
+            }
+            Argument::Variant(path, value) => write!(f, "{path}({value})"),
+        }
+    }
+}
+
+fn join(arguments: &[Argument]) -> String {
+    let arguments: Vec<String> = arguments.iter().map(ToString::to_string).collect();
+    arguments.join(", ")
+}
+
+/// JSON ABI of a Sierra class
+pub fn sierra_abi(class: &ContractClass) -> Result<Value> {
+    match class {
+        ContractClass::Sierra(class) => {
+            serde_json::from_str(&class.abi).context("Failed to parse the class ABI")
+        }
+        ContractClass::Legacy(_) => bail!("--arguments are supported only for Sierra classes"),
+    }
+}
+
+/// Parameters of the constructor in the JSON ABI of a Sierra contract, empty if it has none
+pub fn constructor_parameters(abi: &Value) -> Result<Vec<AbiParameter>> {
+    abi_entries(abi)
+        .find(|entry| entry["type"] == "constructor")
+        .map_or_else(|| Ok(vec![]), |entry| parameters_of(&entry["inputs"]))
+}
+
+/// Parameters of the function `name` in the JSON ABI of a Sierra contract,
+/// including functions of the interfaces it implements
+pub fn function_parameters(abi: &Value, name: &str) -> Result<Vec<AbiParameter>> {
+    let function = abi_functions(abi)
+        .find(|function| function["name"] == name)
+        .with_context(|| format!("Function {name} not found in the contract ABI"))?;
+    parameters_of(&function["inputs"])
+}
+
+/// Whether the JSON ABI of a Sierra contract has the `__validate__` and `__execute__`
+/// entry points of an account
+#[must_use]
+pub fn is_account_abi(abi: &Value) -> bool {
+    ["__validate__", "__execute__"]
+        .iter()
+        .all(|name| abi_functions(abi).any(|function| function["name"] == *name))
+}
+
+fn abi_entries(abi: &Value) -> impl Iterator<Item = &Value> {
+    abi.as_array().into_iter().flatten()
+}
+
+/// Functions in the JSON ABI, including functions of the interfaces the contract implements
+fn abi_functions(abi: &Value) -> impl Iterator<Item = &Value> {
+    abi_entries(abi)
+        .flat_map(|entry| match entry["type"].as_str() {
+            Some("interface") => abi_entries(&entry["items"]).collect::<Vec<_>>(),
+            _ => vec![entry],
+        })
+        .filter(|entry| entry["type"] == "function")
+}
+
+fn parameters_of(inputs: &Value) -> Result<Vec<AbiParameter>> {
+    abi_entries(inputs)
+        .map(|input| {
+            Ok(AbiParameter {
+                name: input["name"]
+                    .as_str()
+                    .context("ABI parameter has no name")?
+                    .to_string(),
+                ty: input["type"]
+                    .as_str()
+                    .context("ABI parameter has no type")?
+                    .to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Serializes comma separated human readable `arguments` into calldata, according to
+/// the types of `parameters` and the structs and enums declared in `abi`
+pub fn serialize_arguments(
+    arguments: &str,
+    parameters: &[AbiParameter],
+    abi: &Value,
+) -> Result<Vec<FieldElement>> {
+    let arguments = parse_arguments(arguments)?;
+    if arguments.len() != parameters.len() {
+        bail!(
+            "Expected {} arguments, got {}",
+            parameters.len(),
+            arguments.len()
+        );
+    }
+
+    let types = AbiTypes::from_abi(abi)?;
+    let mut calldata = vec![];
+    for (argument, parameter) in arguments.iter().zip(parameters) {
+        types
+            .serialize(argument, &parameter.ty, &mut calldata)
+            .with_context(|| {
+                format!(
+                    "Failed to serialize argument `{}` of type {}",
+                    parameter.name, parameter.ty
+                )
+            })?;
+    }
+    Ok(calldata)
+}
+
+/// Structs and enums declared in the ABI, by their full type names
+#[derive(Default)]
+struct AbiTypes {
+    structs: HashMap<String, Vec<AbiParameter>>,
+    enums: HashMap<String, Vec<AbiParameter>>,
+}
+
+impl AbiTypes {
+    fn from_abi(abi: &Value) -> Result<Self> {
+        let mut types = Self::default();
+        for entry in abi_entries(abi) {
+            let Some(name) = entry["name"].as_str() else {
+                continue;
+            };
+            match entry["type"].as_str() {
+                Some("struct") => {
+                    types
+                        .structs
+                        .insert(name.to_string(), parameters_of(&entry["members"])?);
+                }
+                Some("enum") => {
+                    types
+                        .enums
+                        .insert(name.to_string(), parameters_of(&entry["variants"])?);
+                }
+                _ => {}
+            }
+        }
+        Ok(types)
+    }
+
+    fn serialize(
+        &self,
+        argument: &Argument,
+        ty: &str,
+        calldata: &mut Vec<FieldElement>,
+    ) -> Result<()> {
+        let type_name = short_type_name(ty);
+        match (argument, type_name) {
+            (Argument::Typed(annotation, value), _) => {
+                if annotation != type_name {
+                    bail!("Value annotated as {annotation} passed where {ty} is expected");
+                }
+                self.serialize(value, ty, calldata)?;
+            }
+            (
+                Argument::Number(number),
+                "felt252" | "ContractAddress" | "ClassHash" | "EthAddress",
+            ) => {
+                calldata.push(parse_felt(number)?);
+            }
+            (Argument::String(string), "felt252") => {
+                calldata.push(
+                    cairo_short_string_to_felt(string)
+                        .with_context(|| format!("{argument} is not a valid short string"))?,
+                );
+            }
+            (
+                Argument::Number(number),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "u256",
+            ) => serialize_unsigned(number, type_name, calldata)?,
+            (Argument::Number(number), "i8" | "i16" | "i32" | "i64" | "i128") => {
+                calldata.push(parse_signed(number, type_name)?);
+            }
+            (Argument::Bool(value), "bool") => calldata.push(FieldElement::from(u8::from(*value))),
+            (Argument::String(string), "ByteArray") => calldata.extend(
+                ByteArray::from(string.as_str())
+                    .serialize_no_magic()
+                    .into_iter()
+                    .map(FieldElement::from_),
+            ),
+            (Argument::Array(elements), "Array" | "Span") => {
+                let element_type = generic_argument(ty)?;
+                calldata.push(FieldElement::from(elements.len()));
+                for element in elements {
+                    self.serialize(element, element_type, calldata)?;
+                }
+            }
+            (Argument::Tuple(elements), _) if ty.starts_with('(') => {
+                let element_types = tuple_types(ty);
+                if elements.len() != element_types.len() {
+                    bail!(
+                        "Expected a tuple of {} elements, got {}",
+                        element_types.len(),
+                        elements.len()
+                    );
+                }
+                for (element, element_type) in elements.iter().zip(element_types) {
+                    self.serialize(element, element_type, calldata)?;
+                }
+            }
+            (Argument::Struct(name, fields), _) if self.structs.contains_key(ty) => {
+                if short_type_name(name) != type_name {
+                    bail!("Struct {name} passed where {ty} is expected");
+                }
+                self.serialize_struct(fields, ty, &self.structs[ty], calldata)?;
+            }
+            (Argument::Path(path), _) if self.enums.contains_key(ty) => {
+                self.serialize_variant(path, None, ty, &self.enums[ty], calldata)?;
+            }
+            (Argument::Variant(path, value), _) if self.enums.contains_key(ty) => {
+                self.serialize_variant(path, Some(value.as_ref()), ty, &self.enums[ty], calldata)?;
+            }
+            _ => bail!("{argument} is not a valid value of type {ty}"),
+        }
+        Ok(())
+    }
+
+    fn serialize_struct(
+        &self,
+        fields: &[(String, Argument)],
+        ty: &str,
+        members: &[AbiParameter],
+        calldata: &mut Vec<FieldElement>,
+    ) -> Result<()> {
+        if let Some((field, _)) = fields
+            .iter()
+            .find(|(field, _)| !members.iter().any(|member| &member.name == field))
+        {
+            bail!("Struct {ty} has no member `{field}`");
+        }
+
+        for member in members {
+            let (_, value) = fields
+                .iter()
+                .find(|(field, _)| *field == member.name)
+                .with_context(|| format!("Member `{}` of struct {ty} is missing", member.name))?;
+            self.serialize(value, &member.ty, calldata)
+                .with_context(|| {
+                    format!(
+                        "Failed to serialize member `{}` of type {}",
+                        member.name, member.ty
+                    )
+                })?;
+        }
+        Ok(())
+    }
+
+    fn serialize_variant(
+        &self,
+        path: &str,
+        value: Option<&Argument>,
+        ty: &str,
+        variants: &[AbiParameter],
+        calldata: &mut Vec<FieldElement>,
+    ) -> Result<()> {
+        let variant_name = match path.rsplit_once("::") {
+            Some((enum_name, variant_name)) => {
+                if short_type_name(enum_name) != short_type_name(ty) {
+                    bail!("Variant {path} passed where {ty} is expected");
+                }
+                variant_name
+            }
+            None => path,
+        };
+        let (index, variant) = variants
+            .iter()
+            .enumerate()
+            .find(|(_, variant)| variant.name == variant_name)
+            .with_context(|| format!("Enum {ty} has no variant {variant_name}"))?;
+
+        calldata.push(FieldElement::from(index));
+        match value {
+            Some(value) => self
+                .serialize(value, &variant.ty, calldata)
+                .with_context(|| {
+                    format!(
+                        "Failed to serialize value of variant {variant_name} of type {}",
+                        variant.ty
+                    )
+                })?,
+            None if variant.ty == "()" => {}
+            None => bail!(
+                "Variant {variant_name} of {ty} requires a value of type {}",
+                variant.ty
+            ),
+        }
+        Ok(())
+    }
+}
+
+/// Name of the type without its module path and generic arguments,
+/// e.g. `Array` for `core::array::Array::<core::felt252>`
+fn short_type_name(ty: &str) -> &str {
+    let base = ty.split_once("::<").map_or(ty, |(base, _)| base);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+/// Generic argument of a type with a single one, e.g. `core::felt252` for
+/// `core::array::Array::<core::felt252>`
+fn generic_argument(ty: &str) -> Result<&str> {
+    ty.split_once("::<")
+        .and_then(|(_, generic)| generic.strip_suffix('>'))
+        .with_context(|| format!("Type {ty} has no generic argument"))
+}
+
+/// Types of the elements of a tuple type, e.g. `(core::felt252, core::integer::u8)`
+fn tuple_types(ty: &str) -> Vec<&str> {
+    let inner = ty
+        .strip_prefix('(')
+        .and_then(|ty| ty.strip_suffix(')'))
+        .unwrap_or(ty);
+
+    let mut types = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, character) in inner.char_indices() {
+        match character {
+            '(' | '<' => depth += 1,
+            ')' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                types.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    types.push(inner[start..].trim());
+    types.retain(|ty| !ty.is_empty());
+    types
+}
+
+fn serialize_unsigned(
+    number: &str,
+    type_name: &str,
+    calldata: &mut Vec<FieldElement>,
+) -> Result<()> {
+    let bits = match type_name {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" | "usize" => 32,
+        "u64" => 64,
+        "u128" => 128,
+        _ => 256,
+    };
+    let value = parse_u256(number)?;
+    if value.bits() > bits {
+        bail!("{number} is out of range of {type_name}");
+    }
+    let (low, high) = u256_to_felts(value);
+    calldata.push(low);
+    if type_name == "u256" {
+        calldata.push(high);
+    }
+    Ok(())
+}
+
+fn parse_signed(number: &str, type_name: &str) -> Result<FieldElement> {
+    let bits: u32 = type_name[1..]
+        .parse()
+        .expect("Failed to parse integer size");
+    let value: i128 = number
+        .parse()
+        .with_context(|| format!("{number} is not a valid {type_name}"))?;
+    let max = i128::MAX >> (128 - bits);
+    if value > max || value < -max - 1 {
+        bail!("{number} is out of range of {type_name}");
+    }
+    let magnitude = FieldElement::from(value.unsigned_abs());
+    Ok(if value < 0 {
+        FieldElement::ZERO - magnitude
+    } else {
+        magnitude
+    })
+}
+
+fn parse_felt(number: &str) -> Result<FieldElement> {
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, number),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(_) => FieldElement::from_hex_be(digits),
+        None => FieldElement::from_dec_str(digits),
+    }
+    .with_context(|| format!("{number} is not a valid felt252"))?;
+    Ok(if negative {
+        FieldElement::ZERO - value
+    } else {
+        value
+    })
+}
+
+fn parse_u256(number: &str) -> Result<U256> {
+    match number.strip_prefix("0x") {
+        Some(digits) => U256::from_str_radix(digits, 16).ok(),
+        None => U256::from_dec_str(number).ok(),
+    }
+    .with_context(|| format!("{number} is not a valid unsigned integer"))
+}
+
+fn parse_arguments(arguments: &str) -> Result<Vec<Argument>> {
+    let mut parser = Parser {
+        chars: arguments.chars().collect(),
+        position: 0,
+    };
+    let mut parsed = vec![];
+    parser.skip_whitespace();
+    while parser.peek().is_some() {
+        parsed.push(parser.parse_argument()?);
+        parser.skip_whitespace();
+        match parser.advance() {
+            Some(',') | None => parser.skip_whitespace(),
+            Some(unexpected) => bail!(
+                "Unexpected `{unexpected}` at position {} of arguments, expected `,`",
+                parser.position
+            ),
+        }
+    }
+    Ok(parsed)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let current = self.peek();
+        self.position += 1;
+        current
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.advance() {
+            Some(character) if character == expected => Ok(()),
+            Some(unexpected) => bail!(
+                "Unexpected `{unexpected}` at position {} of arguments, expected `{expected}`",
+                self.position
+            ),
+            None => bail!("Unexpected end of arguments, expected `{expected}`"),
+        }
+    }
+
+    fn parse_argument(&mut self) -> Result<Argument> {
+        match self.peek() {
+            Some('"') => return self.parse_string(),
+            Some('[') => return Ok(Argument::Array(self.parse_list('[', ']')?)),
+            Some('(') => return Ok(Argument::Tuple(self.parse_list('(', ')')?)),
+            _ => {}
+        }
+
+        let path = self.parse_path();
+        if path.is_empty() {
+            match self.peek() {
+                Some(unexpected) => bail!(
+                    "Unexpected `{unexpected}` at position {} of arguments",
+                    self.position + 1
+                ),
+                None => bail!("Unexpected end of arguments"),
+            }
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(':') => {
+                self.position += 1;
+                self.skip_whitespace();
+                Ok(Argument::Typed(path, Box::new(self.parse_argument()?)))
+            }
+            Some('!') if path == "array" => {
+                self.position += 1;
+                self.skip_whitespace();
+                Ok(Argument::Array(self.parse_list('[', ']')?))
+            }
+            Some('{') => Ok(Argument::Struct(path, self.parse_fields()?)),
+            Some('(') => {
+                self.position += 1;
+                self.skip_whitespace();
+                let value = self.parse_argument()?;
+                self.expect(')')?;
+                Ok(Argument::Variant(path, Box::new(value)))
+            }
+            _ => Ok(match path.as_str() {
+                "true" => Argument::Bool(true),
+                "false" => Argument::Bool(false),
+                _ if path.starts_with(|character: char| character.is_ascii_digit())
+                    || path.starts_with('-') =>
+                {
+                    Argument::Number(path)
+                }
+                _ => Argument::Path(path),
+            }),
+        }
+    }
+
+    /// Word optionally followed by `::` separated segments, e.g. `Direction::North`
+    fn parse_path(&mut self) -> String {
+        let mut path = self.parse_word();
+        while !path.is_empty()
+            && self.peek() == Some(':')
+            && self.chars.get(self.position + 1) == Some(&':')
+        {
+            self.position += 2;
+            path.push_str("::");
+            path.push_str(&self.parse_word());
+        }
+        path
+    }
+
+    fn parse_word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(character) = self
+            .peek()
+            .filter(|character| character.is_alphanumeric() || matches!(character, '_' | '-'))
+        {
+            word.push(character);
+            self.position += 1;
+        }
+        word
+    }
+
+    /// Comma separated arguments between `open` and `close`, a trailing comma is allowed
+    fn parse_list(&mut self, open: char, close: char) -> Result<Vec<Argument>> {
+        self.expect(open)?;
+        let mut elements = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(close) {
+                self.position += 1;
+                return Ok(elements);
+            }
+            elements.push(self.parse_argument()?);
+            self.skip_whitespace();
+            if self.peek() != Some(close) {
+                self.expect(',')?;
+            }
+        }
+    }
+
+    fn parse_fields(&mut self) -> Result<Vec<(String, Argument)>> {
+        self.expect('{')?;
+        let mut fields = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.position += 1;
+                return Ok(fields);
+            }
+            let field = self.parse_word();
+            if field.is_empty() {
+                bail!(
+                    "Expected a struct member name at position {} of arguments",
+                    self.position + 1
+                );
+            }
+            self.expect(':')?;
+            self.skip_whitespace();
+            fields.push((field, self.parse_argument()?));
+            self.skip_whitespace();
+            if self.peek() != Some('}') {
+                self.expect(',')?;
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<Argument> {
+        self.position += 1;
+        let mut string = String::new();
+        loop {
+            match self.advance() {
+                Some('"') => return Ok(Argument::String(string)),
+                Some('\\') => match self.advance() {
+                    Some('n') => string.push('\n'),
+                    Some(escaped @ ('"' | '\\')) => string.push(escaped),
+                    _ => bail!("Invalid escape sequence in a string argument"),
+                },
+                Some(character) => string.push(character),
+                None => bail!("Unterminated string argument"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parameters(types: &[&str]) -> Vec<AbiParameter> {
+        types
+            .iter()
+            .enumerate()
+            .map(|(index, ty)| AbiParameter {
+                name: format!("arg{index}"),
+                ty: (*ty).to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn serializes_scalar_arguments() {
+        let parameters = parameters(&[
+            "core::felt252",
+            "core::integer::u256",
+            "core::starknet::contract_address::ContractAddress",
+            "core::bool",
+            "core::integer::i8",
+            "core::felt252",
+        ]);
+        let calldata = serialize_arguments(
+            r#"0x123, u256:1000, 0x456, true, -1, "name""#,
+            &parameters,
+            &json!([]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            calldata,
+            vec![
+                FieldElement::from(0x123_u32),
+                FieldElement::from(1000_u32),
+                FieldElement::ZERO,
+                FieldElement::from(0x456_u32),
+                FieldElement::ONE,
+                FieldElement::ZERO - FieldElement::ONE,
+                cairo_short_string_to_felt("name").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_byte_array() {
+        let calldata = serialize_arguments(
+            r#""token name""#,
+            &parameters(&["core::byte_array::ByteArray"]),
+            &json!([]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            calldata,
+            vec![
+                FieldElement::ZERO,
+                cairo_short_string_to_felt("token name").unwrap(),
+                FieldElement::from(10_u8),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        let parameters = parameters(&["core::integer::u8"]);
+
+        assert!(serialize_arguments("256", &parameters, &json!([])).is_err());
+        assert!(serialize_arguments("u16:1", &parameters, &json!([])).is_err());
+        assert!(serialize_arguments("1, 2", &parameters, &json!([])).is_err());
+        assert!(serialize_arguments(r#""unterminated"#, &parameters, &json!([])).is_err());
+    }
+
+    fn abi_with_types() -> Value {
+        json!([
+            {
+                "type": "struct",
+                "name": "types::Point",
+                "members": [
+                    {"name": "x", "type": "core::felt252"},
+                    {"name": "y", "type": "core::integer::u256"}
+                ]
+            },
+            {
+                "type": "enum",
+                "name": "types::Direction",
+                "variants": [
+                    {"name": "North", "type": "()"},
+                    {"name": "East", "type": "core::integer::u8"}
+                ]
+            },
+            {
+                "type": "enum",
+                "name": "core::option::Option::<core::integer::u32>",
+                "variants": [
+                    {"name": "Some", "type": "core::integer::u32"},
+                    {"name": "None", "type": "()"}
+                ]
+            }
+        ])
+    }
+
+    #[test]
+    fn serializes_compound_arguments() {
+        let parameters = parameters(&[
+            "types::Point",
+            "core::array::Array::<core::felt252>",
+            "(core::felt252, core::bool)",
+            "types::Direction",
+            "types::Direction",
+            "core::option::Option::<core::integer::u32>",
+            "core::option::Option::<core::integer::u32>",
+        ]);
+        let calldata = serialize_arguments(
+            "Point { y: 2, x: 1 }, array![1, 2], (3, true), Direction::East(5), North, Some(7), None",
+            &parameters,
+            &abi_with_types(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            calldata,
+            [1_u8, 2, 0, 2, 1, 2, 3, 1, 1, 5, 0, 0, 7, 1]
+                .into_iter()
+                .map(FieldElement::from)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn accepts_qualified_struct_names() {
+        let calldata = serialize_arguments(
+            "types::Point { x: 1, y: 2 }",
+            &parameters(&["types::Point"]),
+            &abi_with_types(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            calldata,
+            [1_u8, 2, 0]
+                .into_iter()
+                .map(FieldElement::from)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_compound_arguments() {
+        let abi = abi_with_types();
+
+        for (arguments, ty) in [
+            ("Point { x: 1 }", "types::Point"),
+            ("Point { x: 1, y: 2, z: 3 }", "types::Point"),
+            ("other::Line { x: 1, y: 2 }", "types::Point"),
+            ("West", "types::Direction"),
+            ("Point::North", "types::Direction"),
+            ("East", "types::Direction"),
+            ("(1, 2)", "(core::felt252,)"),
+            ("[1, 2", "core::array::Array::<core::felt252>"),
+        ] {
+            assert!(
+                serialize_arguments(arguments, &parameters(&[ty]), &abi).is_err(),
+                "{arguments} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn finds_function_parameters() {
+        let abi = json!([
+            {
+                "type": "interface",
+                "name": "map::IMap",
+                "items": [{
+                    "type": "function",
+                    "name": "put",
+                    "inputs": [{"name": "key", "type": "core::felt252"}],
+                    "outputs": [],
+                    "state_mutability": "external"
+                }]
+            }
+        ]);
+
+        assert_eq!(
+            function_parameters(&abi, "put").unwrap(),
+            vec![AbiParameter {
+                name: "key".to_string(),
+                ty: "core::felt252".to_string(),
+            }]
+        );
+        assert!(function_parameters(&abi, "get").is_err());
+    }
+
+    #[test]
+    fn finds_constructor_parameters() {
+        let abi = json!([
+            {"type": "function", "name": "get", "inputs": [], "outputs": [], "state_mutability": "view"},
+            {
+                "type": "constructor",
+                "name": "constructor",
+                "inputs": [{"name": "first", "type": "core::felt252"}]
+            }
+        ]);
+
+        assert_eq!(
+            constructor_parameters(&abi).unwrap(),
+            parameters(&["core::felt252"])
+                .into_iter()
+                .map(|parameter| AbiParameter {
+                    name: "first".to_string(),
+                    ..parameter
+                })
+                .collect::<Vec<_>>()
+        );
+        assert!(constructor_parameters(&json!([])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn detects_account_abi() {
+        let function = |name: &str| json!({"type": "function", "name": name, "inputs": [], "outputs": [], "state_mutability": "external"});
+        let account_abi = json!([
+            {
+                "type": "interface",
+                "name": "account::ISRC6",
+                "items": [function("__execute__"), function("__validate__")]
+            }
+        ]);
+
+        assert!(is_account_abi(&account_abi));
+        assert!(!is_account_abi(&json!([function("__execute__")])));
+        assert!(!is_account_abi(&json!([function("put")])));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::helpers::external_signer::TransactionDetails;
+use crate::helpers::signer::TransactionSigner;
+use async_trait::async_trait;
+use starknet::accounts::{AccountFactory, PreparedAccountDeployment, RawAccountDeployment};
+use starknet::core::types::{BlockId, BlockTag, FieldElement};
+use starknet::providers::Provider;
+use starknet::signers::Signer;
+use starknet_crypto::poseidon_hash_many;
+
+/// Factory of Braavos accounts
+///
+/// Braavos accounts are deployed as the base account class, which replaces itself
+/// with the implementation class passed in the auxiliary data of the deployment signature
+pub struct BraavosAccountFactory<S, P> {
+    class_hash: FieldElement,
+    base_class_hash: FieldElement,
+    chain_id: FieldElement,
+    signer_public_key: FieldElement,
+    signer: S,
+    provider: P,
+    block_id: BlockId,
+}
+
+impl<S, P> BraavosAccountFactory<S, P>
+where
+    S: Signer,
+{
+    pub async fn new(
+        class_hash: FieldElement,
+        base_class_hash: FieldElement,
+        chain_id: FieldElement,
+        signer: S,
+        provider: P,
+    ) -> Result<Self, S::GetPublicKeyError> {
+        let signer_public_key = signer.get_public_key().await?;
+
+        Ok(Self {
+            class_hash,
+            base_class_hash,
+            chain_id,
+            signer_public_key: signer_public_key.scalar(),
+            signer,
+            provider,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        })
+    }
+}
+
+#[async_trait]
+impl<S, P> AccountFactory for BraavosAccountFactory<S, P>
+where
+    S: TransactionSigner + Sync + Send,
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+    type SignError = S::SignError;
+
+    fn class_hash(&self) -> FieldElement {
+        self.base_class_hash
+    }
+
+    fn calldata(&self) -> Vec<FieldElement> {
+        vec![self.signer_public_key]
+    }
+
+    fn chain_id(&self) -> FieldElement {
+        self.chain_id
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn sign_deployment(
+        &self,
+        deployment: &RawAccountDeployment,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash =
+            PreparedAccountDeployment::from_raw(deployment.clone(), self).transaction_hash();
+        let transaction = TransactionDetails::deploy_account(self, deployment);
+        let signature = self.signer.sign_transaction(&tx_hash, &transaction).await?;
+
+        // Implementation class hash, 9 zeroed hardware signer and multisig fields, chain id
+        let mut aux_data = vec![self.class_hash];
+        aux_data.extend([FieldElement::ZERO; 9]);
+        aux_data.push(self.chain_id);
+
+        let aux_hash = poseidon_hash_many(&aux_data);
+        let aux_signature = self.signer.sign_hash(&aux_hash).await?;
+
+        Ok([
+            vec![signature.r, signature.s],
+            aux_data,
+            vec![aux_signature.r, aux_signature.s],
+        ]
+        .concat())
+    }
+}
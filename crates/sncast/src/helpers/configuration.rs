@@ -1,5 +1,5 @@
-use crate::ValidatedWaitParams;
-use anyhow::Result;
+use crate::{get_block_id, ValidatedWaitParams};
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use configuration::GlobalConfig;
 use serde::{Deserialize, Serialize};
@@ -26,6 +26,13 @@ pub struct CastConfig {
         rename(serialize = "wait-params", deserialize = "wait-params")
     )]
     pub wait_params: ValidatedWaitParams,
+
+    #[serde(
+        default,
+        rename(serialize = "default-block-id", deserialize = "default-block-id")
+    )]
+    /// Block identifier used by read commands when none is passed explicitly
+    pub default_block_id: Option<String>,
 }
 
 impl GlobalConfig for CastConfig {
@@ -35,6 +42,11 @@ impl GlobalConfig for CastConfig {
     }
 
     fn from_raw(config: serde_json::Value) -> Result<Self> {
-        Ok(serde_json::from_value::<CastConfig>(config)?)
+        let config = serde_json::from_value::<CastConfig>(config)?;
+        if let Some(block_id) = &config.default_block_id {
+            get_block_id(block_id)
+                .context("Invalid value of `default-block-id` in snfoundry.toml")?;
+        }
+        Ok(config)
     }
 }
@@ -0,0 +1,221 @@
+use crate::helpers::token::u256_from_felts;
+use serde_json::Value;
+use starknet::core::types::{Event, FieldElement};
+use starknet::core::utils::get_selector_from_name;
+
+/// Event declared in a contract ABI, matched with emitted events by its selector
+#[derive(Debug, PartialEq)]
+pub struct EventAbi {
+    pub selector: FieldElement,
+    pub name: String,
+    pub members: Vec<EventMember>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventMember {
+    pub name: String,
+    pub ty: String,
+    pub is_key: bool,
+}
+
+/// Extracts events from the JSON ABI of a Sierra or a legacy contract
+#[must_use]
+pub fn events_from_abi(abi: &Value) -> Vec<EventAbi> {
+    let entries: Vec<&Value> = abi
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry["type"] == "event")
+        .collect();
+
+    let mut events = vec![];
+    for entry in &entries {
+        let Some(name) = entry["name"].as_str() else {
+            continue;
+        };
+        match entry["kind"].as_str() {
+            // Cairo 1 events are emitted with the name of the variant of the event enum
+            Some("struct") => {
+                let members = members_of(&entry["members"], None);
+                let mut variant_names: Vec<&str> = entries
+                    .iter()
+                    .filter(|entry| entry["kind"] == "enum")
+                    .filter_map(|entry| entry["variants"].as_array())
+                    .flatten()
+                    .filter(|variant| variant["type"] == name && variant["kind"] == "nested")
+                    .filter_map(|variant| variant["name"].as_str())
+                    .collect();
+                if variant_names.is_empty() {
+                    variant_names.push(name.rsplit("::").next().unwrap_or(name));
+                }
+                for variant_name in variant_names {
+                    if let Some(event) = event_abi(variant_name, &members) {
+                        events.push(event);
+                    }
+                }
+            }
+            Some(_) => {}
+            None => {
+                let mut members = members_of(&entry["keys"], Some(true));
+                members.extend(members_of(&entry["data"], Some(false)));
+                if let Some(event) = event_abi(name, &members) {
+                    events.push(event);
+                }
+            }
+        }
+    }
+    events
+}
+
+fn members_of(members: &Value, is_key: Option<bool>) -> Vec<EventMember> {
+    members
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|member| {
+            Some(EventMember {
+                name: member["name"].as_str()?.to_string(),
+                ty: member["type"].as_str()?.to_string(),
+                is_key: is_key.unwrap_or(member["kind"] == "key"),
+            })
+        })
+        .collect()
+}
+
+fn event_abi(name: &str, members: &[EventMember]) -> Option<EventAbi> {
+    Some(EventAbi {
+        selector: get_selector_from_name(name).ok()?,
+        name: name.to_string(),
+        members: members.to_vec(),
+    })
+}
+
+/// Decodes an emitted event as `Name(member: value, ...)`, returns `None` if the event
+/// is not in the ABI or has members of types which can't be decoded
+#[must_use]
+pub fn decode_event(event: &Event, abi: &[EventAbi]) -> Option<String> {
+    let (selector, keys) = event.keys.split_first()?;
+    let event_abi = abi
+        .iter()
+        .find(|event_abi| event_abi.selector == *selector)?;
+
+    let mut keys = keys.iter();
+    let mut data = event.data.iter();
+    let mut members = vec![];
+    for member in &event_abi.members {
+        let values = if member.is_key { &mut keys } else { &mut data };
+        let value = decode_value(&member.ty, values)?;
+        members.push(format!("{}: {value}", member.name));
+    }
+    if keys.next().is_some() || data.next().is_some() {
+        return None;
+    }
+
+    Some(format!("{}({})", event_abi.name, members.join(", ")))
+}
+
+fn decode_value<'a>(
+    ty: &str,
+    values: &mut impl Iterator<Item = &'a FieldElement>,
+) -> Option<String> {
+    match ty.rsplit("::").next().unwrap_or(ty) {
+        "u256" | "Uint256" => {
+            let low = *values.next()?;
+            let high = *values.next()?;
+            u256_from_felts(low, high)
+                .ok()
+                .map(|value| value.to_string())
+        }
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => values.next().map(ToString::to_string),
+        "bool" => match values.next()? {
+            value if *value == FieldElement::ZERO => Some("false".to_string()),
+            value if *value == FieldElement::ONE => Some("true".to_string()),
+            _ => None,
+        },
+        "felt252" | "felt" | "ContractAddress" | "ClassHash" | "EthAddress" => {
+            values.next().map(|value| format!("{value:#x}"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn transfer_event(keys: &[u32], data: &[u32]) -> Event {
+        Event {
+            from_address: FieldElement::ONE,
+            keys: std::iter::once(get_selector_from_name("Transfer").unwrap())
+                .chain(keys.iter().map(|key| FieldElement::from(*key)))
+                .collect(),
+            data: data
+                .iter()
+                .map(|value| FieldElement::from(*value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn decodes_sierra_event() {
+        let abi = json!([
+            {
+                "type": "event",
+                "name": "token::erc20::Transfer",
+                "kind": "struct",
+                "members": [
+                    {"name": "from", "type": "core::starknet::contract_address::ContractAddress", "kind": "key"},
+                    {"name": "to", "type": "core::starknet::contract_address::ContractAddress", "kind": "data"},
+                    {"name": "value", "type": "core::integer::u256", "kind": "data"}
+                ]
+            },
+            {
+                "type": "event",
+                "name": "token::erc20::Event",
+                "kind": "enum",
+                "variants": [{"name": "Transfer", "type": "token::erc20::Transfer", "kind": "nested"}]
+            }
+        ]);
+        let abi = events_from_abi(&abi);
+
+        assert_eq!(abi.len(), 1);
+        assert_eq!(
+            decode_event(&transfer_event(&[0x12], &[0x34, 100, 0]), &abi).unwrap(),
+            "Transfer(from: 0x12, to: 0x34, value: 100)"
+        );
+    }
+
+    #[test]
+    fn decodes_legacy_event() {
+        let abi = json!([
+            {
+                "type": "event",
+                "name": "Transfer",
+                "keys": [],
+                "data": [
+                    {"name": "from_", "type": "felt"},
+                    {"name": "to", "type": "felt"},
+                    {"name": "value", "type": "Uint256"}
+                ]
+            }
+        ]);
+        let abi = events_from_abi(&abi);
+
+        assert_eq!(
+            decode_event(&transfer_event(&[], &[0x12, 0x34, 100, 0]), &abi).unwrap(),
+            "Transfer(from_: 0x12, to: 0x34, value: 100)"
+        );
+    }
+
+    #[test]
+    fn does_not_decode_unknown_event() {
+        let abi = events_from_abi(&json!([]));
+        assert!(decode_event(&transfer_event(&[], &[]), &abi).is_none());
+
+        let abi = events_from_abi(&json!([
+            {"type": "event", "name": "Transfer", "keys": [], "data": [{"name": "to", "type": "felt"}]}
+        ]));
+        assert!(decode_event(&transfer_event(&[], &[0x12, 0x34]), &abi).is_none());
+    }
+}
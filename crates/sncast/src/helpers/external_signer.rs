@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use starknet::accounts::{AccountFactory, PreparedAccountDeployment, RawAccountDeployment};
+use starknet::core::crypto::Signature;
+use starknet::core::types::FieldElement;
+use starknet::signers::{Signer, VerifyingKey};
+use std::process::{ExitStatus, Stdio};
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+#[derive(Debug, Error)]
+pub enum ExternalSignerError {
+    #[error("Failed to run signer command `{command}`: {source}")]
+    Io {
+        command: String,
+        source: std::io::Error,
+    },
+    #[error("Signer command `{command}` failed with {status}: {stderr}")]
+    Failed {
+        command: String,
+        status: ExitStatus,
+        stderr: String,
+    },
+    #[error("Invalid response of signer command `{command}`: {reason}")]
+    InvalidResponse { command: String, reason: String },
+    #[error("Signer command `{command}` returned an error: {message}")]
+    Signer { command: String, message: String },
+}
+
+/// Request written as a single JSON line to stdin of the signer command
+#[derive(Serialize, Debug)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum ExternalSignerRequest<'a> {
+    GetPublicKey,
+    SignHash {
+        hash: String,
+    },
+    SignTransaction {
+        hash: String,
+        transaction: &'a TransactionDetails,
+    },
+}
+
+/// Fields of a signed transaction, from which the signer command can recompute its hash
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransactionDetails {
+    Invoke {
+        sender_address: FieldElement,
+        calldata: Vec<FieldElement>,
+        max_fee: FieldElement,
+        nonce: FieldElement,
+        chain_id: FieldElement,
+        query_only: bool,
+    },
+    Declare {
+        sender_address: FieldElement,
+        class_hash: FieldElement,
+        compiled_class_hash: FieldElement,
+        max_fee: FieldElement,
+        nonce: FieldElement,
+        chain_id: FieldElement,
+        query_only: bool,
+    },
+    DeployAccount {
+        contract_address: FieldElement,
+        class_hash: FieldElement,
+        contract_address_salt: FieldElement,
+        constructor_calldata: Vec<FieldElement>,
+        max_fee: FieldElement,
+        nonce: FieldElement,
+        chain_id: FieldElement,
+    },
+}
+
+impl TransactionDetails {
+    #[must_use]
+    pub fn deploy_account<F>(factory: &F, deployment: &RawAccountDeployment) -> Self
+    where
+        F: AccountFactory,
+    {
+        let prepared = PreparedAccountDeployment::from_raw(deployment.clone(), factory);
+        TransactionDetails::DeployAccount {
+            contract_address: prepared.address(),
+            class_hash: factory.class_hash(),
+            contract_address_salt: deployment.salt(),
+            constructor_calldata: factory.calldata(),
+            max_fee: deployment.max_fee(),
+            nonce: deployment.nonce(),
+            chain_id: factory.chain_id(),
+        }
+    }
+}
+
+/// Response read from stdout of the signer command
+#[derive(Deserialize, Debug)]
+struct ExternalSignerResponse {
+    public_key: Option<FieldElement>,
+    signature: Option<Vec<FieldElement>>,
+    error: Option<String>,
+}
+
+/// Signer delegating to an external command, which keeps the private key
+///
+/// The command is run through the shell once per request. It receives
+/// `{"method": "get_public_key"}`, `{"method": "sign_transaction", "hash": "0x...", "transaction": {...}}`
+/// or, for hashes which are not transactions, `{"method": "sign_hash", "hash": "0x..."}` on stdin
+/// and must print `{"public_key": "0x..."}`, `{"signature": ["0x<r>", "0x<s>"]}`
+/// or `{"error": "<message>"}` to stdout
+#[derive(Debug)]
+pub struct ExternalSigner {
+    command: String,
+}
+
+impl ExternalSigner {
+    #[must_use]
+    pub fn new(command: String) -> Self {
+        Self { command }
+    }
+
+    /// Signs the hash of `transaction`, passing its fields to the command
+    pub async fn sign_transaction(
+        &self,
+        hash: &FieldElement,
+        transaction: &TransactionDetails,
+    ) -> Result<Signature, ExternalSignerError> {
+        let response = self
+            .request(&ExternalSignerRequest::SignTransaction {
+                hash: format!("{hash:#x}"),
+                transaction,
+            })
+            .await?;
+
+        self.read_signature(&response)
+    }
+
+    async fn request(
+        &self,
+        request: &ExternalSignerRequest<'_>,
+    ) -> Result<ExternalSignerResponse, ExternalSignerError> {
+        let io_error = |source| ExternalSignerError::Io {
+            command: self.command.clone(),
+            source,
+        };
+
+        let mut shell = if cfg!(windows) {
+            let mut shell = Command::new("cmd");
+            shell.arg("/C");
+            shell
+        } else {
+            let mut shell = Command::new("sh");
+            shell.arg("-c");
+            shell
+        };
+        let mut child = shell
+            .arg(&self.command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(io_error)?;
+
+        let mut request = serde_json::to_string(request).expect("Failed to serialize request");
+        request.push('\n');
+        if let Some(mut stdin) = child.stdin.take() {
+            // The command may exit without reading the request, its output decides the result
+            let _ = stdin.write_all(request.as_bytes()).await;
+        }
+
+        let output = child.wait_with_output().await.map_err(io_error)?;
+        if !output.status.success() {
+            return Err(ExternalSignerError::Failed {
+                command: self.command.clone(),
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        let response: ExternalSignerResponse = serde_json::from_slice(&output.stdout)
+            .map_err(|error| self.invalid_response(&error.to_string()))?;
+        if let Some(message) = response.error {
+            return Err(ExternalSignerError::Signer {
+                command: self.command.clone(),
+                message,
+            });
+        }
+        Ok(response)
+    }
+
+    fn read_signature(
+        &self,
+        response: &ExternalSignerResponse,
+    ) -> Result<Signature, ExternalSignerError> {
+        match response.signature.as_deref() {
+            Some([r, s]) => Ok(Signature { r: *r, s: *s }),
+            Some(_) => Err(self.invalid_response("`signature` must consist of r and s")),
+            None => Err(self.invalid_response("missing `signature`")),
+        }
+    }
+
+    fn invalid_response(&self, reason: &str) -> ExternalSignerError {
+        ExternalSignerError::InvalidResponse {
+            command: self.command.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for ExternalSigner {
+    type GetPublicKeyError = ExternalSignerError;
+    type SignError = ExternalSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        let response = self.request(&ExternalSignerRequest::GetPublicKey).await?;
+        let public_key = response
+            .public_key
+            .ok_or_else(|| self.invalid_response("missing `public_key`"))?;
+
+        Ok(VerifyingKey::from_scalar(public_key))
+    }
+
+    async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Self::SignError> {
+        let response = self
+            .request(&ExternalSignerRequest::SignHash {
+                hash: format!("{hash:#x}"),
+            })
+            .await?;
+
+        self.read_signature(&response)
+    }
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_public_key() {
+        let signer = ExternalSigner::new(r#"echo '{"public_key": "0x123"}'"#.to_string());
+
+        let public_key = signer.get_public_key().await.unwrap();
+        assert_eq!(public_key.scalar(), FieldElement::from(0x123_u32));
+    }
+
+    #[tokio::test]
+    async fn passes_hash_to_command() {
+        let command = r#"read request; hash=$(echo "$request" | sed 's/.*"hash":"\([^"]*\)".*/\1/'); echo "{\"signature\": [\"$hash\", \"0x2\"]}""#;
+        let signer = ExternalSigner::new(command.to_string());
+
+        let signature = signer
+            .sign_hash(&FieldElement::from(0xabc_u32))
+            .await
+            .unwrap();
+        assert_eq!(signature.r, FieldElement::from(0xabc_u32));
+        assert_eq!(signature.s, FieldElement::TWO);
+    }
+
+    #[tokio::test]
+    async fn passes_transaction_to_command() {
+        let command = r#"read request; case "$request" in *'"method":"sign_transaction"'*'"type":"invoke"'*'"calldata":["0x1","0x2"]'*'"max_fee":"0x3"'*'"nonce":"0x4"'*'"chain_id":"0x5"'*) echo '{"signature": ["0x1", "0x2"]}';; *) echo '{"error": "unexpected request"}';; esac"#;
+        let signer = ExternalSigner::new(command.to_string());
+
+        let transaction = TransactionDetails::Invoke {
+            sender_address: FieldElement::ONE,
+            calldata: vec![FieldElement::ONE, FieldElement::TWO],
+            max_fee: FieldElement::THREE,
+            nonce: FieldElement::from(4_u32),
+            chain_id: FieldElement::from(5_u32),
+            query_only: false,
+        };
+        let signature = signer
+            .sign_transaction(&FieldElement::from(0xabc_u32), &transaction)
+            .await
+            .unwrap();
+        assert_eq!(signature.r, FieldElement::ONE);
+        assert_eq!(signature.s, FieldElement::TWO);
+    }
+
+    #[tokio::test]
+    async fn surfaces_signer_errors() {
+        let signer = ExternalSigner::new(r#"echo '{"error": "rejected"}'"#.to_string());
+        let error = signer.sign_hash(&FieldElement::ONE).await.unwrap_err();
+        assert!(error.to_string().ends_with("returned an error: rejected"));
+
+        let signer = ExternalSigner::new("echo denied >&2; exit 1".to_string());
+        let error = signer.sign_hash(&FieldElement::ONE).await.unwrap_err();
+        assert!(error.to_string().ends_with("denied"));
+
+        let signer = ExternalSigner::new(r#"echo '{"signature": ["0x1"]}'"#.to_string());
+        let error = signer.sign_hash(&FieldElement::ONE).await.unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("`signature` must consist of r and s"));
+    }
+}
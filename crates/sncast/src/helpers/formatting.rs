@@ -0,0 +1,8 @@
+use starknet::core::types::FieldElement;
+
+/// Formats felts as a list of hex values, e.g. `[0x1, 0x2]`
+#[must_use]
+pub fn format_felts(felts: &[FieldElement]) -> String {
+    let felts: Vec<String> = felts.iter().map(|felt| format!("{felt:#x}")).collect();
+    format!("[{}]", felts.join(", "))
+}
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+#[cfg(feature = "ledger")]
+use coins_ledger::common::{APDUCommand, APDUData};
+#[cfg(feature = "ledger")]
+use coins_ledger::transports::{Ledger, LedgerAsync};
+use starknet::core::crypto::Signature;
+use starknet::core::types::FieldElement;
+use starknet::signers::{Signer, VerifyingKey};
+use thiserror::Error;
+
+#[cfg(feature = "ledger")]
+const CLA_STARKNET: u8 = 0x5a;
+const INS_GET_PUBLIC_KEY: u8 = 0x01;
+const INS_SIGN_HASH: u8 = 0x02;
+
+const EIP_2645_PURPOSE: u32 = 2645;
+const EIP_2645_PATH_LENGTH: usize = 6;
+const HARDENED_BIT: u32 = 0x8000_0000;
+
+#[cfg(feature = "ledger")]
+const RETURN_CODE_OK: u16 = 0x9000;
+const PUBLIC_KEY_SIZE: usize = 65;
+const SIGNATURE_SIZE: usize = 65;
+
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[cfg(feature = "ledger")]
+    #[error("Failed to communicate with Ledger: {0}")]
+    Transport(#[from] coins_ledger::LedgerError),
+    #[error("Invalid Ledger derivation path = {0}. Expected an EIP-2645 path with numeric segments, e.g. m/2645'/1195502025'/1470455285'/0'/0'/0")]
+    InvalidDerivationPath(String),
+    #[error("Ledger returned error code {0:#06x}, make sure the device is unlocked and the Starknet app is open")]
+    ErrorCode(u16),
+    #[error("Unexpected response from Ledger")]
+    UnexpectedResponse,
+    #[cfg(not(feature = "ledger"))]
+    #[error(
+        "sncast was built without Ledger support, reinstall it with the `ledger` feature enabled"
+    )]
+    Unsupported,
+}
+
+/// Signer backed by the Starknet app of a Ledger device
+pub struct LedgerSigner {
+    #[cfg(feature = "ledger")]
+    transport: Ledger,
+    derivation_path: Vec<u32>,
+}
+
+impl LedgerSigner {
+    /// Connects to the first Ledger device found, keys are derived with the EIP-2645 `derivation_path`
+    #[cfg(feature = "ledger")]
+    pub async fn new(derivation_path: &str) -> Result<Self, LedgerError> {
+        let derivation_path = parse_derivation_path(derivation_path)?;
+        let transport = Ledger::init().await?;
+
+        Ok(Self {
+            transport,
+            derivation_path,
+        })
+    }
+
+    #[cfg(not(feature = "ledger"))]
+    pub async fn new(derivation_path: &str) -> Result<Self, LedgerError> {
+        parse_derivation_path(derivation_path)?;
+        Err(LedgerError::Unsupported)
+    }
+
+    #[cfg(feature = "ledger")]
+    async fn exchange(&self, ins: u8, p1: u8, data: Vec<u8>) -> Result<Vec<u8>, LedgerError> {
+        let command = APDUCommand {
+            cla: CLA_STARKNET,
+            ins,
+            p1,
+            p2: 0x00,
+            data: APDUData::new(&data),
+            response_len: None,
+        };
+        let answer = self.transport.exchange(&command).await?;
+
+        if answer.retcode() != RETURN_CODE_OK {
+            return Err(LedgerError::ErrorCode(answer.retcode()));
+        }
+        Ok(answer.data().unwrap_or_default().to_vec())
+    }
+
+    #[cfg(not(feature = "ledger"))]
+    #[allow(clippy::unused_async)]
+    async fn exchange(&self, _ins: u8, _p1: u8, _data: Vec<u8>) -> Result<Vec<u8>, LedgerError> {
+        Err(LedgerError::Unsupported)
+    }
+
+    fn serialized_derivation_path(&self) -> Vec<u8> {
+        self.derivation_path
+            .iter()
+            .flat_map(|segment| segment.to_be_bytes())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Signer for LedgerSigner {
+    type GetPublicKeyError = LedgerError;
+    type SignError = LedgerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        let response = self
+            .exchange(INS_GET_PUBLIC_KEY, 0x00, self.serialized_derivation_path())
+            .await?;
+
+        // Uncompressed point, prefixed with 0x04
+        if response.len() != PUBLIC_KEY_SIZE || response[0] != 0x04 {
+            return Err(LedgerError::UnexpectedResponse);
+        }
+        let public_key = FieldElement::from_byte_slice_be(&response[1..33])
+            .map_err(|_| LedgerError::UnexpectedResponse)?;
+
+        Ok(VerifyingKey::from_scalar(public_key))
+    }
+
+    async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Self::SignError> {
+        self.exchange(INS_SIGN_HASH, 0x00, self.serialized_derivation_path())
+            .await?;
+        let response = self
+            .exchange(INS_SIGN_HASH, 0x01, shift_hash(hash).to_vec())
+            .await?;
+
+        // Signature length, r, s and v
+        if response.len() != SIGNATURE_SIZE + 1 || usize::from(response[0]) != SIGNATURE_SIZE {
+            return Err(LedgerError::UnexpectedResponse);
+        }
+        let r = FieldElement::from_byte_slice_be(&response[1..33])
+            .map_err(|_| LedgerError::UnexpectedResponse)?;
+        let s = FieldElement::from_byte_slice_be(&response[33..65])
+            .map_err(|_| LedgerError::UnexpectedResponse)?;
+
+        Ok(Signature { r, s })
+    }
+}
+
+/// Parses EIP-2645 derivation path, e.g. `m/2645'/1195502025'/1470455285'/0'/0'/0`
+pub fn parse_derivation_path(path: &str) -> Result<Vec<u32>, LedgerError> {
+    let invalid_path = || LedgerError::InvalidDerivationPath(path.to_string());
+
+    let mut segments = path.split('/');
+    if segments.next() != Some("m") {
+        return Err(invalid_path());
+    }
+
+    let derivation_path = segments
+        .map(|segment| {
+            let (index, hardened) = match segment.strip_suffix('\'') {
+                Some(index) => (index, true),
+                None => (segment, false),
+            };
+            let index: u32 = index.parse().map_err(|_| invalid_path())?;
+            if index >= HARDENED_BIT {
+                return Err(invalid_path());
+            }
+            Ok(if hardened {
+                index | HARDENED_BIT
+            } else {
+                index
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if derivation_path.len() != EIP_2645_PATH_LENGTH
+        || derivation_path[0] != EIP_2645_PURPOSE | HARDENED_BIT
+    {
+        return Err(invalid_path());
+    }
+    Ok(derivation_path)
+}
+
+/// The Starknet app expects the 252-bit hash shifted left by 4 bits to fill 32 bytes
+fn shift_hash(hash: &FieldElement) -> [u8; 32] {
+    let bytes = hash.to_bytes_be();
+    let mut shifted = [0_u8; 32];
+    for (i, byte) in shifted.iter_mut().enumerate() {
+        let next = bytes.get(i + 1).copied().unwrap_or(0);
+        *byte = (bytes[i] << 4) | (next >> 4);
+    }
+    shifted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_derivation_path() {
+        assert_eq!(
+            parse_derivation_path("m/2645'/1195502025'/1470455285'/0'/0'/0").unwrap(),
+            vec![
+                2645 | HARDENED_BIT,
+                1_195_502_025 | HARDENED_BIT,
+                1_470_455_285 | HARDENED_BIT,
+                HARDENED_BIT,
+                HARDENED_BIT,
+                0
+            ]
+        );
+        assert!(parse_derivation_path("m/44'/60'/0'/0/0").is_err());
+        assert!(parse_derivation_path("2645'/1'/1'/0'/0'/0").is_err());
+        assert!(parse_derivation_path("m/2645'/starknet'/1'/0'/0'/0").is_err());
+    }
+
+    #[test]
+    fn shifts_hash() {
+        let hash = FieldElement::from_hex_be("0x123").unwrap();
+        let shifted = shift_hash(&hash);
+
+        assert_eq!(shifted[30..], [0x12, 0x30]);
+        assert!(shifted[..30].iter().all(|byte| *byte == 0));
+    }
+}
@@ -1,3 +1,16 @@
+pub mod account;
+pub mod argent;
+pub mod arguments;
+pub mod braavos;
 pub mod configuration;
 pub mod constants;
+pub mod events;
+pub mod external_signer;
+pub mod formatting;
+pub mod ledger;
+pub mod nonce_manager;
+pub mod open_zeppelin;
 pub mod scarb_utils;
+pub mod script_lock;
+pub mod signer;
+pub mod token;
@@ -0,0 +1,199 @@
+use crate::handle_rpc_error;
+use crate::helpers::account::CastAccount;
+use anyhow::{Context, Result};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use starknet::accounts::{Account, AccountError, ConnectedAccount};
+use starknet::core::types::{FieldElement, StarknetError::InvalidTransactionNonce};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::providers::ProviderError::StarknetError;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// For how many seconds a tracked nonce is preferred over the pending nonce from the node,
+/// so a transaction dropped by the node doesn't leave a gap in nonces of the account
+const TRACKED_NONCE_TTL: u64 = 60;
+
+#[derive(Deserialize, Serialize)]
+struct TrackedNonce {
+    next_nonce: FieldElement,
+    updated_at: u64,
+}
+
+/// Tracker of the nonces used by an account on a network, which allocates sequential nonces
+/// to transactions sent one after another, before the node includes them in the pending block.
+/// Other sncast processes wait for the tracker of the same account until it is dropped.
+pub struct NonceManager {
+    file: File,
+}
+
+impl NonceManager {
+    pub fn lock(chain_id: FieldElement, account_address: FieldElement) -> Result<Self> {
+        let path = nonce_tracker_path(chain_id, account_address)?;
+        fs::create_dir_all(path.parent().expect("Nonce tracker path has no parent"))?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .with_context(|| format!("Failed to open nonce tracker = {}", path.display()))?;
+        file.lock_exclusive()
+            .with_context(|| format!("Failed to lock nonce tracker = {}", path.display()))?;
+
+        Ok(Self { file })
+    }
+
+    /// Nonce of the next transaction, the tracked one if it is ahead of `pending_nonce`
+    pub fn next_nonce(&mut self, pending_nonce: FieldElement) -> Result<FieldElement> {
+        Ok(match self.tracked_nonce()? {
+            Some(tracked_nonce) if tracked_nonce > pending_nonce => tracked_nonce,
+            _ => pending_nonce,
+        })
+    }
+
+    /// Updates the tracker after a transaction with `nonce` was sent, forgetting the tracked
+    /// nonce if the node rejected it
+    pub fn record_sent<T, S>(
+        &mut self,
+        nonce: FieldElement,
+        result: &Result<T, AccountError<S>>,
+    ) -> Result<()> {
+        match result {
+            Ok(_) => {
+                let sent_next_nonce = nonce + FieldElement::ONE;
+                let next_nonce = match self.tracked_nonce()? {
+                    Some(tracked_nonce) if tracked_nonce > sent_next_nonce => tracked_nonce,
+                    _ => sent_next_nonce,
+                };
+                self.write(Some(&TrackedNonce {
+                    next_nonce,
+                    updated_at: now(),
+                }))
+            }
+            Err(AccountError::Provider(StarknetError(InvalidTransactionNonce))) => self.write(None),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn tracked_nonce(&mut self) -> Result<Option<FieldElement>> {
+        let mut content = String::new();
+        self.file.rewind()?;
+        self.file.read_to_string(&mut content)?;
+
+        // An unreadable tracker is treated as empty, the pending nonce is used instead
+        Ok(serde_json::from_str::<TrackedNonce>(&content)
+            .ok()
+            .filter(|tracked| now().saturating_sub(tracked.updated_at) <= TRACKED_NONCE_TTL)
+            .map(|tracked| tracked.next_nonce))
+    }
+
+    fn write(&mut self, tracked: Option<&TrackedNonce>) -> Result<()> {
+        self.file.set_len(0)?;
+        self.file.rewind()?;
+        if let Some(tracked) = tracked {
+            self.file
+                .write_all(serde_json::to_string(tracked)?.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Locks the nonce tracker of the account and picks the nonce of its next transaction,
+/// which is `nonce` if passed explicitly
+pub async fn allocate_nonce(
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<(NonceManager, FieldElement)> {
+    let mut nonce_manager = NonceManager::lock(account.chain_id(), account.address())?;
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => {
+            let pending_nonce = account.get_nonce().await.map_err(handle_rpc_error)?;
+            nonce_manager.next_nonce(pending_nonce)?
+        }
+    };
+    Ok((nonce_manager, nonce))
+}
+
+/// Trackers are kept in the cache directory of the user, so they outlive the system temporary
+/// directory cleanups between commands
+pub fn nonce_tracker_path(
+    chain_id: FieldElement,
+    account_address: FieldElement,
+) -> Result<PathBuf> {
+    let cache_dir = dirs::cache_dir().context("Failed to find the cache directory of the user")?;
+    Ok(cache_dir
+        .join("sncast")
+        .join("nonces")
+        .join(format!("{chain_id:#x}_{account_address:#x}.json")))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is before the UNIX epoch")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::providers::ProviderError;
+
+    const SENT: Result<(), AccountError<()>> = Ok(());
+
+    #[test]
+    fn test_allocates_sequential_nonces() {
+        let chain_id = FieldElement::from_hex_be("0x6e6f6e63655f74657374").unwrap();
+        let account_address = FieldElement::from_hex_be("0x6e6f6e6365").unwrap();
+        let _ = fs::remove_file(nonce_tracker_path(chain_id, account_address).unwrap());
+
+        let mut nonce_manager = NonceManager::lock(chain_id, account_address).unwrap();
+        let nonce = nonce_manager.next_nonce(FieldElement::TWO).unwrap();
+        assert_eq!(nonce, FieldElement::TWO);
+        nonce_manager.record_sent(nonce, &SENT).unwrap();
+        drop(nonce_manager);
+
+        // the node hasn't included the transaction in the pending block yet
+        let mut nonce_manager = NonceManager::lock(chain_id, account_address).unwrap();
+        let nonce = nonce_manager.next_nonce(FieldElement::TWO).unwrap();
+        assert_eq!(nonce, FieldElement::from(3_u8));
+        nonce_manager.record_sent(nonce, &SENT).unwrap();
+
+        // the node is ahead of the tracker
+        let nonce = nonce_manager.next_nonce(FieldElement::from(10_u8)).unwrap();
+        assert_eq!(nonce, FieldElement::from(10_u8));
+    }
+
+    #[test]
+    fn test_forgets_rejected_nonce() {
+        let chain_id = FieldElement::from_hex_be("0x6e6f6e63655f74657374").unwrap();
+        let account_address = FieldElement::from_hex_be("0x72656a6563746564").unwrap();
+        let _ = fs::remove_file(nonce_tracker_path(chain_id, account_address).unwrap());
+
+        let mut nonce_manager = NonceManager::lock(chain_id, account_address).unwrap();
+        nonce_manager
+            .record_sent(FieldElement::from(5_u8), &SENT)
+            .unwrap();
+        assert_eq!(
+            nonce_manager.next_nonce(FieldElement::ONE).unwrap(),
+            FieldElement::from(6_u8)
+        );
+
+        let rejected: Result<(), AccountError<()>> = Err(AccountError::Provider(
+            ProviderError::StarknetError(InvalidTransactionNonce),
+        ));
+        nonce_manager
+            .record_sent(FieldElement::from(6_u8), &rejected)
+            .unwrap();
+        assert_eq!(
+            nonce_manager.next_nonce(FieldElement::ONE).unwrap(),
+            FieldElement::ONE
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::helpers::external_signer::TransactionDetails;
+use crate::helpers::signer::TransactionSigner;
+use async_trait::async_trait;
+use starknet::accounts::{AccountFactory, PreparedAccountDeployment, RawAccountDeployment};
+use starknet::core::types::{BlockId, BlockTag, FieldElement};
+use starknet::providers::Provider;
+use starknet::signers::Signer;
+
+/// Factory of OpenZeppelin accounts, passing fields of the deployment to the signer
+pub struct OpenZeppelinAccountFactory<S, P> {
+    class_hash: FieldElement,
+    chain_id: FieldElement,
+    public_key: FieldElement,
+    signer: S,
+    provider: P,
+    block_id: BlockId,
+}
+
+impl<S, P> OpenZeppelinAccountFactory<S, P>
+where
+    S: Signer,
+{
+    pub async fn new(
+        class_hash: FieldElement,
+        chain_id: FieldElement,
+        signer: S,
+        provider: P,
+    ) -> Result<Self, S::GetPublicKeyError> {
+        let public_key = signer.get_public_key().await?;
+
+        Ok(Self {
+            class_hash,
+            chain_id,
+            public_key: public_key.scalar(),
+            signer,
+            provider,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        })
+    }
+}
+
+#[async_trait]
+impl<S, P> AccountFactory for OpenZeppelinAccountFactory<S, P>
+where
+    S: TransactionSigner + Sync + Send,
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+    type SignError = S::SignError;
+
+    fn class_hash(&self) -> FieldElement {
+        self.class_hash
+    }
+
+    fn calldata(&self) -> Vec<FieldElement> {
+        vec![self.public_key]
+    }
+
+    fn chain_id(&self) -> FieldElement {
+        self.chain_id
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn sign_deployment(
+        &self,
+        deployment: &RawAccountDeployment,
+    ) -> Result<Vec<FieldElement>, Self::SignError> {
+        let tx_hash =
+            PreparedAccountDeployment::from_raw(deployment.clone(), self).transaction_hash();
+        let transaction = TransactionDetails::deploy_account(self, deployment);
+        let signature = self.signer.sign_transaction(&tx_hash, &transaction).await?;
+
+        Ok(vec![signature.r, signature.s])
+    }
+}
@@ -0,0 +1,64 @@
+use crate::chain_id_to_network_name;
+use anyhow::{anyhow, Context, Result};
+use fs2::FileExt;
+use starknet::core::types::FieldElement;
+use std::env;
+use std::fs::{self, File};
+use std::path::PathBuf;
+
+/// Lock preventing concurrent `script run`s with the same account on the same network,
+/// which would otherwise collide on nonces. The lock is released when dropped.
+pub struct ScriptLock {
+    _file: File,
+}
+
+impl ScriptLock {
+    pub fn acquire(chain_id: FieldElement, account_address: FieldElement) -> Result<Self> {
+        let path = script_lock_path(chain_id, account_address);
+        fs::create_dir_all(path.parent().expect("Lock file path has no parent"))?;
+
+        let file = File::create(&path)
+            .with_context(|| format!("Failed to create script lock file = {}", path.display()))?;
+        file.try_lock_exclusive().map_err(|_| {
+            anyhow!(
+                "Another script is already running on network = {} with account = {account_address:#x}. Wait for it to finish or use --force to run anyway",
+                chain_id_to_network_name(chain_id)
+            )
+        })?;
+
+        Ok(Self { _file: file })
+    }
+}
+
+#[must_use]
+pub fn script_lock_path(chain_id: FieldElement, account_address: FieldElement) -> PathBuf {
+    env::temp_dir()
+        .join("sncast")
+        .join(format!("script_{chain_id:#x}_{account_address:#x}.lock"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScriptLock;
+    use starknet::core::types::FieldElement;
+
+    #[test]
+    fn test_script_lock_is_exclusive() {
+        let chain_id = FieldElement::from_hex_be("0x534e5f5345504f4c4941").unwrap();
+        let account_address = FieldElement::from_hex_be("0x123456789abcdef").unwrap();
+
+        let lock = ScriptLock::acquire(chain_id, account_address).unwrap();
+        let err = ScriptLock::acquire(chain_id, account_address)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Another script is already running on network = alpha-sepolia"));
+
+        let other_account_lock =
+            ScriptLock::acquire(chain_id, FieldElement::from_hex_be("0x1").unwrap());
+        assert!(other_account_lock.is_ok());
+
+        drop(lock);
+        assert!(ScriptLock::acquire(chain_id, account_address).is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::helpers::external_signer::{ExternalSigner, ExternalSignerError, TransactionDetails};
+use crate::helpers::ledger::{LedgerError, LedgerSigner};
+use async_trait::async_trait;
+use starknet::core::crypto::Signature;
+use starknet::core::types::FieldElement;
+use starknet::signers::local_wallet::SignError;
+use starknet::signers::{LocalWallet, Signer, VerifyingKey};
+use std::fmt;
+use thiserror::Error;
+
+/// Signer of the account transactions, selected per account
+pub enum AccountSigner {
+    Local(LocalWallet),
+    Ledger(LedgerSigner),
+    External(ExternalSigner),
+}
+
+impl fmt::Debug for AccountSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountSigner::Local(signer) => signer.fmt(f),
+            AccountSigner::Ledger(_) => f.write_str("LedgerSigner"),
+            AccountSigner::External(signer) => signer.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AccountSignerError {
+    #[error(transparent)]
+    Local(#[from] SignError),
+    #[error(transparent)]
+    Ledger(#[from] LedgerError),
+    #[error(transparent)]
+    External(#[from] ExternalSignerError),
+}
+
+#[async_trait]
+impl Signer for AccountSigner {
+    type GetPublicKeyError = AccountSignerError;
+    type SignError = AccountSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        match self {
+            AccountSigner::Local(signer) => match signer.get_public_key().await {
+                Ok(public_key) => Ok(public_key),
+                Err(error) => match error {},
+            },
+            AccountSigner::Ledger(signer) => Ok(signer.get_public_key().await?),
+            AccountSigner::External(signer) => Ok(signer.get_public_key().await?),
+        }
+    }
+
+    async fn sign_hash(&self, hash: &FieldElement) -> Result<Signature, Self::SignError> {
+        match self {
+            AccountSigner::Local(signer) => Ok(signer.sign_hash(hash).await?),
+            AccountSigner::Ledger(signer) => Ok(signer.sign_hash(hash).await?),
+            AccountSigner::External(signer) => Ok(signer.sign_hash(hash).await?),
+        }
+    }
+}
+
+/// Signer which can be given fields of the transaction along with its hash
+#[async_trait]
+pub trait TransactionSigner: Signer {
+    async fn sign_transaction(
+        &self,
+        hash: &FieldElement,
+        transaction: &TransactionDetails,
+    ) -> Result<Signature, Self::SignError>;
+}
+
+#[async_trait]
+impl TransactionSigner for AccountSigner {
+    async fn sign_transaction(
+        &self,
+        hash: &FieldElement,
+        transaction: &TransactionDetails,
+    ) -> Result<Signature, Self::SignError> {
+        match self {
+            AccountSigner::External(signer) => {
+                Ok(signer.sign_transaction(hash, transaction).await?)
+            }
+            AccountSigner::Local(_) | AccountSigner::Ledger(_) => self.sign_hash(hash).await,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::helpers::constants::{ETH_TOKEN_ADDRESS, STRK_TOKEN_ADDRESS};
+use crate::response::errors::StarknetCommandError;
+use anyhow::{bail, Context, Result};
+use primitive_types::U256;
+use starknet::core::types::{BlockId, FieldElement, FunctionCall};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::str::FromStr;
+
+/// ERC20 token, either one of the fee tokens or any other token by its address
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token {
+    Eth,
+    Strk,
+    Custom(FieldElement),
+}
+
+impl Token {
+    #[must_use]
+    pub fn address(&self) -> FieldElement {
+        match self {
+            Token::Eth => {
+                FieldElement::from_hex_be(ETH_TOKEN_ADDRESS).expect("Failed to parse ETH address")
+            }
+            Token::Strk => {
+                FieldElement::from_hex_be(STRK_TOKEN_ADDRESS).expect("Failed to parse STRK address")
+            }
+            Token::Custom(address) => *address,
+        }
+    }
+
+    /// Decimals of the fee tokens, other tokens have to be asked for theirs
+    #[must_use]
+    pub fn known_decimals(&self) -> Option<u8> {
+        match self {
+            Token::Eth | Token::Strk => Some(18),
+            Token::Custom(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self {
+            Token::Eth => Some("ETH"),
+            Token::Strk => Some("STRK"),
+            Token::Custom(_) => None,
+        }
+    }
+}
+
+impl FromStr for Token {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "eth" => Ok(Token::Eth),
+            "strk" => Ok(Token::Strk),
+            address if address.starts_with("0x") => Ok(Token::Custom(
+                FieldElement::from_hex_be(address)
+                    .with_context(|| format!("Invalid token address = {s}"))?,
+            )),
+            _ => bail!("Unknown token = {s}, expected eth, strk or a token address"),
+        }
+    }
+}
+
+/// Converts `low` and `high` felts of a Cairo `u256` into `U256`
+pub fn u256_from_felts(low: FieldElement, high: FieldElement) -> Result<U256> {
+    let low = U256::from_big_endian(&low.to_bytes_be());
+    let high = U256::from_big_endian(&high.to_bytes_be());
+    if low.bits() > 128 || high.bits() > 128 {
+        bail!("Value is not a valid u256");
+    }
+    Ok((high << 128) | low)
+}
+
+/// Splits `U256` into `low` and `high` felts of a Cairo `u256`
+#[must_use]
+pub fn u256_to_felts(value: U256) -> (FieldElement, FieldElement) {
+    let to_felt = |value: U256| {
+        let mut bytes = [0_u8; 32];
+        value.to_big_endian(&mut bytes);
+        FieldElement::from_bytes_be(&bytes).expect("Failed to convert u128 to FieldElement")
+    };
+    let mask = (U256::one() << 128) - 1;
+    (to_felt(value & mask), to_felt(value >> 128))
+}
+
+/// Formats an amount in the smallest units as a decimal number of whole tokens, e.g. `1.5`
+#[must_use]
+pub fn format_units(amount: U256, decimals: u8) -> String {
+    let unit = U256::exp10(usize::from(decimals));
+    let whole = amount / unit;
+    let fraction = amount % unit;
+    if fraction.is_zero() {
+        return whole.to_string();
+    }
+    let fraction = fraction.to_string();
+    let leading_zeros = "0".repeat(usize::from(decimals) - fraction.len());
+    format!("{whole}.{leading_zeros}{}", fraction.trim_end_matches('0'))
+}
+
+/// Parses a decimal number of whole tokens, e.g. `1.5`, into an amount in the smallest units
+pub fn parse_units(amount: &str, decimals: u8) -> Result<U256> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if fraction.len() > usize::from(decimals) {
+        bail!("Amount = {amount} has more than {decimals} decimal places");
+    }
+    let digits = format!("{whole}{fraction:0<width$}", width = usize::from(decimals));
+    if digits.is_empty() || !digits.chars().all(|char| char.is_ascii_digit()) {
+        bail!("Invalid amount = {amount}");
+    }
+    U256::from_dec_str(&digits).with_context(|| format!("Invalid amount = {amount}"))
+}
+
+/// Fetches decimals of the token
+pub async fn get_decimals(
+    token: Token,
+    provider: &JsonRpcClient<HttpTransport>,
+    block_id: &BlockId,
+) -> Result<u8, StarknetCommandError> {
+    if let Some(decimals) = token.known_decimals() {
+        return Ok(decimals);
+    }
+
+    let token_address = token.address();
+    let decimals = call_erc20(provider, token_address, &["decimals"], vec![], block_id)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let decimals = decimals
+        .first()
+        .and_then(|decimals| felt_to_u8(*decimals))
+        .with_context(|| format!("Invalid decimals returned by token = {token_address:#x}"))?;
+    Ok(decimals)
+}
+
+/// Calls the first of `entry_points` which the token implements, as ERC20 functions
+/// are either in snake case or camel case
+pub async fn call_erc20(
+    provider: &JsonRpcClient<HttpTransport>,
+    token_address: FieldElement,
+    entry_points: &[&str],
+    calldata: Vec<FieldElement>,
+    block_id: &BlockId,
+) -> Result<Vec<FieldElement>, ProviderError> {
+    let mut result = Ok(vec![]);
+    for entry_point in entry_points {
+        let function_call = FunctionCall {
+            contract_address: token_address,
+            entry_point_selector: get_selector_from_name(entry_point)
+                .expect("Failed to convert entry point selector to FieldElement"),
+            calldata: calldata.clone(),
+        };
+        result = provider.call(function_call, block_id).await;
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+fn felt_to_u8(felt: FieldElement) -> Option<u8> {
+    let bytes = felt.to_bytes_be();
+    let (last, leading) = bytes.split_last()?;
+    leading.iter().all(|byte| *byte == 0).then_some(*last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_token() {
+        assert_eq!(Token::from_str("eth").unwrap(), Token::Eth);
+        assert_eq!(Token::from_str("STRK").unwrap(), Token::Strk);
+        assert_eq!(
+            Token::from_str("0x123").unwrap(),
+            Token::Custom(FieldElement::from(0x123_u32))
+        );
+        assert!(Token::from_str("usdc").is_err());
+    }
+
+    #[test]
+    fn converts_u256() {
+        assert_eq!(
+            u256_from_felts(FieldElement::from(5_u32), FieldElement::TWO).unwrap(),
+            (U256::from(2) << 128) + 5
+        );
+        assert!(u256_from_felts(FieldElement::MAX, FieldElement::ZERO).is_err());
+    }
+
+    #[test]
+    fn splits_u256() {
+        let (low, high) = u256_to_felts((U256::from(2) << 128) + 5);
+
+        assert_eq!(low, FieldElement::from(5_u32));
+        assert_eq!(high, FieldElement::TWO);
+    }
+
+    #[test]
+    fn parses_units() {
+        assert_eq!(parse_units("1", 18).unwrap(), U256::exp10(18));
+        assert_eq!(parse_units("1.5", 6).unwrap(), U256::from(1_500_000_u64));
+        assert_eq!(parse_units(".5", 1).unwrap(), U256::from(5_u64));
+        assert!(parse_units("0.001", 2).is_err());
+        assert!(parse_units("1,5", 6).is_err());
+        assert!(parse_units("", 6).is_err());
+    }
+
+    #[test]
+    fn formats_units() {
+        assert_eq!(format_units(U256::exp10(18), 18), "1");
+        assert_eq!(format_units(U256::from(1_500_000_u64), 6), "1.5");
+        assert_eq!(format_units(U256::from(1_u64), 18), "0.000000000000000001");
+        assert_eq!(format_units(U256::from(42_u64), 0), "42");
+    }
+}
@@ -1,6 +1,10 @@
 use anyhow::{anyhow, bail, Context, Error, Result};
 use camino::Utf8PathBuf;
-use helpers::constants::{KEYSTORE_PASSWORD_ENV_VAR, UDC_ADDRESS};
+use clap::ValueEnum;
+use helpers::constants::{
+    ARGENT_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH, BRAAVOS_CLASS_HASH,
+    KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH, UDC_ADDRESS,
+};
 use rand::rngs::OsRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
@@ -12,9 +16,9 @@ use starknet::core::types::{
     StarknetError::{ClassHashNotFound, ContractNotFound, TransactionHashNotFound},
 };
 use starknet::core::utils::UdcUniqueness::{NotUnique, Unique};
-use starknet::core::utils::{UdcUniqueSettings, UdcUniqueness};
+use starknet::core::utils::{get_contract_address, UdcUniqueSettings, UdcUniqueness};
 use starknet::{
-    accounts::{ExecutionEncoding, SingleOwnerAccount},
+    accounts::ExecutionEncoding,
     providers::{
         jsonrpc::{HttpTransport, JsonRpcClient},
         Provider, ProviderError,
@@ -23,15 +27,19 @@ use starknet::{
     signers::{LocalWallet, SigningKey},
 };
 
+use crate::helpers::account::CastAccount;
 use crate::helpers::constants::{WAIT_RETRY_INTERVAL, WAIT_TIMEOUT};
+use crate::helpers::external_signer::ExternalSigner;
+use crate::helpers::ledger::LedgerSigner;
+use crate::helpers::signer::AccountSigner;
 use crate::response::errors::SNCastProviderError;
 use cairo_felt::Felt252;
 use conversions::felt252::SerializeAsFelt252Vec;
 use serde::de::DeserializeOwned;
 use shared::rpc::create_rpc_client;
 use starknet::accounts::AccountFactoryError;
-use starknet::signers::local_wallet::SignError;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::thread::sleep;
 use std::time::Duration;
 use std::{env, fs};
@@ -43,7 +51,9 @@ pub mod state;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 struct AccountData {
-    private_key: String,
+    private_key: Option<String>,
+    ledger_path: Option<String>,
+    signer_command: Option<String>,
     public_key: String,
     address: String,
     salt: Option<String>,
@@ -52,6 +62,81 @@ struct AccountData {
     legacy: Option<bool>,
 }
 
+#[derive(ValueEnum, Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccountType {
+    #[default]
+    #[serde(rename = "open_zeppelin")]
+    Oz,
+    #[serde(rename = "argent")]
+    Argent,
+    #[serde(rename = "braavos")]
+    Braavos,
+}
+
+impl AccountType {
+    /// Name of the account type used in accounts and account JSON files
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AccountType::Oz => "open_zeppelin",
+            AccountType::Argent => "argent",
+            AccountType::Braavos => "braavos",
+        }
+    }
+
+    #[must_use]
+    pub fn default_class_hash(self) -> FieldElement {
+        let class_hash = match self {
+            AccountType::Oz => OZ_CLASS_HASH,
+            AccountType::Argent => ARGENT_CLASS_HASH,
+            AccountType::Braavos => BRAAVOS_CLASS_HASH,
+        };
+        FieldElement::from_hex_be(class_hash).expect("Failed to parse account class hash")
+    }
+
+    /// Address of the account deployed from `class_hash` with the given salt and public key
+    #[must_use]
+    pub fn account_address(
+        self,
+        class_hash: FieldElement,
+        salt: FieldElement,
+        public_key: FieldElement,
+    ) -> FieldElement {
+        match self {
+            AccountType::Oz => {
+                get_contract_address(salt, class_hash, &[public_key], FieldElement::ZERO)
+            }
+            AccountType::Argent => get_contract_address(
+                salt,
+                class_hash,
+                &[public_key, FieldElement::ZERO],
+                FieldElement::ZERO,
+            ),
+            // Braavos accounts are deployed as the base account and upgraded in the constructor
+            AccountType::Braavos => get_contract_address(
+                salt,
+                FieldElement::from_hex_be(BRAAVOS_BASE_ACCOUNT_CLASS_HASH)
+                    .expect("Failed to parse Braavos base account class hash"),
+                &[public_key],
+                FieldElement::ZERO,
+            ),
+        }
+    }
+}
+
+impl FromStr for AccountType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "open_zeppelin" | "oz" => Ok(AccountType::Oz),
+            "argent" => Ok(AccountType::Argent),
+            "braavos" => Ok(AccountType::Braavos),
+            _ => bail!("Unknown account type = {s}"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NumbersFormat {
     Default,
@@ -197,7 +282,7 @@ pub async fn get_account<'a>(
     accounts_file: &Utf8PathBuf,
     provider: &'a JsonRpcClient<HttpTransport>,
     keystore: Option<Utf8PathBuf>,
-) -> Result<SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>> {
+) -> Result<CastAccount<&'a JsonRpcClient<HttpTransport>>> {
     let chain_id = get_chain_id(provider).await?;
     let account_data = if let Some(keystore) = keystore {
         get_account_data_from_keystore(account, &keystore)?
@@ -210,15 +295,42 @@ pub async fn get_account<'a>(
     Ok(account)
 }
 
+/// Reads address of the account without creating its signer
+pub fn get_account_address(
+    account: &str,
+    accounts_file: &Utf8PathBuf,
+    chain_id: FieldElement,
+    keystore: Option<&Utf8PathBuf>,
+) -> Result<FieldElement> {
+    let address = if keystore.is_some() {
+        let account_info: Value = read_and_parse_json_file(&Utf8PathBuf::from(account))?;
+        account_info
+            .get("deployment")
+            .and_then(|deployment| deployment.get("address"))
+            .and_then(Value::as_str)
+            .context(
+                "Failed to get address from account JSON file - make sure the account is deployed",
+            )?
+            .to_string()
+    } else {
+        get_account_data_from_accounts_file(account, chain_id, accounts_file)?.address
+    };
+
+    parse_number(&address)
+        .with_context(|| format!("Failed to convert account address = {address} to FieldElement"))
+}
+
 async fn build_account(
     account_data: AccountData,
     chain_id: FieldElement,
     provider: &JsonRpcClient<HttpTransport>,
-) -> Result<SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>> {
-    let signer = LocalWallet::from(SigningKey::from_secret_scalar(
-        parse_number(&account_data.private_key)
-            .context("Failed to convert private key to FieldElement")?,
-    ));
+) -> Result<CastAccount<&JsonRpcClient<HttpTransport>>> {
+    let signer = get_account_signer(
+        account_data.private_key.as_deref(),
+        account_data.ledger_path.as_deref(),
+        account_data.signer_command.as_deref(),
+    )
+    .await?;
     let address = parse_number(&account_data.address).with_context(|| {
         format!(
             "Failed to convert account address = {} to FieldElement",
@@ -242,8 +354,7 @@ async fn build_account(
     let account_encoding =
         get_account_encoding(account_data.legacy, class_hash, address, provider).await?;
 
-    let mut account =
-        SingleOwnerAccount::new(provider, signer, address, chain_id, account_encoding);
+    let mut account = CastAccount::new(provider, signer, address, chain_id, account_encoding);
 
     account.set_block_id(BlockId::Tag(Pending));
 
@@ -283,6 +394,34 @@ pub async fn check_class_hash_exists(
     }
 }
 
+/// Creates the signer of an account, which is either its private key, a Ledger derivation path
+/// or an external signer command
+pub async fn get_account_signer(
+    private_key: Option<&str>,
+    ledger_path: Option<&str>,
+    signer_command: Option<&str>,
+) -> Result<AccountSigner> {
+    match (private_key, ledger_path, signer_command) {
+        (Some(private_key), _, _) => Ok(AccountSigner::Local(LocalWallet::from(
+            SigningKey::from_secret_scalar(
+                parse_number(private_key)
+                    .context("Failed to convert private key to FieldElement")?,
+            ),
+        ))),
+        (None, Some(ledger_path), _) => Ok(AccountSigner::Ledger(
+            LedgerSigner::new(ledger_path)
+                .await
+                .context("Failed to connect to Ledger")?,
+        )),
+        (None, None, Some(signer_command)) => Ok(AccountSigner::External(ExternalSigner::new(
+            signer_command.to_string(),
+        ))),
+        (None, None, None) => {
+            bail!("Account has none of private_key, ledger_path and signer_command defined")
+        }
+    }
+}
+
 fn get_account_data_from_keystore(
     account: &str,
     keystore_path: &Utf8PathBuf,
@@ -295,7 +434,7 @@ fn get_account_data_from_keystore(
         get_keystore_password(KEYSTORE_PASSWORD_ENV_VAR)?.as_str(),
     )?
     .secret_scalar();
-    let private_key = format!("{private_key:#x}");
+    let private_key = Some(format!("{private_key:#x}"));
 
     let account_info: Value = read_and_parse_json_file(&path_to_account)?;
 
@@ -321,6 +460,8 @@ fn get_account_data_from_keystore(
 
     Ok(AccountData {
         private_key,
+        ledger_path: None,
+        signer_command: None,
         public_key,
         address,
         salt,
@@ -575,7 +716,10 @@ pub fn handle_rpc_error(error: impl Into<SNCastProviderError>) -> Error {
 }
 
 #[must_use]
-pub fn handle_account_factory_error(err: AccountFactoryError<SignError>) -> anyhow::Error {
+pub fn handle_account_factory_error<S>(err: AccountFactoryError<S>) -> anyhow::Error
+where
+    S: std::error::Error + Send + Sync + 'static,
+{
     match err {
         AccountFactoryError::Provider(error) => handle_rpc_error(error),
         error => anyhow!(error),
@@ -770,7 +914,10 @@ mod tests {
             &Utf8PathBuf::from("tests/data/accounts/accounts.json"),
         )
         .unwrap();
-        assert_eq!(account.private_key, "0xffd33878eed7767e7c546ce3fc026295");
+        assert_eq!(
+            account.private_key,
+            Some("0xffd33878eed7767e7c546ce3fc026295".to_string())
+        );
         assert_eq!(
             account.public_key,
             "0x17b62d16ee2b9b5ccd3320e2c0b234dfbdd1d01d09d0aa29ce164827cddf46a"
@@ -796,7 +943,10 @@ mod tests {
             &Utf8PathBuf::from("tests/data/keystore/my_key.json"),
         )
         .unwrap();
-        assert_eq!(account.private_key, "0x55ae34c86281fbd19292c7e3bfdfceb4");
+        assert_eq!(
+            account.private_key,
+            Some("0x55ae34c86281fbd19292c7e3bfdfceb4".to_string())
+        );
         assert_eq!(
             account.public_key,
             "0xe2d3d7080bfc665e0060a06e8e95c3db3ff78a1fec4cc81ddc87e49a12e0a"
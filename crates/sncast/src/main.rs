@@ -1,8 +1,9 @@
 use crate::starknet_commands::account::Account;
 use crate::starknet_commands::show_config::ShowConfig;
 use crate::starknet_commands::{
-    account, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke, multicall::Multicall,
-    script::Script,
+    account, balance::Balance, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke,
+    multicall::Multicall, receipt::Receipt, script::Script, transfer::Transfer,
+    tx_status::TxStatus, verify::Verify,
 };
 use anyhow::{Context, Result};
 use configuration::load_global_config;
@@ -12,16 +13,22 @@ use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand};
 use shared::verify_and_warn_if_incompatible_rpc_version;
 use sncast::helpers::configuration::CastConfig;
-use sncast::helpers::constants::{DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS};
+use sncast::helpers::constants::{
+    DEFAULT_ACCOUNTS_FILE, DEFAULT_BLOCK_ID, DEFAULT_MULTICALL_CONTENTS,
+};
 use sncast::helpers::scarb_utils::{
     assert_manifest_path_exists, build_and_load_artifacts, get_package_metadata,
     get_scarb_metadata_with_deps, BuildConfig,
 };
-use sncast::response::errors::handle_starknet_command_error;
+use sncast::response::errors::{handle_starknet_command_error, StarknetCommandError};
+use sncast::response::structs::{
+    CallResponse, CommandResponse, DeclareResponse, DeployResponse, InvokeResponse,
+};
 use sncast::{
-    chain_id_to_network_name, get_account, get_block_id, get_chain_id, get_nonce, get_provider,
-    NumbersFormat, ValidatedWaitParams, WaitForTx,
+    chain_id_to_network_name, get_account, get_account_address, get_block_id, get_chain_id,
+    get_nonce, get_provider, NumbersFormat, ValidatedWaitParams, WaitForTx,
 };
+use starknet::core::types::{BlockId, BlockTag, FieldElement};
 use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
@@ -99,9 +106,24 @@ enum Commands {
     /// Invoke a contract
     Invoke(Invoke),
 
+    /// Fetch a token balance
+    Balance(Balance),
+
+    /// Transfer tokens
+    Transfer(Transfer),
+
+    /// Get the status of a transaction
+    TxStatus(TxStatus),
+
+    /// Get the receipt of a transaction
+    Receipt(Receipt),
+
     /// Execute multiple calls
     Multicall(Multicall),
 
+    /// Verify a contract with a block explorer
+    Verify(Verify),
+
     /// Create and deploy an account
     Account(Account),
 
@@ -160,23 +182,70 @@ async fn run_async_command(
                 config.keystore,
             )
             .await?;
-            let manifest_path = assert_manifest_path_exists()?;
-            let package_metadata = get_package_metadata(&manifest_path, &declare.package)?;
-            let artifacts = build_and_load_artifacts(
-                &package_metadata,
-                &BuildConfig {
-                    scarb_toml_path: manifest_path,
-                    json: cli.json,
-                    profile: cli.profile.unwrap_or("dev".to_string()),
-                },
-            )
-            .expect("Failed to build contract");
+            let contract_artifacts = if let Some(sierra_file) = &declare.sierra_file {
+                starknet_commands::declare::load_contract_artifacts(
+                    sierra_file,
+                    declare.casm_file.as_ref(),
+                )
+            } else {
+                let manifest_path = assert_manifest_path_exists()?;
+                let package_metadata = get_package_metadata(&manifest_path, &declare.package)?;
+                let artifacts = build_and_load_artifacts(
+                    &package_metadata,
+                    &BuildConfig {
+                        scarb_toml_path: manifest_path,
+                        json: cli.json,
+                        profile: cli.profile.unwrap_or("dev".to_string()),
+                    },
+                )
+                .expect("Failed to build contract");
+                let contract_name = declare
+                    .contract
+                    .as_ref()
+                    .expect("Contract name is required without --sierra-file");
+                starknet_commands::declare::get_contract_artifacts(contract_name, &artifacts)
+                    .cloned()
+            };
+            let contract_artifacts = match contract_artifacts {
+                Ok(contract_artifacts) => contract_artifacts,
+                Err(error) => {
+                    let mut result: Result<DeclareResponse> =
+                        Err(handle_starknet_command_error(error));
+                    print_command_result("declare", &mut result, numbers_format, &output_format)?;
+                    return Ok(());
+                }
+            };
+
+            if declare.estimate_only {
+                let mut result = starknet_commands::declare::estimate_declare(
+                    &contract_artifacts,
+                    &account,
+                    declare.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("declare", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+            if declare.simulate {
+                let mut result = starknet_commands::declare::simulate_declare(
+                    &contract_artifacts,
+                    declare.max_fee,
+                    &account,
+                    declare.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("declare", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
             let mut result = starknet_commands::declare::declare(
-                &declare.contract,
+                &contract_artifacts,
                 declare.max_fee,
                 &account,
                 declare.nonce,
-                &artifacts,
                 wait_config,
             )
             .await
@@ -193,9 +262,57 @@ async fn run_async_command(
                 config.keystore,
             )
             .await?;
-            let mut result = starknet_commands::deploy::deploy(
+            let constructor_calldata = starknet_commands::deploy::constructor_calldata(
+                &provider,
                 deploy.class_hash,
+                deploy.arguments.as_deref(),
                 deploy.constructor_calldata,
+            )
+            .await;
+            let Some(constructor_calldata) = calldata_or_print_error::<DeployResponse>(
+                "deploy",
+                constructor_calldata,
+                numbers_format,
+                &output_format,
+            )?
+            else {
+                return Ok(());
+            };
+
+            if deploy.estimate_only {
+                let mut result = starknet_commands::deploy::estimate_deploy(
+                    deploy.class_hash,
+                    constructor_calldata,
+                    deploy.salt,
+                    deploy.unique,
+                    &account,
+                    deploy.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("deploy", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+            if deploy.simulate {
+                let mut result = starknet_commands::deploy::simulate_deploy(
+                    deploy.class_hash,
+                    constructor_calldata,
+                    deploy.salt,
+                    deploy.unique,
+                    deploy.max_fee,
+                    &account,
+                    deploy.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("deploy", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+            let mut result = starknet_commands::deploy::deploy(
+                deploy.class_hash,
+                constructor_calldata,
                 deploy.salt,
                 deploy.unique,
                 deploy.max_fee,
@@ -210,13 +327,36 @@ async fn run_async_command(
             Ok(())
         }
         Commands::Call(call) => {
-            let block_id = get_block_id(&call.block_id)?;
+            let block_id = get_block_id(
+                call.block_id
+                    .as_deref()
+                    .or(config.default_block_id.as_deref())
+                    .unwrap_or(DEFAULT_BLOCK_ID),
+            )?;
+            let calldata = starknet_commands::call::function_calldata(
+                &provider,
+                call.contract_address,
+                &call.function,
+                call.arguments.as_deref(),
+                call.calldata,
+                block_id.as_ref(),
+            )
+            .await;
+            let Some(calldata) = calldata_or_print_error::<CallResponse>(
+                "call",
+                calldata,
+                numbers_format,
+                &output_format,
+            )?
+            else {
+                return Ok(());
+            };
 
             let mut result = starknet_commands::call::call(
                 call.contract_address,
                 get_selector_from_name(&call.function)
                     .context("Failed to convert entry point selector to FieldElement")?,
-                call.calldata,
+                calldata,
                 &provider,
                 block_id.as_ref(),
             )
@@ -234,11 +374,60 @@ async fn run_async_command(
                 config.keystore,
             )
             .await?;
-            let mut result = starknet_commands::invoke::invoke(
+            let function_selector = get_selector_from_name(&invoke.function)
+                .context("Failed to convert entry point selector to FieldElement")?;
+            let calldata = starknet_commands::call::function_calldata(
+                &provider,
                 invoke.contract_address,
-                get_selector_from_name(&invoke.function)
-                    .context("Failed to convert entry point selector to FieldElement")?,
+                &invoke.function,
+                invoke.arguments.as_deref(),
                 invoke.calldata,
+                &BlockId::Tag(BlockTag::Pending),
+            )
+            .await;
+            let Some(calldata) = calldata_or_print_error::<InvokeResponse>(
+                "invoke",
+                calldata,
+                numbers_format,
+                &output_format,
+            )?
+            else {
+                return Ok(());
+            };
+
+            if invoke.estimate_only {
+                let mut result = starknet_commands::invoke::estimate_invoke(
+                    invoke.contract_address,
+                    function_selector,
+                    calldata,
+                    &account,
+                    invoke.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("invoke", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+            if invoke.simulate {
+                let mut result = starknet_commands::invoke::simulate_invoke(
+                    invoke.contract_address,
+                    function_selector,
+                    calldata,
+                    invoke.max_fee,
+                    &account,
+                    invoke.nonce,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result("invoke", &mut result, numbers_format, &output_format)?;
+                return Ok(());
+            }
+            let mut result = starknet_commands::invoke::invoke(
+                invoke.contract_address,
+                function_selector,
+                calldata,
                 invoke.max_fee,
                 &account,
                 invoke.nonce,
@@ -250,6 +439,101 @@ async fn run_async_command(
             print_command_result("invoke", &mut result, numbers_format, &output_format)?;
             Ok(())
         }
+        Commands::Balance(balance) => {
+            let address = match balance.address {
+                Some(address) => address,
+                None => get_account_address(
+                    &config.account,
+                    &config.accounts_file,
+                    get_chain_id(&provider).await?,
+                    config.keystore.as_ref(),
+                )?,
+            };
+            let block_id = get_block_id(
+                balance
+                    .block_id
+                    .as_deref()
+                    .or(config.default_block_id.as_deref())
+                    .unwrap_or(DEFAULT_BLOCK_ID),
+            )?;
+
+            let mut result = starknet_commands::balance::balance(
+                address,
+                balance.token,
+                &provider,
+                block_id.as_ref(),
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+
+            print_command_result("balance", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Transfer(transfer) => {
+            let account = get_account(
+                &config.account,
+                &config.accounts_file,
+                &provider,
+                config.keystore,
+            )
+            .await?;
+            let mut result = starknet_commands::transfer::transfer(
+                transfer.recipient,
+                &transfer.amount,
+                transfer.token,
+                transfer.max_fee,
+                &account,
+                transfer.nonce,
+                wait_config,
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+
+            print_command_result("transfer", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::TxStatus(tx_status) => {
+            let mut result = starknet_commands::tx_status::tx_status(
+                &provider,
+                tx_status.transaction_hash,
+                tx_status.finality,
+                wait_config,
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+
+            print_command_result("tx-status", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Receipt(receipt) => {
+            let mut result =
+                starknet_commands::receipt::receipt(&provider, receipt.transaction_hash)
+                    .await
+                    .map_err(handle_starknet_command_error);
+
+            print_command_result("receipt", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
+        Commands::Verify(verify) => {
+            let manifest_path = assert_manifest_path_exists()?;
+            let package_metadata = get_package_metadata(&manifest_path, &verify.package)?;
+            let metadata = get_scarb_metadata_with_deps(&manifest_path)?;
+
+            let mut result = starknet_commands::verify::verify(
+                &provider,
+                verify.class_hash,
+                &verify.contract_name,
+                verify.verifier,
+                &metadata,
+                &package_metadata,
+                wait_config.wait_params,
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+
+            print_command_result("verify", &mut result, numbers_format, &output_format)?;
+            Ok(())
+        }
         Commands::Multicall(multicall) => {
             match &multicall.command {
                 starknet_commands::multicall::Commands::New(new) => {
@@ -274,10 +558,25 @@ async fn run_async_command(
                         config.keystore,
                     )
                     .await?;
+                    if run.dry_run {
+                        let mut result = starknet_commands::multicall::run::dry_run(
+                            &run.path, &account, run.nonce,
+                        )
+                        .await;
+
+                        print_command_result(
+                            "multicall run",
+                            &mut result,
+                            numbers_format,
+                            &output_format,
+                        )?;
+                        return Ok(());
+                    }
                     let mut result = starknet_commands::multicall::run::run(
                         &run.path,
                         &account,
                         run.max_fee,
+                        run.nonce,
                         wait_config,
                     )
                     .await;
@@ -324,7 +623,10 @@ async fn run_async_command(
                     chain_id,
                     create.salt,
                     create.add_profile,
+                    create.account_type,
                     create.class_hash,
+                    create.ledger_path,
+                    create.signer_command,
                 )
                 .await;
 
@@ -390,6 +692,38 @@ async fn run_async_command(
                 )?;
                 Ok(())
             }
+            account::Commands::Export(export) => {
+                let network_name = match &export.network {
+                    Some(network) => network.clone(),
+                    None => chain_id_to_network_name(get_chain_id(&provider).await?),
+                };
+
+                let mut result = starknet_commands::account::export::export(
+                    &config.accounts_file,
+                    &network_name,
+                    &provider,
+                    &export,
+                )
+                .await;
+
+                print_command_result(
+                    "account export",
+                    &mut result,
+                    numbers_format,
+                    &output_format,
+                )?;
+                Ok(())
+            }
+            account::Commands::List(_) => {
+                let mut result = starknet_commands::account::list::list(
+                    &config.accounts_file,
+                    config.keystore.as_ref(),
+                    &config.account,
+                );
+
+                print_command_result("account list", &mut result, numbers_format, &output_format)?;
+                Ok(())
+            }
         },
         Commands::ShowConfig(_) => {
             let mut result =
@@ -401,6 +735,24 @@ async fn run_async_command(
     }
 }
 
+/// Unwraps calldata resolved from `--arguments`, printing the error as the result of `command`
+/// if it could not be resolved
+fn calldata_or_print_error<T: CommandResponse>(
+    command: &str,
+    calldata: Result<Vec<FieldElement>, StarknetCommandError>,
+    numbers_format: NumbersFormat,
+    output_format: &OutputFormat,
+) -> Result<Option<Vec<FieldElement>>> {
+    match calldata {
+        Ok(calldata) => Ok(Some(calldata)),
+        Err(error) => {
+            let mut result: Result<T> = Err(handle_starknet_command_error(error));
+            print_command_result(command, &mut result, numbers_format, output_format)?;
+            Ok(None)
+        }
+    }
+}
+
 fn run_script_command(
     cli: &Cli,
     runtime: Runtime,
@@ -447,6 +799,7 @@ fn run_script_command(
                 &provider,
                 runtime,
                 &config,
+                run.force,
             );
 
             print_command_result("script run", &mut result, numbers_format, output_format)?;
@@ -3,6 +3,7 @@ use serde_json::Value;
 use starknet::core::types::FieldElement;
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
+use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 
 use crate::NumbersFormat;
@@ -29,9 +30,10 @@ impl OutputFormat {
 pub enum OutputValue {
     String(String),
     Array(Vec<OutputValue>),
+    Object(Vec<(String, OutputValue)>),
 }
 
-/// Constrained subset of `serde::json`. No nested maps allowed.
+/// Constrained subset of `serde::json`, with other values than strings, arrays and objects printed as strings
 type OutputData = Vec<(String, OutputValue)>;
 
 impl Serialize for OutputValue {
@@ -42,6 +44,13 @@ impl Serialize for OutputValue {
         match &self {
             OutputValue::String(s) => serializer.serialize_str(s),
             OutputValue::Array(arr) => arr.serialize::<S>(serializer),
+            OutputValue::Object(obj) => {
+                let mut map = serializer.serialize_map(Some(obj.len()))?;
+                for (key, value) in obj {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
         }
     }
 }
@@ -58,6 +67,53 @@ impl Display for OutputValue {
                     .join(", ");
                 write!(fmt, "[{arr_as_string}]")
             }
+            OutputValue::Object(obj) => {
+                let obj_as_string = obj
+                    .iter()
+                    .map(|(key, value)| format!("{key}: {value}"))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(fmt, "{{{obj_as_string}}}")
+            }
+        }
+    }
+}
+
+impl OutputValue {
+    /// Lines of the value in the human readable format, nested objects and arrays of them
+    /// are printed in indented blocks
+    fn human_lines(&self, key: &str) -> Vec<String> {
+        let indent = |line: String| format!("  {line}");
+        match self {
+            OutputValue::Object(obj) => {
+                let mut lines = vec![format!("{key}:")];
+                for (nested_key, value) in obj {
+                    lines.extend(value.human_lines(nested_key).into_iter().map(indent));
+                }
+                lines
+            }
+            OutputValue::Array(arr)
+                if arr
+                    .iter()
+                    .any(|item| matches!(item, OutputValue::Object(_))) =>
+            {
+                let mut lines = vec![format!("{key}:")];
+                for item in arr {
+                    let item_lines = match item {
+                        OutputValue::Object(obj) => obj
+                            .iter()
+                            .flat_map(|(nested_key, value)| value.human_lines(nested_key))
+                            .collect(),
+                        _ => vec![item.to_string()],
+                    };
+                    for (i, line) in item_lines.into_iter().enumerate() {
+                        let marker = if i == 0 { "-" } else { " " };
+                        lines.push(indent(format!("{marker} {line}")));
+                    }
+                }
+                lines
+            }
+            _ => vec![format!("{key}: {self}")],
         }
     }
 }
@@ -98,8 +154,7 @@ fn pretty_output(output: OutputData, output_format: &OutputFormat) -> Result<Vec
         OutputFormat::Human => {
             let mut result = vec![];
             for (key, value) in &output {
-                let value = value.to_string();
-                result.push(format!("{key}: {value}"));
+                result.extend(value.human_lines(key));
             }
             Ok(result)
         }
@@ -136,8 +191,11 @@ fn struct_value_to_output_data(struct_value: Value) -> OutputData {
 fn value_to_output_value(value: Value) -> OutputValue {
     match value {
         Value::Array(a) => OutputValue::Array(a.into_iter().map(value_to_output_value).collect()),
+        Value::Object(_) => OutputValue::Object(struct_value_to_output_data(value)),
         Value::String(s) => OutputValue::String(s.to_string()),
-        s => panic!("{s:?} cannot be auto-serialized to output"),
+        Value::Bool(b) => OutputValue::String(b.to_string()),
+        Value::Number(n) => OutputValue::String(n.to_string()),
+        Value::Null => panic!("null cannot be auto-serialized to output"),
     }
 }
 
@@ -160,6 +218,11 @@ fn apply_numbers_formatting(value: OutputValue, formatting: NumbersFormat) -> Ou
                 .collect();
             OutputValue::Array(formatted_arr)
         }
+        OutputValue::Object(obj) => OutputValue::Object(
+            obj.into_iter()
+                .map(|(key, value)| (key, apply_numbers_formatting(value, formatting)))
+                .collect(),
+        ),
     }
 }
 
@@ -239,4 +302,22 @@ mod tests {
         )];
         assert_eq!(actual, json_value_exp);
     }
+
+    #[test]
+    fn test_nested_output_human_lines() {
+        let value = OutputValue::Array(vec![OutputValue::Object(vec![
+            (String::from("K"), OutputValue::String(String::from("V"))),
+            (
+                String::from("K2"),
+                OutputValue::Object(vec![(
+                    String::from("K3"),
+                    OutputValue::Array(vec![OutputValue::String(String::from("V3"))]),
+                )]),
+            ),
+        ])]);
+
+        let actual = value.human_lines("events");
+        let expected = vec!["events:", "  - K: V", "    K2:", "      K3: [V3]"];
+        assert_eq!(actual, expected);
+    }
 }
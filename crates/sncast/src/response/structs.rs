@@ -3,7 +3,8 @@ use camino::Utf8PathBuf;
 use conversions::felt252::SerializeAsFelt252Vec;
 use conversions::FromConv;
 use serde::{Deserialize, Serialize, Serializer};
-use starknet::core::types::FieldElement;
+use starknet::core::types::{FeeEstimate, FieldElement, PriceUnit};
+use std::collections::BTreeMap;
 
 pub struct Decimal(pub u64);
 
@@ -53,6 +54,196 @@ impl SerializeAsFelt252Vec for CallResponse {
     }
 }
 
+#[derive(Serialize, Clone)]
+pub struct BalanceResponse {
+    pub balance: String,
+    pub formatted_balance: String,
+}
+impl CommandResponse for BalanceResponse {}
+
+#[derive(Serialize, Clone)]
+pub struct TransactionStatusResponse {
+    pub execution_status: Option<String>,
+    pub finality_status: String,
+    pub revert_reason: Option<String>,
+}
+impl CommandResponse for TransactionStatusResponse {}
+
+#[derive(Serialize, Clone)]
+pub struct TransactionReceiptResponse {
+    pub transaction_hash: Felt,
+    pub transaction_type: String,
+    pub block_number: Option<u64>,
+    pub finality_status: String,
+    pub execution_status: String,
+    pub revert_reason: Option<String>,
+    pub actual_fee: FeePaymentResponse,
+    pub events: Vec<EventResponse>,
+    pub messages_sent: Vec<MessageToL1Response>,
+    pub execution_resources: ExecutionResourcesResponse,
+}
+impl CommandResponse for TransactionReceiptResponse {}
+
+#[derive(Serialize, Clone)]
+pub struct FeeEstimateResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    pub unit: String,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_price: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub data_gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub data_gas_price: Felt,
+}
+impl CommandResponse for FeeEstimateResponse {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FeePaymentResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub amount: Felt,
+    pub unit: String,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct EventResponse {
+    pub from_address: Felt,
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+    /// Event decoded with the ABI of the emitting contract, e.g. `Transfer(from: 0x1, to: 0x2, value: 3)`
+    pub decoded: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MessageToL1Response {
+    pub to_address: Felt,
+    pub payload: Vec<Felt>,
+}
+
+/// Resources used by a transaction, builtins which were not used are omitted
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ExecutionResourcesResponse {
+    pub steps: u64,
+    pub memory_holes: Option<u64>,
+    pub range_check_builtin_applications: Option<u64>,
+    pub pedersen_builtin_applications: Option<u64>,
+    pub poseidon_builtin_applications: Option<u64>,
+    pub ec_op_builtin_applications: Option<u64>,
+    pub ecdsa_builtin_applications: Option<u64>,
+    pub bitwise_builtin_applications: Option<u64>,
+    pub keccak_builtin_applications: Option<u64>,
+    pub segment_arena_builtin: Option<u64>,
+    pub data_availability: Option<DataAvailabilityResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DataAvailabilityResponse {
+    pub l1_gas: u64,
+    pub l1_data_gas: u64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct SimulationResponse {
+    pub validate_invocation: Option<InvocationResponse>,
+    pub constructor_invocation: Option<InvocationResponse>,
+    pub execute_invocation: Option<InvocationResponse>,
+    pub fee_transfer_invocation: Option<InvocationResponse>,
+    pub revert_reason: Option<String>,
+    pub state_diff: Option<StateDiffResponse>,
+    pub execution_resources: Option<ExecutionResourcesResponse>,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    pub unit: String,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub data_gas_consumed: Felt,
+}
+impl CommandResponse for SimulationResponse {}
+
+/// Call executed in a simulated transaction, with the calls it made
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InvocationResponse {
+    pub contract_address: Felt,
+    pub entry_point_selector: Felt,
+    pub calldata: Vec<Felt>,
+    pub result: Vec<Felt>,
+    pub calls: Vec<InvocationResponse>,
+}
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct StateDiffResponse {
+    pub deployed_contracts: Vec<DeployedContractResponse>,
+    pub declared_classes: Vec<Felt>,
+    pub storage_diffs: Vec<StorageDiffResponse>,
+    pub nonces: Vec<NonceUpdateResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DeployedContractResponse {
+    pub address: Felt,
+    pub class_hash: Felt,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StorageDiffResponse {
+    pub address: Felt,
+    pub storage_entries: Vec<StorageEntryResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StorageEntryResponse {
+    pub key: Felt,
+    pub value: Felt,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NonceUpdateResponse {
+    pub contract_address: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub nonce: Felt,
+}
+
+#[derive(Serialize, Clone)]
+pub struct MulticallDryRunResponse {
+    pub calls: Vec<String>,
+    pub calldata: Vec<Felt>,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    pub unit: String,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub data_gas_consumed: Felt,
+}
+impl CommandResponse for MulticallDryRunResponse {}
+
+#[derive(Serialize, Clone)]
+pub struct VerifyResponse {
+    pub class_hash: Felt,
+    pub message: String,
+}
+impl CommandResponse for VerifyResponse {}
+
+impl From<FeeEstimate> for FeeEstimateResponse {
+    fn from(fee_estimate: FeeEstimate) -> Self {
+        FeeEstimateResponse {
+            overall_fee: Felt(fee_estimate.overall_fee),
+            unit: match fee_estimate.unit {
+                PriceUnit::Wei => "WEI",
+                PriceUnit::Fri => "FRI",
+            }
+            .to_string(),
+            gas_consumed: Felt(fee_estimate.gas_consumed),
+            gas_price: Felt(fee_estimate.gas_price),
+            data_gas_consumed: Felt(fee_estimate.data_gas_consumed),
+            data_gas_price: Felt(fee_estimate.data_gas_price),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct InvokeResponse {
     pub transaction_hash: Felt,
@@ -122,6 +313,32 @@ pub struct AccountDeleteResponse {
 
 impl CommandResponse for AccountDeleteResponse {}
 
+#[derive(Serialize)]
+pub struct AccountExportResponse {
+    pub keystore: Utf8PathBuf,
+    pub account_file: Option<Utf8PathBuf>,
+}
+
+impl CommandResponse for AccountExportResponse {}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct AccountListItem {
+    pub address: Option<String>,
+    pub class_hash: Option<String>,
+    pub deployed: Option<bool>,
+    #[serde(rename = "type")]
+    pub account_type: Option<String>,
+    pub keystore: bool,
+}
+
+/// Accounts by their names, grouped by networks
+#[derive(Serialize, Debug)]
+pub struct AccountListResponse {
+    pub accounts: BTreeMap<String, BTreeMap<String, AccountListItem>>,
+}
+
+impl CommandResponse for AccountListResponse {}
+
 #[derive(Serialize)]
 pub struct MulticallNewResponse {
     pub path: Utf8PathBuf,
@@ -139,6 +356,7 @@ pub struct ShowConfigResponse {
     pub keystore: Option<Utf8PathBuf>,
     pub wait_timeout: Option<Decimal>,
     pub wait_retry_interval: Option<Decimal>,
+    pub default_block_id: Option<String>,
 }
 impl CommandResponse for ShowConfigResponse {}
 
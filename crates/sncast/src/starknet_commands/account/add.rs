@@ -1,16 +1,20 @@
 use crate::starknet_commands::account::{
     add_created_profile_to_configuration, prepare_account_json, write_account_to_accounts_file,
+    AccountKey,
 };
 use anyhow::{ensure, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use sncast::helpers::configuration::CastConfig;
+use sncast::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
+use sncast::helpers::external_signer::ExternalSigner;
+use sncast::helpers::ledger::LedgerSigner;
 use sncast::response::structs::AccountAddResponse;
-use sncast::{check_class_hash_exists, get_chain_id, parse_number};
+use sncast::{check_class_hash_exists, get_chain_id, get_keystore_password, parse_number};
 use sncast::{check_if_legacy_contract, get_class_hash_by_address};
 use starknet::core::types::FieldElement;
 use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
-use starknet::signers::SigningKey;
+use starknet::signers::{Signer, SigningKey};
 
 #[derive(Args, Debug)]
 #[command(about = "Add an account to the accounts file")]
@@ -35,6 +39,18 @@ pub struct Add {
     #[clap(long = "private-key-file", group = "private_key_input")]
     pub private_key_file_path: Option<Utf8PathBuf>,
 
+    /// Path to the encrypted keystore file holding account private key
+    #[clap(long = "keystore", group = "private_key_input")]
+    pub keystore_path: Option<Utf8PathBuf>,
+
+    /// EIP-2645 derivation path of the account key on a Ledger device, e.g. m/2645'/1195502025'/1470455285'/0'/0'/0
+    #[clap(long, group = "private_key_input")]
+    pub ledger_path: Option<String>,
+
+    /// Command of an external signer holding the account key, see the `sncast` external signer protocol
+    #[clap(long, group = "private_key_input")]
+    pub signer_command: Option<String>,
+
     /// Account public key
     #[clap(long)]
     pub public_key: Option<FieldElement>,
@@ -56,17 +72,10 @@ pub async fn add(
     provider: &JsonRpcClient<HttpTransport>,
     add: &Add,
 ) -> Result<AccountAddResponse> {
-    let private_key = match &add.private_key_file_path {
-        Some(file_path) => get_private_key_from_file(file_path)
-            .with_context(|| format!("Failed to obtain private key from the file {file_path}"))?,
-        None => add
-            .private_key
-            .expect("Failed to parse provided private key"),
-    };
-    let private_key = &SigningKey::from_secret_scalar(private_key);
+    let account_key = get_account_key(add).await?;
     if let Some(public_key) = &add.public_key {
         ensure!(
-            public_key == &private_key.verifying_key().scalar(),
+            public_key == &account_key.public_key(),
             "The private key does not match the public key"
         );
     }
@@ -93,7 +102,7 @@ pub async fn add(
     let legacy = check_if_legacy_contract(class_hash, add.address, provider).await?;
 
     let account_json = prepare_account_json(
-        private_key,
+        &account_key,
         add.address,
         deployed,
         legacy,
@@ -126,7 +135,58 @@ pub async fn add(
     })
 }
 
+async fn get_account_key(add: &Add) -> Result<AccountKey> {
+    if let Some(file_path) = &add.private_key_file_path {
+        let private_key = get_private_key_from_file(file_path)
+            .with_context(|| format!("Failed to obtain private key from the file {file_path}"))?;
+        return Ok(AccountKey::PrivateKey(SigningKey::from_secret_scalar(
+            private_key,
+        )));
+    }
+    if let Some(keystore_path) = &add.keystore_path {
+        let private_key = get_private_key_from_keystore(keystore_path).with_context(|| {
+            format!("Failed to obtain private key from the keystore {keystore_path}")
+        })?;
+        return Ok(AccountKey::PrivateKey(private_key));
+    }
+    if let Some(ledger_path) = &add.ledger_path {
+        let public_key = get_public_key_from_ledger(ledger_path)
+            .await
+            .context("Failed to obtain public key from Ledger")?;
+        return Ok(AccountKey::Ledger {
+            path: ledger_path.clone(),
+            public_key,
+        });
+    }
+    if let Some(signer_command) = &add.signer_command {
+        let public_key = ExternalSigner::new(signer_command.clone())
+            .get_public_key()
+            .await
+            .context("Failed to obtain public key from the external signer")?
+            .scalar();
+        return Ok(AccountKey::External {
+            command: signer_command.clone(),
+            public_key,
+        });
+    }
+    Ok(AccountKey::PrivateKey(SigningKey::from_secret_scalar(
+        add.private_key
+            .expect("Failed to parse provided private key"),
+    )))
+}
+
 fn get_private_key_from_file(file_path: &Utf8PathBuf) -> Result<FieldElement> {
     let private_key_string = std::fs::read_to_string(file_path.clone())?;
     parse_number(&private_key_string)
 }
+
+fn get_private_key_from_keystore(keystore_path: &Utf8PathBuf) -> Result<SigningKey> {
+    ensure!(keystore_path.exists(), "Keystore file does not exist");
+    let password = get_keystore_password(KEYSTORE_PASSWORD_ENV_VAR)?;
+    Ok(SigningKey::from_keystore(keystore_path, &password)?)
+}
+
+async fn get_public_key_from_ledger(ledger_path: &str) -> Result<FieldElement> {
+    let signer = LedgerSigner::new(ledger_path).await?;
+    Ok(signer.get_public_key().await?.scalar())
+}
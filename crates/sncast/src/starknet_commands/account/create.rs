@@ -1,23 +1,30 @@
 use crate::starknet_commands::account::{
     add_created_profile_to_configuration, prepare_account_json, write_account_to_accounts_file,
+    write_account_to_file, AccountKey,
 };
 use anyhow::{anyhow, bail, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde_json::json;
+use sncast::helpers::argent::ArgentAccountFactory;
+use sncast::helpers::braavos::BraavosAccountFactory;
 use sncast::helpers::configuration::CastConfig;
-use sncast::helpers::constants::{CREATE_KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH};
+use sncast::helpers::constants::{
+    BRAAVOS_BASE_ACCOUNT_CLASS_HASH, CREATE_KEYSTORE_PASSWORD_ENV_VAR,
+};
+use sncast::helpers::external_signer::ExternalSigner;
+use sncast::helpers::ledger::LedgerSigner;
+use sncast::helpers::signer::{AccountSigner, AccountSignerError};
 use sncast::response::structs::{AccountCreateResponse, Felt};
 use sncast::{
     check_class_hash_exists, check_if_legacy_contract, extract_or_generate_salt, get_chain_id,
-    get_keystore_password, handle_account_factory_error, parse_number,
+    get_keystore_password, handle_account_factory_error, parse_number, AccountType,
 };
 use starknet::accounts::{AccountFactory, OpenZeppelinAccountFactory};
 use starknet::core::types::{FeeEstimate, FieldElement};
-use starknet::core::utils::get_contract_address;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
-use starknet::signers::{LocalWallet, SigningKey};
+use starknet::signers::{LocalWallet, Signer, SigningKey};
 
 #[derive(Args, Debug)]
 #[command(about = "Create an account with all important secrets")]
@@ -33,10 +40,23 @@ pub struct Create {
     /// If passed, a profile with provided name and corresponding data will be created in snfoundry.toml
     #[clap(long)]
     pub add_profile: Option<String>,
-    // TODO (#253): think about supporting different account providers
-    /// Custom open zeppelin contract class hash of declared contract
+
+    /// Type of the account
+    #[clap(long = "type", value_enum, default_value_t = AccountType::Oz)]
+    pub account_type: AccountType,
+
+    /// Custom contract class hash of declared account contract of the chosen type
     #[clap(short, long)]
     pub class_hash: Option<FieldElement>,
+
+    /// EIP-2645 derivation path of the account key on a Ledger device, used instead of generating a private key,
+    /// e.g. m/2645'/1195502025'/1470455285'/0'/0'/0
+    #[clap(long)]
+    pub ledger_path: Option<String>,
+
+    /// Command of an external signer holding the account key, used instead of generating a private key
+    #[clap(long, conflicts_with = "ledger_path")]
+    pub signer_command: Option<String>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -49,15 +69,30 @@ pub async fn create(
     chain_id: FieldElement,
     salt: Option<FieldElement>,
     add_profile: Option<String>,
+    account_type: AccountType,
     class_hash: Option<FieldElement>,
+    ledger_path: Option<String>,
+    signer_command: Option<String>,
 ) -> Result<AccountCreateResponse> {
+    if keystore.is_some() && ledger_path.is_some() {
+        bail!("Argument `--ledger-path` cannot be used with `--keystore`");
+    }
+    if keystore.is_some() && signer_command.is_some() {
+        bail!("Argument `--signer-command` cannot be used with `--keystore`");
+    }
     let salt = extract_or_generate_salt(salt);
-    let class_hash = class_hash.unwrap_or_else(|| {
-        FieldElement::from_hex_be(OZ_CLASS_HASH).expect("Failed to parse OZ class hash")
-    });
+    let class_hash = class_hash.unwrap_or_else(|| account_type.default_class_hash());
     check_class_hash_exists(provider, class_hash).await?;
 
-    let (account_json, max_fee) = generate_account(provider, salt, class_hash).await?;
+    let (account_json, max_fee) = generate_account(
+        provider,
+        salt,
+        account_type,
+        class_hash,
+        ledger_path,
+        signer_command,
+    )
+    .await?;
 
     let address = parse_number(
         account_json["address"]
@@ -83,6 +118,7 @@ pub async fn create(
         create_to_keystore(
             private_key,
             salt,
+            account_type,
             class_hash,
             &keystore,
             &account_path,
@@ -125,29 +161,59 @@ pub async fn create(
 async fn generate_account(
     provider: &JsonRpcClient<HttpTransport>,
     salt: FieldElement,
+    account_type: AccountType,
     class_hash: FieldElement,
+    ledger_path: Option<String>,
+    signer_command: Option<String>,
 ) -> Result<(serde_json::Value, FieldElement)> {
-    let private_key = SigningKey::from_random();
+    let (account_key, signer) = if let Some(path) = ledger_path {
+        let signer = LedgerSigner::new(&path)
+            .await
+            .context("Failed to connect to Ledger")?;
+        let public_key = signer
+            .get_public_key()
+            .await
+            .context("Failed to obtain public key from Ledger")?
+            .scalar();
+        (
+            AccountKey::Ledger { path, public_key },
+            AccountSigner::Ledger(signer),
+        )
+    } else if let Some(command) = signer_command {
+        let signer = ExternalSigner::new(command.clone());
+        let public_key = signer
+            .get_public_key()
+            .await
+            .context("Failed to obtain public key from the external signer")?
+            .scalar();
+        (
+            AccountKey::External {
+                command,
+                public_key,
+            },
+            AccountSigner::External(signer),
+        )
+    } else {
+        let private_key = SigningKey::from_random();
+        let signer = AccountSigner::Local(LocalWallet::from_signing_key(private_key.clone()));
+        (AccountKey::PrivateKey(private_key), signer)
+    };
 
-    let address: FieldElement = get_contract_address(
-        salt,
-        class_hash,
-        &[private_key.verifying_key().scalar()],
-        FieldElement::ZERO,
-    );
+    let address = account_type.account_address(class_hash, salt, account_key.public_key());
 
     let legacy = check_if_legacy_contract(Some(class_hash), address, provider).await?;
 
-    let account_json = prepare_account_json(
-        &private_key,
+    let mut account_json = prepare_account_json(
+        &account_key,
         address,
         false,
         legacy,
         Some(class_hash),
         Some(salt),
     );
+    account_json["type"] = serde_json::Value::from(account_type.as_str());
 
-    let max_fee = get_account_deployment_fee(&private_key, class_hash, salt, provider)
+    let max_fee = get_account_deployment_fee(signer, account_type, class_hash, salt, provider)
         .await?
         .overall_fee;
 
@@ -155,14 +221,50 @@ async fn generate_account(
 }
 
 async fn get_account_deployment_fee(
-    private_key: &SigningKey,
+    signer: AccountSigner,
+    account_type: AccountType,
     class_hash: FieldElement,
     salt: FieldElement,
     provider: &JsonRpcClient<HttpTransport>,
 ) -> Result<FeeEstimate> {
-    let signer = LocalWallet::from_signing_key(private_key.clone());
     let chain_id = get_chain_id(provider).await?;
-    let factory = OpenZeppelinAccountFactory::new(class_hash, chain_id, signer, provider).await?;
+
+    match account_type {
+        AccountType::Oz => {
+            let factory =
+                OpenZeppelinAccountFactory::new(class_hash, chain_id, signer, provider).await?;
+            estimate_deployment_fee(&factory, salt).await
+        }
+        AccountType::Argent => {
+            let factory = ArgentAccountFactory::new(
+                class_hash,
+                chain_id,
+                FieldElement::ZERO,
+                signer,
+                provider,
+            )
+            .await?;
+            estimate_deployment_fee(&factory, salt).await
+        }
+        AccountType::Braavos => {
+            let factory = BraavosAccountFactory::new(
+                class_hash,
+                FieldElement::from_hex_be(BRAAVOS_BASE_ACCOUNT_CLASS_HASH)
+                    .expect("Failed to parse Braavos base account class hash"),
+                chain_id,
+                signer,
+                provider,
+            )
+            .await?;
+            estimate_deployment_fee(&factory, salt).await
+        }
+    }
+}
+
+async fn estimate_deployment_fee<T>(factory: &T, salt: FieldElement) -> Result<FeeEstimate>
+where
+    T: AccountFactory<SignError = AccountSignerError> + Sync,
+{
     let deployment = factory.deploy(salt);
 
     let fee_estimate = deployment.estimate_fee().await;
@@ -180,6 +282,7 @@ async fn get_account_deployment_fee(
 fn create_to_keystore(
     private_key: FieldElement,
     salt: FieldElement,
+    account_type: AccountType,
     class_hash: FieldElement,
     keystore_path: &Utf8PathBuf,
     account_path: &Utf8PathBuf,
@@ -195,10 +298,10 @@ fn create_to_keystore(
     let private_key = SigningKey::from_secret_scalar(private_key);
     private_key.save_as_keystore(keystore_path, &password)?;
 
-    let oz_account_json = json!({
+    let account_json = json!({
         "version": 1,
         "variant": {
-            "type": "open_zeppelin",
+            "type": account_type.as_str(),
             "version": 1,
             "public_key": format!("{:#x}", private_key.verifying_key().scalar()),
             "legacy": legacy,
@@ -210,17 +313,5 @@ fn create_to_keystore(
         }
     });
 
-    write_account_to_file(&oz_account_json, account_path)
-}
-
-fn write_account_to_file(
-    account_json: &serde_json::Value,
-    account_file: &Utf8PathBuf,
-) -> Result<()> {
-    std::fs::create_dir_all(account_file.clone().parent().unwrap())?;
-    std::fs::write(
-        account_file.clone(),
-        serde_json::to_string_pretty(&account_json).unwrap(),
-    )?;
-    Ok(())
+    write_account_to_file(&account_json, account_path)
 }
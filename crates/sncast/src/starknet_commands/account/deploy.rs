@@ -2,21 +2,26 @@ use anyhow::{anyhow, bail, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde_json::Map;
-use sncast::helpers::constants::{KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH};
+use sncast::helpers::argent::ArgentAccountFactory;
+use sncast::helpers::braavos::BraavosAccountFactory;
+use sncast::helpers::constants::{BRAAVOS_BASE_ACCOUNT_CLASS_HASH, KEYSTORE_PASSWORD_ENV_VAR};
+use sncast::helpers::open_zeppelin::OpenZeppelinAccountFactory;
+use sncast::helpers::signer::{AccountSigner, AccountSignerError};
 use sncast::response::structs::{Felt, InvokeResponse};
+use starknet::accounts::AccountFactory;
 use starknet::accounts::AccountFactoryError;
-use starknet::accounts::{AccountFactory, OpenZeppelinAccountFactory};
 use starknet::core::types::BlockTag::Pending;
 use starknet::core::types::{BlockId, FieldElement, StarknetError::ClassHashNotFound};
-use starknet::core::utils::get_contract_address;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::ProviderError::StarknetError;
 use starknet::providers::{JsonRpcClient, Provider};
-use starknet::signers::{LocalWallet, SigningKey};
+use starknet::signers::{LocalWallet, Signer, SigningKey};
+use std::str::FromStr;
 
 use sncast::{
-    chain_id_to_network_name, check_account_file_exists, get_keystore_password,
-    handle_account_factory_error, handle_rpc_error, handle_wait_for_tx, parse_number, WaitForTx,
+    chain_id_to_network_name, check_account_file_exists, check_class_hash_exists,
+    get_account_signer, get_keystore_password, handle_account_factory_error, handle_rpc_error,
+    handle_wait_for_tx, parse_number, AccountType, WaitForTx,
 };
 
 #[derive(Args, Debug)]
@@ -30,7 +35,7 @@ pub struct Deploy {
     #[clap(short, long)]
     pub max_fee: Option<FieldElement>,
 
-    /// Custom open zeppelin contract class hash of declared contract
+    /// Custom contract class hash of declared account contract
     #[clap(short, long)]
     pub class_hash: Option<FieldElement>,
 }
@@ -110,12 +115,17 @@ async fn deploy_from_keystore(
             .and_then(serde_json::Value::as_str)
             .context("Failed to get salt from account JSON file")?,
     )?;
-    let oz_class_hash = FieldElement::from_hex_be(
+    let class_hash = FieldElement::from_hex_be(
         deployment
             .get("class_hash")
             .and_then(serde_json::Value::as_str)
             .context("Failed to get class_hash from account JSON file")?,
     )?;
+    let account_type = items
+        .get("variant")
+        .and_then(|v| v.get("type"))
+        .and_then(serde_json::Value::as_str)
+        .map_or(Ok(AccountType::Oz), AccountType::from_str)?;
 
     if !keystore_path.exists() {
         bail!("Failed to read keystore file");
@@ -136,12 +146,8 @@ async fn deploy_from_keystore(
         bail!("Public key and private key from keystore do not match");
     }
 
-    let address = get_contract_address(
-        salt,
-        oz_class_hash,
-        &[private_key.verifying_key().scalar()],
-        FieldElement::ZERO,
-    );
+    let address =
+        account_type.account_address(class_hash, salt, private_key.verifying_key().scalar());
 
     let result = if provider
         .get_class_hash_at(BlockId::Tag(Pending), address)
@@ -152,10 +158,11 @@ async fn deploy_from_keystore(
             transaction_hash: Felt(FieldElement::ZERO),
         }
     } else {
-        deploy_oz_account(
+        deploy_account(
             provider,
-            oz_class_hash,
-            private_key,
+            account_type,
+            class_hash,
+            AccountSigner::Local(LocalWallet::from_signing_key(private_key)),
             salt,
             chain_id,
             max_fee,
@@ -186,7 +193,7 @@ async fn deploy_from_accounts_file(
     chain_id: FieldElement,
     max_fee: Option<FieldElement>,
     wait_config: WaitForTx,
-    class_hash: Option<FieldElement>,
+    custom_class_hash: Option<FieldElement>,
 ) -> Result<InvokeResponse> {
     let network_name = chain_id_to_network_name(chain_id);
 
@@ -203,18 +210,29 @@ async fn deploy_from_accounts_file(
     }
     let account = &items[&network_name][&name];
 
-    let private_key = SigningKey::from_secret_scalar(
-        parse_number(
-            account
-                .get("private_key")
-                .and_then(serde_json::Value::as_str)
-                .context("Failed to get private key from accounts file")?,
-        )
-        .context("Failed to parse private key")?,
+    let get_str = |key: &str| account.get(key).and_then(serde_json::Value::as_str);
+    let (private_key, ledger_path, signer_command) = (
+        get_str("private_key"),
+        get_str("ledger_path"),
+        get_str("signer_command"),
     );
+    if private_key.is_none() && ledger_path.is_none() && signer_command.is_none() {
+        bail!("Failed to get private key from accounts file");
+    }
+    let signer = get_account_signer(private_key, ledger_path, signer_command).await?;
+    let public_key = signer
+        .get_public_key()
+        .await
+        .context("Failed to get public key of the account")?
+        .scalar();
+
+    let account_type = account
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .map_or(Ok(AccountType::Oz), AccountType::from_str)?;
 
-    let oz_class_hash = {
-        if let Some(class_hash_) = class_hash {
+    let class_hash = {
+        if let Some(class_hash_) = custom_class_hash {
             class_hash_
         } else if let Some(class_hash_) = account
             .get("class_hash")
@@ -223,21 +241,37 @@ async fn deploy_from_accounts_file(
             FieldElement::from_hex_be(class_hash_)
                 .expect("Failed to parse account class hash from accounts file")
         } else {
-            FieldElement::from_hex_be(OZ_CLASS_HASH).expect("Failed to parse OZ class hash")
+            account_type.default_class_hash()
         }
     };
 
-    let result = deploy_oz_account(
+    let salt = parse_number(
+        account
+            .get("salt")
+            .and_then(serde_json::Value::as_str)
+            .context("Failed to get salt from accounts file")?,
+    )
+    .context("Failed to parse salt")?;
+
+    if let Some(custom_class_hash) = custom_class_hash {
+        check_class_hash_exists(provider, custom_class_hash).await?;
+    }
+    if let Some(address) = account.get("address").and_then(serde_json::Value::as_str) {
+        let address = parse_number(address).context("Failed to parse address")?;
+        let computed_address = account_type.account_address(class_hash, salt, public_key);
+        if address != computed_address {
+            bail!(
+                "Address of account {name} = {address:#x} does not match address {computed_address:#x} computed with class hash = {class_hash:#x}"
+            );
+        }
+    }
+
+    let result = deploy_account(
         provider,
-        oz_class_hash,
-        private_key,
-        parse_number(
-            account
-                .get("salt")
-                .and_then(serde_json::Value::as_str)
-                .context("Failed to get salt from accounts file")?,
-        )
-        .context("Failed to parse salt")?,
+        account_type,
+        class_hash,
+        signer,
+        salt,
         chain_id,
         max_fee,
         wait_config,
@@ -251,23 +285,63 @@ async fn deploy_from_accounts_file(
     Ok(result)
 }
 
-async fn deploy_oz_account(
+#[allow(clippy::too_many_arguments)]
+async fn deploy_account(
     provider: &JsonRpcClient<HttpTransport>,
-    oz_class_hash: FieldElement,
-    private_key: SigningKey,
+    account_type: AccountType,
+    class_hash: FieldElement,
+    signer: AccountSigner,
     salt: FieldElement,
     chain_id: FieldElement,
     max_fee: Option<FieldElement>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse> {
-    let factory = OpenZeppelinAccountFactory::new(
-        oz_class_hash,
-        chain_id,
-        LocalWallet::from_signing_key(private_key),
-        provider,
-    )
-    .await?;
+    match account_type {
+        AccountType::Oz => {
+            let factory =
+                OpenZeppelinAccountFactory::new(class_hash, chain_id, signer, provider).await?;
+            deploy_account_with_factory(&factory, provider, class_hash, salt, max_fee, wait_config)
+                .await
+        }
+        AccountType::Argent => {
+            let factory = ArgentAccountFactory::new(
+                class_hash,
+                chain_id,
+                FieldElement::ZERO,
+                signer,
+                provider,
+            )
+            .await?;
+            deploy_account_with_factory(&factory, provider, class_hash, salt, max_fee, wait_config)
+                .await
+        }
+        AccountType::Braavos => {
+            let factory = BraavosAccountFactory::new(
+                class_hash,
+                FieldElement::from_hex_be(BRAAVOS_BASE_ACCOUNT_CLASS_HASH)
+                    .expect("Failed to parse Braavos base account class hash"),
+                chain_id,
+                signer,
+                provider,
+            )
+            .await?;
+            deploy_account_with_factory(&factory, provider, class_hash, salt, max_fee, wait_config)
+                .await
+        }
+    }
+}
 
+async fn deploy_account_with_factory<T>(
+    factory: &T,
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: FieldElement,
+    salt: FieldElement,
+    max_fee: Option<FieldElement>,
+    wait_config: WaitForTx,
+) -> Result<InvokeResponse>
+where
+    T: AccountFactory<SignError = AccountSignerError> + Sync,
+{
     let deployment = factory.deploy(salt);
     let deploy_max_fee = if let Some(max_fee) = max_fee {
         max_fee
@@ -283,10 +357,11 @@ async fn deploy_oz_account(
         Err(AccountFactoryError::Provider(error)) => match error {
             StarknetError(ClassHashNotFound) => Err(anyhow!(
                 "Provided class hash {:#x} does not exist",
-                oz_class_hash,
+                class_hash,
             )),
             _ => Err(handle_rpc_error(error)),
         },
+        Err(AccountFactoryError::Signing(error)) => Err(anyhow!(error)),
         Err(_) => Err(anyhow!("Unknown RPC error")),
         Ok(result) => {
             let return_value = InvokeResponse {
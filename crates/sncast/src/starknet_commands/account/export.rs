@@ -0,0 +1,141 @@
+use crate::starknet_commands::account::write_account_to_file;
+use anyhow::{anyhow, bail, Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use serde_json::json;
+use sncast::helpers::constants::CREATE_KEYSTORE_PASSWORD_ENV_VAR;
+use sncast::response::structs::AccountExportResponse;
+use sncast::{
+    check_account_file_exists, get_class_hash_by_address, get_keystore_password, parse_number,
+    AccountType,
+};
+use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
+use starknet::signers::SigningKey;
+use std::str::FromStr;
+
+#[derive(Args, Debug)]
+#[command(about = "Export an account from the accounts file to an encrypted keystore")]
+pub struct Export {
+    /// Name of the account to be exported
+    #[clap(short, long)]
+    pub name: String,
+
+    /// Network where the account exists; defaults to network of rpc node
+    #[clap(long)]
+    pub network: Option<String>,
+
+    /// Path of the keystore file to be created
+    #[clap(long = "keystore")]
+    pub keystore_path: Utf8PathBuf,
+
+    /// Path of the account JSON file to be created, to use the account with `--keystore` afterwards
+    #[clap(long = "account-file")]
+    pub account_file_path: Option<Utf8PathBuf>,
+}
+
+pub async fn export(
+    accounts_file: &Utf8PathBuf,
+    network_name: &str,
+    provider: &JsonRpcClient<HttpTransport>,
+    export: &Export,
+) -> Result<AccountExportResponse> {
+    let name = &export.name;
+    check_account_file_exists(accounts_file)?;
+    let contents =
+        std::fs::read_to_string(accounts_file).context("Failed to read accounts file")?;
+    let items: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|_| anyhow!("Failed to parse accounts file at {accounts_file}"))?;
+
+    if items[network_name].is_null() {
+        bail!("No accounts defined for network = {network_name}");
+    }
+    let account = &items[network_name][name];
+    if account.is_null() {
+        bail!("Account with name {name} does not exist");
+    }
+
+    let get_str = |key: &str| account.get(key).and_then(serde_json::Value::as_str);
+
+    let private_key = SigningKey::from_secret_scalar(
+        parse_number(get_str("private_key").context("Failed to get private key of the account")?)
+            .context("Failed to parse private key")?,
+    );
+
+    let keystore_path = &export.keystore_path;
+    if keystore_path.exists() {
+        bail!("Keystore file {keystore_path} already exists");
+    }
+    let account_file = match &export.account_file_path {
+        Some(account_file_path) => {
+            if account_file_path.exists() {
+                bail!("Account file {account_file_path} already exists");
+            }
+            let account_json =
+                prepare_keystore_account_json(name, account, &private_key, provider).await?;
+            Some((account_file_path, account_json))
+        }
+        None => None,
+    };
+
+    let password = get_keystore_password(CREATE_KEYSTORE_PASSWORD_ENV_VAR)?;
+    private_key.save_as_keystore(keystore_path, &password)?;
+    if let Some((account_file_path, account_json)) = account_file {
+        write_account_to_file(&account_json, account_file_path)?;
+    }
+
+    Ok(AccountExportResponse {
+        keystore: keystore_path.clone(),
+        account_file: export.account_file_path.clone(),
+    })
+}
+
+/// Prepares account JSON file in the format used with `--keystore`
+async fn prepare_keystore_account_json(
+    name: &str,
+    account: &serde_json::Value,
+    private_key: &SigningKey,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<serde_json::Value> {
+    let get_str = |key: &str| account.get(key).and_then(serde_json::Value::as_str);
+
+    let address = parse_number(get_str("address").context("Failed to get account address")?)
+        .context("Failed to parse address")?;
+    let deployed_class_hash = get_class_hash_by_address(provider, address).await?;
+    let class_hash = match get_str("class_hash") {
+        Some(class_hash) => parse_number(class_hash).context("Failed to parse class hash")?,
+        None => deployed_class_hash.with_context(|| {
+            format!("Failed to get class hash of account {name}, it is not deployed")
+        })?,
+    };
+    let account_type = get_str("type").map_or(Ok(AccountType::Oz), AccountType::from_str)?;
+    let legacy = account
+        .get("legacy")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    let deployed = deployed_class_hash.is_some();
+
+    let deployment = if deployed {
+        json!({
+            "status": "deployed",
+            "class_hash": format!("{class_hash:#x}"),
+            "address": format!("{address:#x}"),
+        })
+    } else {
+        json!({
+            "status": "undeployed",
+            "class_hash": format!("{class_hash:#x}"),
+            "salt": get_str("salt").context("Failed to get salt of undeployed account")?,
+        })
+    };
+
+    Ok(json!({
+        "version": 1,
+        "variant": {
+            "type": account_type.as_str(),
+            "version": 1,
+            "public_key": format!("{:#x}", private_key.verifying_key().scalar()),
+            "legacy": legacy,
+        },
+        "deployment": deployment,
+    }))
+}
@@ -0,0 +1,84 @@
+use anyhow::{anyhow, Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use serde_json::Value;
+use sncast::check_account_file_exists;
+use sncast::response::structs::{AccountListItem, AccountListResponse};
+use std::collections::BTreeMap;
+
+/// Section of the listing holding the account passed with `--keystore` and `--account`
+const KEYSTORE_SECTION: &str = "keystore";
+
+#[derive(Args, Debug)]
+#[command(about = "List all accounts from the accounts file")]
+pub struct List {}
+
+pub fn list(
+    accounts_file: &Utf8PathBuf,
+    keystore: Option<&Utf8PathBuf>,
+    account: &str,
+) -> Result<AccountListResponse> {
+    let mut accounts = BTreeMap::new();
+
+    if keystore.is_none() || accounts_file.exists() {
+        check_account_file_exists(accounts_file)?;
+        let contents =
+            std::fs::read_to_string(accounts_file).context("Failed to read accounts file")?;
+        let networks: BTreeMap<String, BTreeMap<String, Value>> =
+            serde_json::from_str(&contents)
+                .map_err(|_| anyhow!("Failed to parse accounts file at {accounts_file}"))?;
+
+        for (network, network_accounts) in networks {
+            let items = network_accounts
+                .into_iter()
+                .map(|(name, account)| (name, account_list_item(&account)))
+                .collect();
+            accounts.insert(network, items);
+        }
+    }
+
+    if keystore.is_some() {
+        let account_path = Utf8PathBuf::from(account);
+        let contents = std::fs::read_to_string(&account_path)
+            .with_context(|| format!("Failed to read account file at {account_path}"))?;
+        let account_json: Value = serde_json::from_str(&contents)
+            .map_err(|_| anyhow!("Failed to parse account file at {account_path}"))?;
+
+        accounts.insert(
+            KEYSTORE_SECTION.to_string(),
+            BTreeMap::from([(account.to_string(), keystore_list_item(&account_json))]),
+        );
+    }
+
+    Ok(AccountListResponse { accounts })
+}
+
+fn account_list_item(account: &Value) -> AccountListItem {
+    let get_str = |key: &str| account.get(key).and_then(Value::as_str).map(String::from);
+
+    AccountListItem {
+        address: get_str("address"),
+        class_hash: get_str("class_hash"),
+        deployed: account.get("deployed").and_then(Value::as_bool),
+        account_type: get_str("type"),
+        keystore: false,
+    }
+}
+
+fn keystore_list_item(account: &Value) -> AccountListItem {
+    let get_str = |parent: &str, key: &str| {
+        account
+            .get(parent)
+            .and_then(|parent| parent.get(key))
+            .and_then(Value::as_str)
+            .map(String::from)
+    };
+
+    AccountListItem {
+        address: get_str("deployment", "address"),
+        class_hash: get_str("deployment", "class_hash"),
+        deployed: get_str("deployment", "status").map(|status| status == "deployed"),
+        account_type: get_str("variant", "type"),
+        keystore: true,
+    }
+}
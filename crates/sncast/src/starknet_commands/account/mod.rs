@@ -2,6 +2,8 @@ use crate::starknet_commands::account::add::Add;
 use crate::starknet_commands::account::create::Create;
 use crate::starknet_commands::account::delete::Delete;
 use crate::starknet_commands::account::deploy::Deploy;
+use crate::starknet_commands::account::export::Export;
+use crate::starknet_commands::account::list::List;
 use anyhow::{anyhow, bail, Context, Result};
 use camino::Utf8PathBuf;
 use clap::{Args, Subcommand};
@@ -18,6 +20,8 @@ pub mod add;
 pub mod create;
 pub mod delete;
 pub mod deploy;
+pub mod export;
+pub mod list;
 
 #[derive(Args)]
 #[command(about = "Creates and deploys an account to the Starknet")]
@@ -28,14 +32,42 @@ pub struct Account {
 
 #[derive(Debug, Subcommand)]
 pub enum Commands {
+    #[command(visible_alias = "import")]
     Add(Add),
     Create(Create),
     Deploy(Deploy),
     Delete(Delete),
+    List(List),
+    Export(Export),
+}
+
+/// Key used to sign transactions of the account
+pub enum AccountKey {
+    PrivateKey(SigningKey),
+    Ledger {
+        path: String,
+        public_key: FieldElement,
+    },
+    External {
+        command: String,
+        public_key: FieldElement,
+    },
+}
+
+impl AccountKey {
+    #[must_use]
+    pub fn public_key(&self) -> FieldElement {
+        match self {
+            AccountKey::PrivateKey(private_key) => private_key.verifying_key().scalar(),
+            AccountKey::Ledger { public_key, .. } | AccountKey::External { public_key, .. } => {
+                *public_key
+            }
+        }
+    }
 }
 
 pub fn prepare_account_json(
-    private_key: &SigningKey,
+    account_key: &AccountKey,
     address: FieldElement,
     deployed: bool,
     legacy: bool,
@@ -43,13 +75,25 @@ pub fn prepare_account_json(
     salt: Option<FieldElement>,
 ) -> serde_json::Value {
     let mut account_json = json!({
-        "private_key": format!("{:#x}", private_key.secret_scalar()),
-        "public_key": format!("{:#x}", private_key.verifying_key().scalar()),
+        "public_key": format!("{:#x}", account_key.public_key()),
         "address": format!("{address:#x}"),
         "deployed": deployed,
         "legacy": legacy,
     });
 
+    match account_key {
+        AccountKey::PrivateKey(private_key) => {
+            account_json["private_key"] =
+                serde_json::Value::String(format!("{:#x}", private_key.secret_scalar()));
+        }
+        AccountKey::Ledger { path, .. } => {
+            account_json["ledger_path"] = serde_json::Value::String(path.clone());
+        }
+        AccountKey::External { command, .. } => {
+            account_json["signer_command"] = serde_json::Value::String(command.clone());
+        }
+    }
+
     if let Some(salt) = salt {
         account_json["salt"] = serde_json::Value::String(format!("{salt:#x}"));
     }
@@ -94,6 +138,18 @@ pub fn write_account_to_accounts_file(
     Ok(())
 }
 
+pub fn write_account_to_file(
+    account_json: &serde_json::Value,
+    account_file: &Utf8PathBuf,
+) -> Result<()> {
+    std::fs::create_dir_all(account_file.clone().parent().unwrap())?;
+    std::fs::write(
+        account_file.clone(),
+        serde_json::to_string_pretty(&account_json).unwrap(),
+    )?;
+    Ok(())
+}
+
 pub fn add_created_profile_to_configuration(
     profile: &Option<String>,
     cast_config: &CastConfig,
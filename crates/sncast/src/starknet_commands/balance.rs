@@ -0,0 +1,62 @@
+use anyhow::{anyhow, Context, Result};
+use clap::Args;
+use sncast::helpers::token::{call_erc20, format_units, get_decimals, u256_from_felts, Token};
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::BalanceResponse;
+use starknet::core::types::{BlockId, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+
+#[derive(Args)]
+#[command(about = "Fetch the token balance of an address or of the account", long_about = None)]
+pub struct Balance {
+    /// Address to fetch the balance of; defaults to the address of the account
+    #[clap(long)]
+    pub address: Option<FieldElement>,
+
+    /// Token to fetch the balance of: eth, strk or an ERC20 token address
+    #[clap(short, long, default_value = "eth")]
+    pub token: Token,
+
+    /// Block identifier on which balance should be fetched.
+    /// Possible values: pending, latest, block hash (0x prefixed string)
+    /// and block number (u64).
+    /// Defaults to `default-block-id` from the profile, or pending if not set
+    #[clap(short, long)]
+    pub block_id: Option<String>,
+}
+
+pub async fn balance(
+    address: FieldElement,
+    token: Token,
+    provider: &JsonRpcClient<HttpTransport>,
+    block_id: &BlockId,
+) -> Result<BalanceResponse, StarknetCommandError> {
+    let token_address = token.address();
+
+    let balance = call_erc20(
+        provider,
+        token_address,
+        &["balance_of", "balanceOf"],
+        vec![address],
+        block_id,
+    )
+    .await
+    .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let [low, high] = balance[..] else {
+        return Err(anyhow!("Invalid balance returned by token = {token_address:#x}").into());
+    };
+    let balance = u256_from_felts(low, high)
+        .with_context(|| format!("Invalid balance returned by token = {token_address:#x}"))?;
+
+    let decimals = get_decimals(token, provider, block_id).await?;
+
+    let formatted_balance = format_units(balance, decimals);
+    Ok(BalanceResponse {
+        balance: balance.to_string(),
+        formatted_balance: match token.symbol() {
+            Some(symbol) => format!("{formatted_balance} {symbol}"),
+            None => formatted_balance,
+        },
+    })
+}
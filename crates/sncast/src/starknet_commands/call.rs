@@ -1,5 +1,6 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Args;
+use sncast::helpers::arguments::{function_parameters, serialize_arguments, sierra_abi};
 use sncast::response::errors::StarknetCommandError;
 use sncast::response::structs::{CallResponse, Felt};
 use starknet::core::types::{BlockId, FieldElement, FunctionCall};
@@ -21,11 +22,42 @@ pub struct Call {
     #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
     pub calldata: Vec<FieldElement>,
 
+    /// Arguments of the called function in a human readable form,
+    /// e.g. `Point { x: 1, y: 2 }, array![1, 2]`, serialized according to the ABI of the contract
+    #[clap(long, conflicts_with = "calldata")]
+    pub arguments: Option<String>,
+
     /// Block identifier on which call should be performed.
     /// Possible values: pending, latest, block hash (0x prefixed string)
-    /// and block number (u64)
-    #[clap(short, long, default_value = "pending")]
-    pub block_id: String,
+    /// and block number (u64).
+    /// Defaults to `default-block-id` from the profile, or pending if not set
+    #[clap(short, long)]
+    pub block_id: Option<String>,
+}
+
+/// Calldata of `function` of the contract, serialized from human readable `arguments`
+/// if they are passed, `calldata` as is otherwise
+pub async fn function_calldata(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract_address: FieldElement,
+    function: &str,
+    arguments: Option<&str>,
+    calldata: Vec<FieldElement>,
+    block_id: &BlockId,
+) -> Result<Vec<FieldElement>, StarknetCommandError> {
+    let Some(arguments) = arguments else {
+        return Ok(calldata);
+    };
+
+    let class = provider
+        .get_class_at(block_id, contract_address)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let abi = sierra_abi(&class)?;
+
+    let parameters = function_parameters(&abi, function)?;
+    Ok(serialize_arguments(arguments, &parameters, &abi)
+        .with_context(|| format!("Failed to serialize arguments of {function}"))?)
 }
 
 #[allow(clippy::ptr_arg)]
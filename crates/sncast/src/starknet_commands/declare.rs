@@ -1,77 +1,84 @@
+use crate::starknet_commands::simulation::simulation_response;
 use anyhow::{anyhow, Context, Result};
+use camino::Utf8PathBuf;
 use clap::Args;
 use scarb_api::StarknetContractArtifacts;
-use sncast::response::structs::DeclareResponse;
 use sncast::response::structs::Felt;
+use sncast::response::structs::{DeclareResponse, FeeEstimateResponse, SimulationResponse};
 use sncast::{apply_optional, handle_wait_for_tx, ErrorData, WaitForTx};
-use starknet::accounts::AccountError::Provider;
+use starknet::accounts::AccountError::{Provider, Signing};
 use starknet::accounts::{ConnectedAccount, Declaration};
 
+use sncast::helpers::account::CastAccount;
+use sncast::helpers::nonce_manager::allocate_nonce;
 use sncast::response::errors::StarknetCommandError;
 use starknet::core::types::FieldElement;
 use starknet::{
-    accounts::{Account, SingleOwnerAccount},
+    accounts::Account,
     core::types::contract::{CompiledClass, SierraClass},
     providers::jsonrpc::{HttpTransport, JsonRpcClient},
-    signers::LocalWallet,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
+use universal_sierra_compiler_api::{compile_sierra, SierraType};
 
 #[derive(Args)]
 #[command(about = "Declare a contract to starknet", long_about = None)]
 pub struct Declare {
     /// Contract name
-    #[clap(short = 'c', long = "contract-name")]
-    pub contract: String,
+    #[clap(
+        short = 'c',
+        long = "contract-name",
+        required_unless_present = "sierra_file"
+    )]
+    pub contract: Option<String>,
 
     /// Max fee for the transaction. If not provided, max fee will be automatically estimated
     #[clap(short, long)]
     pub max_fee: Option<FieldElement>,
 
-    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    /// Nonce of the transaction. If not provided, the pending nonce of the account will be used
     #[clap(short, long)]
     pub nonce: Option<FieldElement>,
 
+    /// Estimate the fee of the transaction without sending it
+    #[clap(long, conflicts_with = "max_fee")]
+    pub estimate_only: bool,
+
+    /// Simulate the transaction and print its execution trace, state diff and resources
+    /// without sending it
+    #[clap(long, conflicts_with = "estimate_only")]
+    pub simulate: bool,
+
     /// Specifies scarb package to be used
     #[clap(long)]
     pub package: Option<String>,
+
+    /// Path to the sierra artifact of the contract, declared without building the package
+    #[clap(long, conflicts_with_all = ["contract", "package"])]
+    pub sierra_file: Option<Utf8PathBuf>,
+
+    /// Path to the casm artifact of the contract. If not provided, it will be compiled from
+    /// the sierra artifact
+    #[clap(long, requires = "sierra_file")]
+    pub casm_file: Option<Utf8PathBuf>,
 }
 
-#[allow(clippy::too_many_lines)]
 pub async fn declare(
-    contract_name: &str,
+    contract_artifacts: &StarknetContractArtifacts,
     max_fee: Option<FieldElement>,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
     nonce: Option<FieldElement>,
-    artifacts: &HashMap<String, StarknetContractArtifacts>,
     wait_config: WaitForTx,
 ) -> Result<DeclareResponse, StarknetCommandError> {
-    let contract_name: String = contract_name.to_string();
-    let contract_artifacts =
-        artifacts
-            .get(&contract_name)
-            .ok_or(StarknetCommandError::ContractArtifactsNotFound(
-                ErrorData::new(contract_name),
-            ))?;
-
-    let contract_definition: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
-        .context("Failed to parse sierra artifact")?;
-    let casm_contract_definition: CompiledClass =
-        serde_json::from_str(&contract_artifacts.casm).context("Failed to parse casm artifact")?;
-
-    let casm_class_hash = casm_contract_definition
-        .class_hash()
-        .map_err(anyhow::Error::from)?;
-
-    let declaration = account.declare(
-        Arc::new(contract_definition.flatten().map_err(anyhow::Error::from)?),
-        casm_class_hash,
-    );
+    let declaration = build_declaration(contract_artifacts, account)?;
+    let (mut nonce_manager, nonce) = allocate_nonce(account, nonce).await?;
 
     let declaration = apply_optional(declaration, max_fee, Declaration::max_fee);
-    let declaration = apply_optional(declaration, nonce, Declaration::nonce);
-    let declared = declaration.send().await;
+    let declared = declaration.nonce(nonce).send().await;
+    nonce_manager.record_sent(nonce, &declared)?;
+    drop(nonce_manager);
+
     match declared {
         Ok(result) => handle_wait_for_tx(
             account.provider(),
@@ -85,6 +92,94 @@ pub async fn declare(
         .await
         .map_err(StarknetCommandError::from),
         Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
+
+pub async fn estimate_declare(
+    contract_artifacts: &StarknetContractArtifacts,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<FeeEstimateResponse, StarknetCommandError> {
+    let declaration = build_declaration(contract_artifacts, account)?;
+    let declaration = apply_optional(declaration, nonce, Declaration::nonce);
+
+    match declaration.estimate_fee().await {
+        Ok(fee_estimate) => Ok(fee_estimate.into()),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub async fn simulate_declare(
+    contract_artifacts: &StarknetContractArtifacts,
+    max_fee: Option<FieldElement>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<SimulationResponse, StarknetCommandError> {
+    let declaration = build_declaration(contract_artifacts, account)?;
+    let declaration = apply_optional(declaration, max_fee, Declaration::max_fee);
+    let declaration = apply_optional(declaration, nonce, Declaration::nonce);
+
+    match declaration.simulate(false, false).await {
+        Ok(simulated) => simulation_response(simulated),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub fn get_contract_artifacts<'a>(
+    contract_name: &str,
+    artifacts: &'a HashMap<String, StarknetContractArtifacts>,
+) -> Result<&'a StarknetContractArtifacts, StarknetCommandError> {
+    let contract_name: String = contract_name.to_string();
+    artifacts
+        .get(&contract_name)
+        .ok_or(StarknetCommandError::ContractArtifactsNotFound(
+            ErrorData::new(contract_name),
+        ))
+}
+
+/// Reads the artifacts of a contract built outside of Scarb, compiling the casm artifact
+/// from the sierra one if its path is not provided
+pub fn load_contract_artifacts(
+    sierra_file: &Utf8PathBuf,
+    casm_file: Option<&Utf8PathBuf>,
+) -> Result<StarknetContractArtifacts, StarknetCommandError> {
+    let sierra = std::fs::read_to_string(sierra_file)
+        .with_context(|| format!("Failed to read sierra file = {sierra_file}"))?;
+    let casm = match casm_file {
+        Some(casm_file) => std::fs::read_to_string(casm_file)
+            .with_context(|| format!("Failed to read casm file = {casm_file}"))?,
+        None => compile_sierra(
+            &serde_json::from_str(&sierra).context("Failed to parse sierra artifact")?,
+            None,
+            &SierraType::Contract,
+        )
+        .context("Failed to compile sierra artifact to casm")?,
+    };
+
+    Ok(StarknetContractArtifacts { sierra, casm })
+}
+
+fn build_declaration<'a, 'p>(
+    contract_artifacts: &StarknetContractArtifacts,
+    account: &'a CastAccount<&'p JsonRpcClient<HttpTransport>>,
+) -> Result<Declaration<'a, CastAccount<&'p JsonRpcClient<HttpTransport>>>, StarknetCommandError> {
+    let contract_definition: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
+        .context("Failed to parse sierra artifact")?;
+    let casm_contract_definition: CompiledClass =
+        serde_json::from_str(&contract_artifacts.casm).context("Failed to parse casm artifact")?;
+
+    let casm_class_hash = casm_contract_definition
+        .class_hash()
+        .map_err(anyhow::Error::from)?;
+
+    Ok(account.declare(
+        Arc::new(contract_definition.flatten().map_err(anyhow::Error::from)?),
+        casm_class_hash,
+    ))
+}
@@ -1,14 +1,17 @@
-use anyhow::{anyhow, Result};
+use crate::starknet_commands::simulation::simulation_response;
+use anyhow::{anyhow, Context, Result};
 use clap::Args;
-use sncast::response::structs::{DeployResponse, Felt};
-use starknet::accounts::AccountError::Provider;
-use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
+use sncast::helpers::account::CastAccount;
+use sncast::helpers::arguments::{constructor_parameters, serialize_arguments, sierra_abi};
+use sncast::helpers::nonce_manager::allocate_nonce;
+use sncast::response::structs::{DeployResponse, FeeEstimateResponse, Felt, SimulationResponse};
+use starknet::accounts::AccountError::{Provider, Signing};
+use starknet::accounts::{Account, ConnectedAccount};
 use starknet::contract::ContractFactory;
-use starknet::core::types::FieldElement;
+use starknet::core::types::{BlockId, BlockTag::Pending, FieldElement};
 use starknet::core::utils::get_udc_deployed_address;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
-use starknet::signers::LocalWallet;
+use starknet::providers::{JsonRpcClient, Provider};
 
 use sncast::response::errors::StarknetCommandError;
 use sncast::{extract_or_generate_salt, udc_uniqueness};
@@ -25,6 +28,11 @@ pub struct Deploy {
     #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
     pub constructor_calldata: Vec<FieldElement>,
 
+    /// Constructor arguments in a human readable form, e.g. `0x123, u256:1000, "name"`,
+    /// serialized according to the ABI of the class
+    #[clap(long, conflicts_with = "constructor_calldata")]
+    pub arguments: Option<String>,
+
     /// Salt for the address
     #[clap(short, long)]
     pub salt: Option<FieldElement>,
@@ -37,9 +45,41 @@ pub struct Deploy {
     #[clap(short, long)]
     pub max_fee: Option<FieldElement>,
 
-    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    /// Nonce of the transaction. If not provided, the pending nonce of the account will be used
     #[clap(short, long)]
     pub nonce: Option<FieldElement>,
+
+    /// Estimate the fee of the transaction without sending it
+    #[clap(long, conflicts_with = "max_fee")]
+    pub estimate_only: bool,
+
+    /// Simulate the transaction and print its execution trace, state diff and resources
+    /// without sending it
+    #[clap(long, conflicts_with = "estimate_only")]
+    pub simulate: bool,
+}
+
+/// Calldata of the constructor of the class, serialized from human readable `arguments`
+/// if they are passed, `constructor_calldata` as is otherwise
+pub async fn constructor_calldata(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: FieldElement,
+    arguments: Option<&str>,
+    constructor_calldata: Vec<FieldElement>,
+) -> Result<Vec<FieldElement>, StarknetCommandError> {
+    let Some(arguments) = arguments else {
+        return Ok(constructor_calldata);
+    };
+
+    let class = provider
+        .get_class(BlockId::Tag(Pending), class_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let abi = sierra_abi(&class)?;
+
+    let parameters = constructor_parameters(&abi)?;
+    Ok(serialize_arguments(arguments, &parameters, &abi)
+        .context("Failed to serialize constructor arguments")?)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -49,13 +89,16 @@ pub async fn deploy(
     salt: Option<FieldElement>,
     unique: bool,
     max_fee: Option<FieldElement>,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
     nonce: Option<FieldElement>,
     wait_config: WaitForTx,
 ) -> Result<DeployResponse, StarknetCommandError> {
     let salt = extract_or_generate_salt(salt);
     let factory = ContractFactory::new(class_hash, account);
-    let execution = factory.deploy(constructor_calldata.clone(), salt, unique);
+    let (mut nonce_manager, nonce) = allocate_nonce(account, nonce).await?;
+    let execution = factory
+        .deploy(constructor_calldata.clone(), salt, unique)
+        .nonce(nonce);
 
     // TODO(#1396): use apply_optional here when `Deployment` in starknet-rs is public
     //  otherwise we cannot pass the necessary reference to a function
@@ -65,13 +108,10 @@ pub async fn deploy(
         execution
     };
 
-    let execution = if let Some(nonce) = nonce {
-        execution.nonce(nonce)
-    } else {
-        execution
-    };
-
     let result = execution.send().await;
+    nonce_manager.record_sent(nonce, &result)?;
+    drop(nonce_manager);
+
     match result {
         Ok(result) => handle_wait_for_tx(
             account.provider(),
@@ -90,6 +130,66 @@ pub async fn deploy(
         .await
         .map_err(StarknetCommandError::from),
         Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub async fn estimate_deploy(
+    class_hash: FieldElement,
+    constructor_calldata: Vec<FieldElement>,
+    salt: Option<FieldElement>,
+    unique: bool,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<FeeEstimateResponse, StarknetCommandError> {
+    let salt = extract_or_generate_salt(salt);
+    let factory = ContractFactory::new(class_hash, account);
+    let execution = factory.deploy(constructor_calldata, salt, unique);
+
+    let execution = if let Some(nonce) = nonce {
+        execution.nonce(nonce)
+    } else {
+        execution
+    };
+
+    match execution.estimate_fee().await {
+        Ok(fee_estimate) => Ok(fee_estimate.into()),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub async fn simulate_deploy(
+    class_hash: FieldElement,
+    constructor_calldata: Vec<FieldElement>,
+    salt: Option<FieldElement>,
+    unique: bool,
+    max_fee: Option<FieldElement>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<SimulationResponse, StarknetCommandError> {
+    let salt = extract_or_generate_salt(salt);
+    let factory = ContractFactory::new(class_hash, account);
+    let execution = factory.deploy(constructor_calldata, salt, unique);
+
+    let execution = if let Some(max_fee) = max_fee {
+        execution.max_fee(max_fee)
+    } else {
+        execution
+    };
+
+    let execution = if let Some(nonce) = nonce {
+        execution.nonce(nonce)
+    } else {
+        execution
+    };
+
+    match execution.simulate(false, false).await {
+        Ok(simulated) => simulation_response(simulated),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
@@ -1,15 +1,17 @@
 use anyhow::{anyhow, Result};
 use clap::Args;
 
+use crate::starknet_commands::simulation::simulation_response;
+use sncast::helpers::account::CastAccount;
+use sncast::helpers::nonce_manager::allocate_nonce;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::{Felt, InvokeResponse};
+use sncast::response::structs::{FeeEstimateResponse, Felt, InvokeResponse, SimulationResponse};
 use sncast::{apply_optional, handle_wait_for_tx, WaitForTx};
-use starknet::accounts::AccountError::Provider;
-use starknet::accounts::{Account, Call, ConnectedAccount, Execution, SingleOwnerAccount};
+use starknet::accounts::AccountError::{Provider, Signing};
+use starknet::accounts::{Account, Call, ConnectedAccount, Execution};
 use starknet::core::types::FieldElement;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
-use starknet::signers::LocalWallet;
 
 #[derive(Args)]
 #[command(about = "Invoke a contract on Starknet")]
@@ -26,13 +28,27 @@ pub struct Invoke {
     #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
     pub calldata: Vec<FieldElement>,
 
+    /// Arguments of the invoked function in a human readable form,
+    /// e.g. `Point { x: 1, y: 2 }, array![1, 2]`, serialized according to the ABI of the contract
+    #[clap(long, conflicts_with = "calldata")]
+    pub arguments: Option<String>,
+
     /// Max fee for the transaction. If not provided, max fee will be automatically estimated
     #[clap(short, long)]
     pub max_fee: Option<FieldElement>,
 
-    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    /// Nonce of the transaction. If not provided, the pending nonce of the account will be used
     #[clap(short, long)]
     pub nonce: Option<FieldElement>,
+
+    /// Estimate the fee of the transaction without sending it
+    #[clap(long, conflicts_with = "max_fee")]
+    pub estimate_only: bool,
+
+    /// Simulate the transaction and print its execution trace, state diff and resources
+    /// without sending it
+    #[clap(long, conflicts_with = "estimate_only")]
+    pub simulate: bool,
 }
 
 pub async fn invoke(
@@ -40,7 +56,7 @@ pub async fn invoke(
     function_selector: FieldElement,
     calldata: Vec<FieldElement>,
     max_fee: Option<FieldElement>,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
     nonce: Option<FieldElement>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse, StarknetCommandError> {
@@ -54,18 +70,22 @@ pub async fn invoke(
 }
 
 pub async fn execute_calls(
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
     calls: Vec<Call>,
     max_fee: Option<FieldElement>,
     nonce: Option<FieldElement>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse, StarknetCommandError> {
-    let execution_calls = account.execute(calls);
+    let (mut nonce_manager, nonce) = allocate_nonce(account, nonce).await?;
+    let execution_calls = account.execute(calls).nonce(nonce);
 
     let execution = apply_optional(execution_calls, max_fee, Execution::max_fee);
-    let execution = apply_optional(execution, nonce, Execution::nonce);
 
-    match execution.send().await {
+    let result = execution.send().await;
+    nonce_manager.record_sent(nonce, &result)?;
+    drop(nonce_manager);
+
+    match result {
         Ok(result) => handle_wait_for_tx(
             account.provider(),
             result.transaction_hash,
@@ -77,6 +97,74 @@ pub async fn execute_calls(
         .await
         .map_err(StarknetCommandError::from),
         Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub async fn estimate_invoke(
+    contract_address: FieldElement,
+    function_selector: FieldElement,
+    calldata: Vec<FieldElement>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<FeeEstimateResponse, StarknetCommandError> {
+    let call = Call {
+        to: contract_address,
+        selector: function_selector,
+        calldata,
+    };
+
+    estimate_calls(account, vec![call], nonce).await
+}
+
+pub async fn estimate_calls(
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    calls: Vec<Call>,
+    nonce: Option<FieldElement>,
+) -> Result<FeeEstimateResponse, StarknetCommandError> {
+    let execution = account.execute(calls);
+    let execution = apply_optional(execution, nonce, Execution::nonce);
+
+    match execution.estimate_fee().await {
+        Ok(fee_estimate) => Ok(fee_estimate.into()),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+pub async fn simulate_invoke(
+    contract_address: FieldElement,
+    function_selector: FieldElement,
+    calldata: Vec<FieldElement>,
+    max_fee: Option<FieldElement>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<SimulationResponse, StarknetCommandError> {
+    let call = Call {
+        to: contract_address,
+        selector: function_selector,
+        calldata,
+    };
+
+    simulate_calls(account, vec![call], max_fee, nonce).await
+}
+
+pub async fn simulate_calls(
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    calls: Vec<Call>,
+    max_fee: Option<FieldElement>,
+    nonce: Option<FieldElement>,
+) -> Result<SimulationResponse, StarknetCommandError> {
+    let execution = account.execute(calls);
+    let execution = apply_optional(execution, max_fee, Execution::max_fee);
+    let execution = apply_optional(execution, nonce, Execution::nonce);
+
+    match execution.simulate(false, false).await {
+        Ok(simulated) => simulation_response(simulated),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
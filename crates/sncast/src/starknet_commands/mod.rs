@@ -1,8 +1,14 @@
 pub mod account;
+pub mod balance;
 pub mod call;
 pub mod declare;
 pub mod deploy;
 pub mod invoke;
 pub mod multicall;
+pub mod receipt;
 pub mod script;
 pub mod show_config;
+pub mod simulation;
+pub mod transfer;
+pub mod tx_status;
+pub mod verify;
@@ -1,30 +1,43 @@
-use crate::starknet_commands::invoke::execute_calls;
-use anyhow::{Context, Result};
+use crate::starknet_commands::invoke::{estimate_calls, execute_calls};
+use crate::starknet_commands::simulation::executed_calls;
+use anyhow::{anyhow, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde::Deserialize;
+use sncast::helpers::account::CastAccount;
 use sncast::helpers::constants::UDC_ADDRESS;
-use sncast::response::errors::handle_starknet_command_error;
-use sncast::response::structs::InvokeResponse;
-use sncast::{extract_or_generate_salt, parse_number, udc_uniqueness, WaitForTx};
-use starknet::accounts::{Account, Call, SingleOwnerAccount};
+use sncast::helpers::formatting::format_felts;
+use sncast::response::errors::{handle_starknet_command_error, StarknetCommandError};
+use sncast::response::structs::{
+    Felt, InvocationResponse, InvokeResponse, MulticallDryRunResponse,
+};
+use sncast::{apply_optional, extract_or_generate_salt, parse_number, udc_uniqueness, WaitForTx};
+use starknet::accounts::AccountError::{Provider, Signing};
+use starknet::accounts::{Account, Call, Execution, ExecutionEncoder};
 use starknet::core::types::FieldElement;
 use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
-use starknet::signers::LocalWallet;
 use std::collections::HashMap;
 
 #[derive(Args, Debug)]
-#[command(about = "Execute a multicall from a .toml file", long_about = None)]
+#[command(about = "Execute a multicall from a .toml or .json file", long_about = None)]
 pub struct Run {
-    /// Path to the toml file with declared operations
+    /// Path to the toml or json file with declared operations
     #[clap(short = 'p', long = "path")]
     pub path: Utf8PathBuf,
 
     /// Max fee for the transaction. If not provided, max fee will be automatically estimated
     #[clap(short, long)]
     pub max_fee: Option<FieldElement>,
+
+    /// Nonce of the transaction. If not provided, the pending nonce of the account will be used
+    #[clap(short, long)]
+    pub nonce: Option<FieldElement>,
+
+    /// Resolve the calls and estimate the fee without sending the transaction
+    #[clap(long, conflicts_with = "max_fee")]
+    pub dry_run: bool,
 }
 
 #[allow(dead_code)]
@@ -45,20 +58,77 @@ struct InvokeCall {
     contract_address: String,
     function: String,
     inputs: Vec<String>,
+    id: Option<String>,
+}
+
+/// Call of the multicall with its human readable description
+struct ResolvedCall {
+    call: Call,
+    description: String,
+}
+
+/// Outputs of the invoke calls with an `id`, resolved by simulating the calls preceding
+/// the first call which references them
+#[derive(Default)]
+struct CallOutputs {
+    call_indexes: HashMap<String, usize>,
+    values: HashMap<String, Vec<FieldElement>>,
 }
 
 pub async fn run(
     path: &Utf8PathBuf,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
     max_fee: Option<FieldElement>,
+    nonce: Option<FieldElement>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse> {
-    let contents = std::fs::read_to_string(path)?;
-    let items_map: HashMap<String, Vec<toml::Value>> =
-        toml::from_str(&contents).with_context(|| format!("Failed to parse {path}"))?;
+    let calls = resolve_calls(path, account, nonce)
+        .await?
+        .into_iter()
+        .map(|resolved| resolved.call)
+        .collect();
+
+    execute_calls(account, calls, max_fee, nonce, wait_config)
+        .await
+        .map_err(handle_starknet_command_error)
+}
+
+pub async fn dry_run(
+    path: &Utf8PathBuf,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<MulticallDryRunResponse> {
+    let (calls, descriptions): (Vec<Call>, Vec<String>) = resolve_calls(path, account, nonce)
+        .await?
+        .into_iter()
+        .map(|resolved| (resolved.call, resolved.description))
+        .unzip();
+    let calldata = account.encode_calls(&calls).into_iter().map(Felt).collect();
+
+    let fee_estimate = estimate_calls(account, calls, nonce)
+        .await
+        .map_err(handle_starknet_command_error)?;
+
+    Ok(MulticallDryRunResponse {
+        calls: descriptions,
+        calldata,
+        overall_fee: fee_estimate.overall_fee,
+        unit: fee_estimate.unit,
+        gas_consumed: fee_estimate.gas_consumed,
+        data_gas_consumed: fee_estimate.data_gas_consumed,
+    })
+}
+
+async fn resolve_calls(
+    path: &Utf8PathBuf,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<Vec<ResolvedCall>> {
+    let items_map = read_calls_file(path)?;
 
     let mut contracts = HashMap::new();
-    let mut parsed_calls: Vec<Call> = vec![];
+    let mut outputs = CallOutputs::default();
+    let mut parsed_calls: Vec<ResolvedCall> = vec![];
 
     for call in items_map.get("call").unwrap_or(&vec![]) {
         let call_type = call.get("call_type");
@@ -69,49 +139,88 @@ pub async fn run(
         match call_type.unwrap().as_str() {
             Some("deploy") => {
                 let deploy_call: DeployCall = toml::from_str(toml::to_string(&call)?.as_str())
-                    .context("Failed to parse toml `deploy` call")?;
+                    .context("Failed to parse `deploy` call")?;
+
+                resolve_outputs(
+                    &deploy_call.inputs,
+                    &parsed_calls,
+                    &mut outputs,
+                    account,
+                    nonce,
+                )
+                .await?;
+                let parsed_inputs = parse_inputs(&deploy_call.inputs, &contracts, &outputs.values)?;
 
                 let salt = extract_or_generate_salt(deploy_call.salt);
                 let mut calldata = vec![
                     deploy_call.class_hash,
                     salt,
                     FieldElement::from(u8::from(deploy_call.unique)),
-                    deploy_call.inputs.len().into(),
+                    parsed_inputs.len().into(),
                 ];
-
-                let parsed_inputs = parse_inputs(&deploy_call.inputs, &contracts)?;
                 calldata.extend(&parsed_inputs);
 
-                parsed_calls.push(Call {
-                    to: parse_number(UDC_ADDRESS)?,
-                    selector: get_selector_from_name("deployContract")?,
-                    calldata,
-                });
-
                 let contract_address = get_udc_deployed_address(
                     salt,
                     deploy_call.class_hash,
                     &udc_uniqueness(deploy_call.unique, account.address()),
                     &parsed_inputs,
                 );
+
+                ensure_unique_id(&deploy_call.id, &contracts, &outputs)?;
+                let udc_address = parse_number(UDC_ADDRESS)?;
+                parsed_calls.push(ResolvedCall {
+                    description: format!(
+                        "deploy `{}` at {contract_address:#x}: {udc_address:#x}::deployContract({})",
+                        deploy_call.id,
+                        format_felts(&calldata)
+                    ),
+                    call: Call {
+                        to: udc_address,
+                        selector: get_selector_from_name("deployContract")?,
+                        calldata,
+                    },
+                });
                 contracts.insert(deploy_call.id, contract_address.to_string());
             }
             Some("invoke") => {
                 let invoke_call: InvokeCall = toml::from_str(toml::to_string(&call)?.as_str())
-                    .context("Failed to parse toml `invoke` call")?;
+                    .context("Failed to parse `invoke` call")?;
                 let mut contract_address = &invoke_call.contract_address;
                 if let Some(addr) = contracts.get(&invoke_call.contract_address) {
                     contract_address = addr;
                 }
 
-                let calldata = parse_inputs(&invoke_call.inputs, &contracts)?;
+                resolve_outputs(
+                    &invoke_call.inputs,
+                    &parsed_calls,
+                    &mut outputs,
+                    account,
+                    nonce,
+                )
+                .await?;
+                let calldata = parse_inputs(&invoke_call.inputs, &contracts, &outputs.values)?;
+                let contract_address = parse_number(contract_address)
+                    .context("Failed to parse contract address to FieldElement")?;
+                if let Some(id) = &invoke_call.id {
+                    ensure_unique_id(id, &contracts, &outputs)?;
+                }
 
-                parsed_calls.push(Call {
-                    to: parse_number(contract_address)
-                        .context("Failed to parse contract address to FieldElement")?,
-                    selector: get_selector_from_name(&invoke_call.function)?,
-                    calldata,
+                parsed_calls.push(ResolvedCall {
+                    description: format!(
+                        "invoke {contract_address:#x}::{}({})",
+                        invoke_call.function,
+                        format_felts(&calldata)
+                    ),
+                    call: Call {
+                        to: contract_address,
+                        selector: get_selector_from_name(&invoke_call.function)?,
+                        calldata,
+                    },
                 });
+                if let Some(id) = invoke_call.id {
+                    outputs.call_indexes.insert(id, parsed_calls.len() - 1);
+                }
             }
             Some(unsupported) => {
                 anyhow::bail!("Unsupported call type found = {}", unsupported);
@@ -120,20 +229,132 @@ pub async fn run(
         }
     }
 
-    execute_calls(account, parsed_calls, max_fee, None, wait_config)
+    Ok(parsed_calls)
+}
+
+/// Reads calls from a json file if its extension is `.json`, from a toml file otherwise
+fn read_calls_file(path: &Utf8PathBuf) -> Result<HashMap<String, Vec<toml::Value>>> {
+    let contents = std::fs::read_to_string(path)?;
+    if path.extension() == Some("json") {
+        serde_json::from_str(&contents).with_context(|| format!("Failed to parse {path}"))
+    } else {
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {path}"))
+    }
+}
+
+/// Simulates `calls` if `inputs` reference outputs of invoke calls which are not resolved yet
+async fn resolve_outputs(
+    inputs: &[String],
+    calls: &[ResolvedCall],
+    outputs: &mut CallOutputs,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+) -> Result<()> {
+    let has_unresolved = inputs.iter().any(|input| {
+        let (id, _) = output_reference(input);
+        outputs.call_indexes.contains_key(id) && !outputs.values.contains_key(id)
+    });
+    if !has_unresolved {
+        return Ok(());
+    }
+
+    let calls: Vec<Call> = calls.iter().map(|resolved| resolved.call.clone()).collect();
+    let invocations = simulate_calls(account, calls.clone(), nonce)
         .await
         .map_err(handle_starknet_command_error)
+        .context("Failed to resolve outputs of earlier calls")?;
+    if invocations.len() != calls.len() {
+        anyhow::bail!(
+            "Simulation returned {} invocations for {} calls, outputs of earlier calls cannot be resolved",
+            invocations.len(),
+            calls.len()
+        );
+    }
+
+    for (id, index) in &outputs.call_indexes {
+        let (call, invocation) = (&calls[*index], &invocations[*index]);
+        if invocation.contract_address.0 != call.to
+            || invocation.entry_point_selector.0 != call.selector
+        {
+            anyhow::bail!(
+                "Invocation of call `{id}` in the simulation does not match its contract address and function"
+            );
+        }
+        outputs.values.insert(
+            id.clone(),
+            invocation.result.iter().map(|felt| felt.0).collect(),
+        );
+    }
+    Ok(())
+}
+
+/// Ids name deployed contracts and invoke outputs alike, so they must be unique across all calls
+fn ensure_unique_id(
+    id: &str,
+    contracts: &HashMap<String, String>,
+    outputs: &CallOutputs,
+) -> Result<()> {
+    if contracts.contains_key(id) || outputs.call_indexes.contains_key(id) {
+        anyhow::bail!("Duplicated id = {id} found in multicall calls");
+    }
+    Ok(())
+}
+
+async fn simulate_calls(
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    calls: Vec<Call>,
+    nonce: Option<FieldElement>,
+) -> Result<Vec<InvocationResponse>, StarknetCommandError> {
+    let execution = account.execute(calls);
+    let execution = apply_optional(execution, nonce, Execution::nonce);
+
+    match execution.simulate(true, true).await {
+        Ok(simulated) => executed_calls(simulated),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(Signing(error)) => Err(anyhow!(error).into()),
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+/// Splits a reference to the output of an invoke call, `id` for all returned values
+/// or `id[index]` for a single one, into the id and the index
+fn output_reference(input: &str) -> (&str, Option<usize>) {
+    if let Some((id, index)) = input
+        .strip_suffix(']')
+        .and_then(|input| input.split_once('['))
+    {
+        if let Ok(index) = index.parse() {
+            return (id, Some(index));
+        }
+    }
+    (input, None)
 }
 
 fn parse_inputs(
     inputs: &Vec<String>,
     contracts: &HashMap<String, String>,
+    outputs: &HashMap<String, Vec<FieldElement>>,
 ) -> Result<Vec<FieldElement>> {
     let mut parsed_inputs = Vec::new();
     for input in inputs {
-        let current_input = contracts.get(input).unwrap_or(input);
-        parsed_inputs
-            .push(parse_number(current_input).context("Failed to parse input to FieldElement")?);
+        if let Some(contract_address) = contracts.get(input) {
+            parsed_inputs.push(
+                parse_number(contract_address).context("Failed to parse input to FieldElement")?,
+            );
+            continue;
+        }
+
+        let (id, index) = output_reference(input);
+        match (outputs.get(id), index) {
+            (Some(values), None) => parsed_inputs.extend(values),
+            (Some(values), Some(index)) => parsed_inputs.push(
+                *values
+                    .get(index)
+                    .with_context(|| format!("Output of call `{id}` has no value at {index}"))?,
+            ),
+            (None, _) => parsed_inputs
+                .push(parse_number(input).context("Failed to parse input to FieldElement")?),
+        }
     }
 
     Ok(parsed_inputs)
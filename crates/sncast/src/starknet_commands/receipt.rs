@@ -0,0 +1,93 @@
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+use sncast::helpers::events::{decode_event, events_from_abi, EventAbi};
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{
+    EventResponse, ExecutionResourcesResponse, FeePaymentResponse, Felt, MessageToL1Response,
+    TransactionReceiptResponse,
+};
+use starknet::core::types::{BlockId, BlockTag::Pending, ContractClass, Event, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+#[derive(Args)]
+#[command(about = "Get the receipt of a transaction", long_about = None)]
+pub struct Receipt {
+    /// Hash of the transaction
+    pub transaction_hash: FieldElement,
+}
+
+/// Fields of the receipt read from its RPC representation, common to all transaction types
+#[derive(Deserialize)]
+struct ReceiptDetails {
+    #[serde(rename = "type")]
+    transaction_type: String,
+    transaction_hash: FieldElement,
+    block_number: Option<u64>,
+    finality_status: String,
+    execution_status: String,
+    revert_reason: Option<String>,
+    actual_fee: FeePaymentResponse,
+    events: Vec<Event>,
+    messages_sent: Vec<MessageToL1Response>,
+    execution_resources: ExecutionResourcesResponse,
+}
+
+pub async fn receipt(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: FieldElement,
+) -> Result<TransactionReceiptResponse, StarknetCommandError> {
+    let receipt = provider
+        .get_transaction_receipt(transaction_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let receipt: ReceiptDetails = serde_json::to_value(receipt)
+        .and_then(serde_json::from_value)
+        .context("Failed to read the transaction receipt")?;
+
+    let mut abis: HashMap<FieldElement, Vec<EventAbi>> = HashMap::new();
+    let mut events = vec![];
+    for event in &receipt.events {
+        let abi = match abis.entry(event.from_address) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(get_events_abi(provider, event.from_address).await)
+            }
+        };
+        events.push(EventResponse {
+            from_address: Felt(event.from_address),
+            keys: event.keys.iter().copied().map(Felt).collect(),
+            data: event.data.iter().copied().map(Felt).collect(),
+            decoded: decode_event(event, abi),
+        });
+    }
+
+    Ok(TransactionReceiptResponse {
+        transaction_hash: Felt(receipt.transaction_hash),
+        transaction_type: receipt.transaction_type,
+        block_number: receipt.block_number,
+        finality_status: receipt.finality_status,
+        execution_status: receipt.execution_status,
+        revert_reason: receipt.revert_reason,
+        actual_fee: receipt.actual_fee,
+        events,
+        messages_sent: receipt.messages_sent,
+        execution_resources: receipt.execution_resources,
+    })
+}
+
+/// Events of the contract ABI, empty if the class can't be fetched
+async fn get_events_abi(
+    provider: &JsonRpcClient<HttpTransport>,
+    address: FieldElement,
+) -> Vec<EventAbi> {
+    let abi = match provider.get_class_at(BlockId::Tag(Pending), address).await {
+        Ok(ContractClass::Sierra(class)) => serde_json::from_str(&class.abi).ok(),
+        Ok(ContractClass::Legacy(class)) => serde_json::to_value(class.abi).ok(),
+        Err(_) => None,
+    };
+    abi.map(|abi| events_from_abi(&abi)).unwrap_or_default()
+}
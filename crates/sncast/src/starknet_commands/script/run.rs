@@ -37,14 +37,15 @@ use scarb_metadata::{Metadata, PackageMetadata};
 use semver::{Comparator, Op, Version, VersionReq};
 use shared::print::print_as_warning;
 use shared::utils::build_readable_text;
+use sncast::helpers::account::CastAccount;
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::SCRIPT_LIB_ARTIFACT_NAME;
+use sncast::helpers::script_lock::ScriptLock;
 use sncast::response::structs::ScriptRunResponse;
-use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::accounts::Account;
 use starknet::core::types::{BlockId, BlockTag::Pending, FieldElement};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
-use starknet::signers::LocalWallet;
 use tokio::runtime::Runtime;
 
 type ScriptStarknetContractArtifacts = StarknetContractArtifacts;
@@ -58,21 +59,23 @@ pub struct Run {
     /// Specifies scarb package to be used
     #[clap(long)]
     pub package: Option<String>,
+
+    /// Run the script even if another script is running on the same network with the same account
+    #[clap(long)]
+    pub force: bool,
 }
 
 pub struct CastScriptExtension<'a> {
     pub hints: &'a HashMap<String, Hint>,
     pub provider: &'a JsonRpcClient<HttpTransport>,
-    pub account: Option<&'a SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>>,
+    pub account: Option<&'a CastAccount<&'a JsonRpcClient<HttpTransport>>>,
     pub tokio_runtime: Runtime,
     pub config: &'a CastConfig,
     pub artifacts: &'a HashMap<String, StarknetContractArtifacts>,
 }
 
 impl<'a> CastScriptExtension<'a> {
-    pub fn account(
-        &self,
-    ) -> Result<&SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>> {
+    pub fn account(&self) -> Result<&CastAccount<&JsonRpcClient<HttpTransport>>> {
         self.account.ok_or_else(|| anyhow!("Account not defined. Please ensure the correct account is passed to `script run` command"))
     }
 }
@@ -117,17 +120,20 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     .read_option_felt()?
                     .map(conversions::IntoConv::into_);
 
-                let declare_result = self.tokio_runtime.block_on(declare::declare(
-                    &contract_name,
-                    max_fee,
-                    self.account()?,
-                    nonce,
-                    self.artifacts,
-                    WaitForTx {
-                        wait: true,
-                        wait_params: self.config.wait_params,
-                    },
-                ));
+                let declare_result =
+                    match declare::get_contract_artifacts(&contract_name, self.artifacts) {
+                        Ok(contract_artifacts) => self.tokio_runtime.block_on(declare::declare(
+                            contract_artifacts,
+                            max_fee,
+                            self.account()?,
+                            nonce,
+                            WaitForTx {
+                                wait: true,
+                                wait_params: self.config.wait_params,
+                            },
+                        )),
+                        Err(error) => Err(error),
+                    };
                 Ok(CheatcodeHandlingResult::Handled(
                     declare_result.serialize_as_felt252_vec(),
                 ))
@@ -231,6 +237,7 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     module_name: &str,
     metadata: &Metadata,
@@ -239,6 +246,7 @@ pub fn run(
     provider: &JsonRpcClient<HttpTransport>,
     tokio_runtime: Runtime,
     config: &CastConfig,
+    force: bool,
 ) -> Result<ScriptRunResponse> {
     warn_if_sncast_std_not_compatible(metadata)?;
     let artifacts = inject_lib_artifact(metadata, package_metadata, artifacts)?;
@@ -312,6 +320,13 @@ pub fn run(
         ))?)
     };
 
+    let _script_lock = match &account {
+        Some(account) if !force => {
+            Some(ScriptLock::acquire(account.chain_id(), account.address())?)
+        }
+        _ => None,
+    };
+
     let cast_extension = CastScriptExtension {
         hints: &string_to_hint,
         provider,
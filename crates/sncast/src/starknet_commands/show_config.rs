@@ -29,6 +29,7 @@ pub async fn show_config(
     }
     let wait_timeout = Some(cast_config.wait_params.get_timeout());
     let wait_retry_interval = Some(cast_config.wait_params.get_retry_interval());
+    let default_block_id = cast_config.default_block_id;
 
     Ok(ShowConfigResponse {
         profile,
@@ -39,5 +40,6 @@ pub async fn show_config(
         keystore,
         wait_timeout: wait_timeout.map(|x| Decimal(u64::from(x))),
         wait_retry_interval: wait_retry_interval.map(|x| Decimal(u64::from(x))),
+        default_block_id,
     })
 }
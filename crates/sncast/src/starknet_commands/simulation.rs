@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{
+    DeployedContractResponse, ExecutionResourcesResponse, FeeEstimateResponse, Felt,
+    InvocationResponse, NonceUpdateResponse, SimulationResponse, StateDiffResponse,
+    StorageDiffResponse,
+};
+use starknet::core::types::{SimulatedTransaction, TransactionTrace};
+
+/// Fields of the trace read from its RPC representation, common to all transaction types
+#[derive(Deserialize)]
+struct TraceDetails {
+    validate_invocation: Option<InvocationResponse>,
+    execute_invocation: Option<ExecuteInvocation>,
+    constructor_invocation: Option<InvocationResponse>,
+    fee_transfer_invocation: Option<InvocationResponse>,
+    state_diff: Option<StateDiff>,
+    execution_resources: Option<ExecutionResourcesResponse>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExecuteInvocation {
+    Success(InvocationResponse),
+    Reverted { revert_reason: String },
+}
+
+#[derive(Deserialize)]
+struct StateDiff {
+    storage_diffs: Vec<StorageDiffResponse>,
+    declared_classes: Vec<DeclaredClass>,
+    deprecated_declared_classes: Vec<Felt>,
+    deployed_contracts: Vec<DeployedContractResponse>,
+    nonces: Vec<NonceUpdateResponse>,
+}
+
+#[derive(Deserialize)]
+struct DeclaredClass {
+    class_hash: Felt,
+}
+
+impl From<StateDiff> for StateDiffResponse {
+    fn from(state_diff: StateDiff) -> Self {
+        StateDiffResponse {
+            deployed_contracts: state_diff.deployed_contracts,
+            declared_classes: state_diff
+                .declared_classes
+                .into_iter()
+                .map(|class| class.class_hash)
+                .chain(state_diff.deprecated_declared_classes)
+                .collect(),
+            storage_diffs: state_diff.storage_diffs,
+            nonces: state_diff.nonces,
+        }
+    }
+}
+
+fn read_trace(trace: TransactionTrace) -> Result<TraceDetails, StarknetCommandError> {
+    let trace = serde_json::to_value(trace)
+        .and_then(serde_json::from_value)
+        .context("Failed to read the transaction trace")?;
+    Ok(trace)
+}
+
+pub fn simulation_response(
+    simulated: SimulatedTransaction,
+) -> Result<SimulationResponse, StarknetCommandError> {
+    let trace = read_trace(simulated.transaction_trace)?;
+    let fee_estimate = FeeEstimateResponse::from(simulated.fee_estimation);
+
+    let (execute_invocation, revert_reason) = match trace.execute_invocation {
+        Some(ExecuteInvocation::Success(invocation)) => (Some(invocation), None),
+        Some(ExecuteInvocation::Reverted { revert_reason }) => (None, Some(revert_reason)),
+        None => (None, None),
+    };
+
+    Ok(SimulationResponse {
+        validate_invocation: trace.validate_invocation,
+        constructor_invocation: trace.constructor_invocation,
+        execute_invocation,
+        fee_transfer_invocation: trace.fee_transfer_invocation,
+        revert_reason,
+        state_diff: trace.state_diff.map(StateDiffResponse::from),
+        execution_resources: trace.execution_resources,
+        overall_fee: fee_estimate.overall_fee,
+        unit: fee_estimate.unit,
+        gas_consumed: fee_estimate.gas_consumed,
+        data_gas_consumed: fee_estimate.data_gas_consumed,
+    })
+}
+
+/// Invocations of the calls executed by the account, in the order reported by the trace
+pub fn executed_calls(
+    simulated: SimulatedTransaction,
+) -> Result<Vec<InvocationResponse>, StarknetCommandError> {
+    match read_trace(simulated.transaction_trace)?.execute_invocation {
+        Some(ExecuteInvocation::Success(invocation)) => Ok(invocation.calls),
+        Some(ExecuteInvocation::Reverted { revert_reason }) => {
+            Err(anyhow!("Simulated calls reverted: {revert_reason}").into())
+        }
+        None => Err(anyhow!("Simulated transaction has no execute invocation").into()),
+    }
+}
@@ -0,0 +1,59 @@
+use crate::starknet_commands::invoke::execute_calls;
+use anyhow::Result;
+use clap::Args;
+use sncast::helpers::account::CastAccount;
+use sncast::helpers::token::{get_decimals, parse_units, u256_to_felts, Token};
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::InvokeResponse;
+use sncast::WaitForTx;
+use starknet::accounts::{Call, ConnectedAccount};
+use starknet::core::types::{BlockId, BlockTag::Pending, FieldElement};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+
+#[derive(Args)]
+#[command(about = "Transfer tokens from the account to a recipient")]
+pub struct Transfer {
+    /// Address of the recipient
+    #[clap(short, long)]
+    pub recipient: FieldElement,
+
+    /// Amount of whole tokens to transfer, e.g. 1.5
+    #[clap(long)]
+    pub amount: String,
+
+    /// Token to transfer: eth, strk or an ERC20 token address
+    #[clap(short, long, default_value = "eth")]
+    pub token: Token,
+
+    /// Max fee for the transaction. If not provided, max fee will be automatically estimated
+    #[clap(short, long)]
+    pub max_fee: Option<FieldElement>,
+
+    /// Nonce of the transaction. If not provided, the pending nonce of the account will be used
+    #[clap(short, long)]
+    pub nonce: Option<FieldElement>,
+}
+
+pub async fn transfer(
+    recipient: FieldElement,
+    amount: &str,
+    token: Token,
+    max_fee: Option<FieldElement>,
+    account: &CastAccount<&JsonRpcClient<HttpTransport>>,
+    nonce: Option<FieldElement>,
+    wait_config: WaitForTx,
+) -> Result<InvokeResponse, StarknetCommandError> {
+    let decimals = get_decimals(token, account.provider(), &BlockId::Tag(Pending)).await?;
+    let (amount_low, amount_high) = u256_to_felts(parse_units(amount, decimals)?);
+
+    let call = Call {
+        to: token.address(),
+        selector: get_selector_from_name("transfer")
+            .expect("Failed to convert entry point selector to FieldElement"),
+        calldata: vec![recipient, amount_low, amount_high],
+    };
+
+    execute_calls(account, vec![call], max_fee, nonce, wait_config).await
+}
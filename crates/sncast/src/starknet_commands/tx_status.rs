@@ -0,0 +1,133 @@
+use clap::{Args, ValueEnum};
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::TransactionStatusResponse;
+use sncast::{ValidatedWaitParams, WaitForTransactionError, WaitForTx};
+use starknet::core::types::StarknetError::TransactionHashNotFound;
+use starknet::core::types::{
+    ExecutionResult, FieldElement, TransactionExecutionStatus, TransactionStatus,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::ProviderError::StarknetError;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::thread::sleep;
+use std::time::Duration;
+
+#[derive(Args)]
+#[command(about = "Get the status of a transaction", long_about = None)]
+pub struct TxStatus {
+    /// Hash of the transaction
+    pub transaction_hash: FieldElement,
+
+    /// Finality status to wait for when `--wait` is passed
+    #[clap(long, value_enum, default_value_t = Finality::AcceptedOnL2)]
+    pub finality: Finality,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Finality {
+    AcceptedOnL2,
+    AcceptedOnL1,
+}
+
+impl Finality {
+    fn as_str(self) -> &'static str {
+        match self {
+            Finality::AcceptedOnL2 => "ACCEPTED_ON_L2",
+            Finality::AcceptedOnL1 => "ACCEPTED_ON_L1",
+        }
+    }
+
+    fn is_reached_by(self, status: &TransactionStatus) -> bool {
+        match status {
+            TransactionStatus::Received => false,
+            TransactionStatus::AcceptedOnL2(_) => self == Finality::AcceptedOnL2,
+            TransactionStatus::Rejected | TransactionStatus::AcceptedOnL1(_) => true,
+        }
+    }
+}
+
+pub async fn tx_status(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: FieldElement,
+    finality: Finality,
+    wait_config: WaitForTx,
+) -> Result<TransactionStatusResponse, StarknetCommandError> {
+    let status = if wait_config.wait {
+        wait_for_finality(
+            provider,
+            transaction_hash,
+            finality,
+            wait_config.wait_params,
+        )
+        .await?
+    } else {
+        provider
+            .get_transaction_status(transaction_hash)
+            .await
+            .map_err(|error| StarknetCommandError::ProviderError(error.into()))?
+    };
+
+    let (finality_status, execution_status) = match status {
+        TransactionStatus::Received => ("RECEIVED", None),
+        TransactionStatus::Rejected => ("REJECTED", None),
+        TransactionStatus::AcceptedOnL2(execution_status) => {
+            (Finality::AcceptedOnL2.as_str(), Some(execution_status))
+        }
+        TransactionStatus::AcceptedOnL1(execution_status) => {
+            (Finality::AcceptedOnL1.as_str(), Some(execution_status))
+        }
+    };
+
+    let revert_reason = match execution_status {
+        Some(TransactionExecutionStatus::Reverted) => {
+            let receipt = provider
+                .get_transaction_receipt(transaction_hash)
+                .await
+                .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+            match receipt.execution_result() {
+                ExecutionResult::Reverted { reason } => Some(reason.clone()),
+                ExecutionResult::Succeeded => None,
+            }
+        }
+        _ => None,
+    };
+
+    Ok(TransactionStatusResponse {
+        execution_status: execution_status.map(|status| {
+            match status {
+                TransactionExecutionStatus::Succeeded => "SUCCEEDED",
+                TransactionExecutionStatus::Reverted => "REVERTED",
+            }
+            .to_string()
+        }),
+        finality_status: finality_status.to_string(),
+        revert_reason,
+    })
+}
+
+async fn wait_for_finality(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: FieldElement,
+    finality: Finality,
+    wait_params: ValidatedWaitParams,
+) -> Result<TransactionStatus, WaitForTransactionError> {
+    let retries = wait_params.get_retries();
+    for i in (1..retries).rev() {
+        match provider.get_transaction_status(transaction_hash).await {
+            Ok(status) if finality.is_reached_by(&status) => return Ok(status),
+            Ok(_) | Err(StarknetError(TransactionHashNotFound)) => {
+                let remaining_time = wait_params.remaining_time(i);
+                println!("Waiting for transaction to reach {} ({i} retries / {remaining_time}s left until timeout)", finality.as_str());
+            }
+            Err(ProviderError::RateLimited) => {
+                println!("Request rate limited while waiting for transaction status");
+                sleep(Duration::from_secs(wait_params.get_retry_interval().into()));
+            }
+            Err(err) => return Err(WaitForTransactionError::ProviderError(err.into())),
+        };
+
+        sleep(Duration::from_secs(wait_params.get_retry_interval().into()));
+    }
+
+    Err(WaitForTransactionError::TimedOut)
+}
@@ -0,0 +1,464 @@
+use anyhow::{anyhow, bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{Args, ValueEnum};
+use scarb_metadata::{Metadata, PackageMetadata};
+use sncast::helpers::arguments::{is_account_abi, sierra_abi};
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::{Felt, VerifyResponse};
+use sncast::{chain_id_to_network_name, get_chain_id, ValidatedWaitParams};
+use starknet::core::types::{BlockId, BlockTag::Pending, ContractClass, FieldElement};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::thread::sleep;
+use std::time::Duration;
+
+mod starkscan;
+mod voyager;
+
+#[derive(Args)]
+#[command(about = "Verify a declared contract with a block explorer", long_about = None)]
+pub struct Verify {
+    /// Class hash of the declared contract
+    #[clap(short = 'g', long)]
+    pub class_hash: FieldElement,
+
+    /// Name of the contract in the package
+    #[clap(short, long)]
+    pub contract_name: String,
+
+    /// Block explorer which verifies the contract
+    #[clap(short, long, value_enum, default_value_t = Verifier::Voyager)]
+    pub verifier: Verifier,
+
+    /// Specifies scarb package to be used
+    #[clap(long)]
+    pub package: Option<String>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Verifier {
+    Voyager,
+    Starkscan,
+}
+
+impl fmt::Display for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verifier::Voyager => write!(f, "Voyager"),
+            Verifier::Starkscan => write!(f, "Starkscan"),
+        }
+    }
+}
+
+/// Everything the block explorers need to rebuild the class from sources
+struct VerificationRequest<'a> {
+    class_hash: FieldElement,
+    contract_name: &'a str,
+    package_name: &'a str,
+    license: Option<&'a str>,
+    is_account_contract: bool,
+    cairo_version: String,
+    scarb_version: String,
+    files: BTreeMap<String, String>,
+}
+
+/// State of a verification job, as reported by the block explorer
+enum VerificationStatus {
+    Pending,
+    Verified(Option<String>),
+    Failed(String),
+}
+
+pub async fn verify(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: FieldElement,
+    contract_name: &str,
+    verifier: Verifier,
+    metadata: &Metadata,
+    package: &PackageMetadata,
+    wait_params: ValidatedWaitParams,
+) -> Result<VerifyResponse, StarknetCommandError> {
+    let class = provider
+        .get_class(BlockId::Tag(Pending), class_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let is_account_contract = match class {
+        ContractClass::Sierra(_) => is_account_abi(&sierra_abi(&class)?),
+        ContractClass::Legacy(_) => false,
+    };
+
+    let network = chain_id_to_network_name(get_chain_id(provider).await?);
+    let api_url = verifier_api_url(verifier, &network)?;
+
+    let request = VerificationRequest {
+        class_hash,
+        contract_name,
+        package_name: &package.name,
+        license: package.manifest_metadata.license.as_deref(),
+        is_account_contract,
+        cairo_version: metadata.app_version_info.cairo.version.to_string(),
+        scarb_version: metadata.app_version_info.version.to_string(),
+        files: verification_files(metadata, package)?,
+    };
+
+    let message = submit_and_wait(verifier, api_url, &request, wait_params).await?;
+    Ok(VerifyResponse {
+        class_hash: Felt(class_hash),
+        message,
+    })
+}
+
+fn verifier_api_url(verifier: Verifier, network: &str) -> Result<&'static str> {
+    let api_url = match verifier {
+        Verifier::Voyager => voyager::api_url(network),
+        Verifier::Starkscan => starkscan::api_url(network),
+    };
+    api_url.with_context(|| {
+        format!("Verification with {verifier} is not supported on network = {network}")
+    })
+}
+
+async fn submit_and_wait(
+    verifier: Verifier,
+    api_url: &str,
+    request: &VerificationRequest<'_>,
+    wait_params: ValidatedWaitParams,
+) -> Result<String> {
+    let client = reqwest::Client::new();
+    let job_id = match verifier {
+        Verifier::Voyager => voyager::submit(&client, api_url, request).await,
+        Verifier::Starkscan => starkscan::submit(&client, api_url, request).await,
+    }
+    .with_context(|| format!("Failed to submit the contract for verification with {verifier}"))?;
+
+    let retries = wait_params.get_retries();
+    for i in (1..retries).rev() {
+        let status = match verifier {
+            Verifier::Voyager => voyager::status(&client, api_url, &job_id).await,
+            Verifier::Starkscan => starkscan::status(&client, api_url, &job_id).await,
+        }
+        .context("Failed to fetch the verification status")?;
+
+        match status {
+            VerificationStatus::Verified(message) => {
+                return Ok(message
+                    .unwrap_or_else(|| format!("Contract verified successfully with {verifier}")))
+            }
+            VerificationStatus::Failed(reason) => bail!("Verification failed: {reason}"),
+            VerificationStatus::Pending => {
+                let remaining_time = wait_params.remaining_time(i);
+                println!("Waiting for verification to complete ({i} retries / {remaining_time}s left until timeout)");
+            }
+        }
+
+        sleep(Duration::from_secs(wait_params.get_retry_interval().into()));
+    }
+
+    Err(anyhow!(
+        "Verification of job = {job_id} did not complete before the timeout"
+    ))
+}
+
+/// Manifests, lockfile and Cairo sources needed to build `package`.
+///
+/// Files of the package are keyed by paths relative to its root, files of its dependencies
+/// by paths relative to `dependencies/<name>`. The core library is provided by the compiler
+/// and is not included.
+fn verification_files(
+    metadata: &Metadata,
+    package: &PackageMetadata,
+) -> Result<BTreeMap<String, String>> {
+    let mut files = package_files(&package.root, "")?;
+
+    let lockfile_path = metadata.workspace.root.join("Scarb.lock");
+    if lockfile_path.exists() {
+        files.insert(
+            "Scarb.lock".to_string(),
+            std::fs::read_to_string(&lockfile_path)
+                .with_context(|| format!("Failed to read {lockfile_path}"))?,
+        );
+    }
+
+    for dependency in package_dependencies(metadata, package) {
+        files.extend(package_files(
+            &dependency.root,
+            &format!("dependencies/{}/", dependency.name),
+        )?);
+    }
+    Ok(files)
+}
+
+/// Transitive dependencies of `package`, without the core library
+fn package_dependencies<'a>(
+    metadata: &'a Metadata,
+    package: &PackageMetadata,
+) -> Vec<&'a PackageMetadata> {
+    let mut visited = HashSet::from([package.name.as_str()]);
+    let mut dependencies = vec![];
+    let mut to_visit: Vec<&str> = package
+        .dependencies
+        .iter()
+        .map(|dependency| dependency.name.as_str())
+        .collect();
+
+    while let Some(name) = to_visit.pop() {
+        if !visited.insert(name) {
+            continue;
+        }
+        let Some(dependency) = metadata
+            .packages
+            .iter()
+            .find(|candidate| candidate.name == name && candidate.source.repr != "std")
+        else {
+            continue;
+        };
+        to_visit.extend(
+            dependency
+                .dependencies
+                .iter()
+                .map(|dependency| dependency.name.as_str()),
+        );
+        dependencies.push(dependency);
+    }
+    dependencies
+}
+
+/// Cairo sources and manifest of the package rooted at `root`, keyed by paths relative to it
+/// preceded by `prefix`
+fn package_files(root: &Utf8Path, prefix: &str) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    let manifest_path = root.join("Scarb.toml");
+    files.insert(
+        format!("{prefix}Scarb.toml"),
+        std::fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {manifest_path}"))?,
+    );
+
+    let mut dirs = vec![root.join("src")];
+    while let Some(dir) = dirs.pop() {
+        for entry in dir
+            .read_dir_utf8()
+            .with_context(|| format!("Failed to read directory {dir}"))?
+        {
+            let path: Utf8PathBuf = entry?.into_path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension() == Some("cairo") {
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {path}"))?;
+                let relative_path = path.strip_prefix(root)?;
+                files.insert(format!("{prefix}{relative_path}"), contents);
+            }
+        }
+    }
+    Ok(files)
+}
+
+async fn response_text(response: reqwest::Response) -> Result<String> {
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .context("Failed to read the verifier response")?;
+    if !status.is_success() {
+        bail!("Verifier responded with status {status}: {text}");
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves `responses` to consecutive requests and returns the received requests,
+    /// as their request lines and bodies, once all of them are served
+    fn serve_responses(
+        responses: Vec<Value>,
+    ) -> (String, thread::JoinHandle<Vec<(String, String)>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let response = response.to_string();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+
+                requests.push((
+                    request_line.trim().to_string(),
+                    String::from_utf8(body).unwrap(),
+                ));
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn map_request() -> VerificationRequest<'static> {
+        VerificationRequest {
+            class_hash: FieldElement::from(0x123_u32),
+            contract_name: "Map",
+            package_name: "map",
+            license: Some("MIT"),
+            is_account_contract: false,
+            cairo_version: "2.6.3".to_string(),
+            scarb_version: "2.6.4".to_string(),
+            files: package_files(Utf8Path::new("tests/data/contracts/map"), "").unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_package_files() {
+        let files = package_files(Utf8Path::new("tests/data/contracts/map"), "").unwrap();
+
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["Scarb.toml", "src/lib.cairo"]
+        );
+    }
+
+    #[test]
+    fn test_dependency_files() {
+        let files = package_files(
+            Utf8Path::new("tests/data/contracts/map"),
+            "dependencies/map/",
+        )
+        .unwrap();
+
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec![
+                "dependencies/map/Scarb.toml",
+                "dependencies/map/src/lib.cairo"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_network() {
+        let error = verifier_api_url(Verifier::Starkscan, "alpha-goerli").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Verification with Starkscan is not supported on network = alpha-goerli"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_voyager_happy_case() {
+        let (url, server) = serve_responses(vec![
+            json!({ "job_id": "job-1" }),
+            json!({ "job_id": "job-1", "status": 5, "status_description": "Processing" }),
+            json!({ "job_id": "job-1", "status": 4, "status_description": null }),
+        ]);
+
+        let message = submit_and_wait(
+            Verifier::Voyager,
+            &url,
+            &map_request(),
+            ValidatedWaitParams::new(1, 5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(message, "Contract verified successfully with Voyager");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].0, "POST /class-verify/0x123 HTTP/1.1");
+        assert_eq!(requests[1].0, "GET /class-verify/job/job-1 HTTP/1.1");
+        assert_eq!(requests[2].0, "GET /class-verify/job/job-1 HTTP/1.1");
+
+        let body: Value = serde_json::from_str(&requests[0].1).unwrap();
+        assert_eq!(body["name"], "Map");
+        assert_eq!(body["package_name"], "map");
+        assert_eq!(body["contract_file"], "src/lib.cairo");
+        assert_eq!(body["compiler_version"], "2.6.3");
+        assert_eq!(body["scarb_version"], "2.6.4");
+        assert_eq!(body["license"], "MIT");
+        assert!(body["files"]["Scarb.toml"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_starkscan_happy_case() {
+        let (url, server) = serve_responses(vec![
+            json!({ "job_id": "job-1" }),
+            json!({ "status": "PENDING" }),
+            json!({ "status": "SUCCESS" }),
+        ]);
+
+        let message = submit_and_wait(
+            Verifier::Starkscan,
+            &url,
+            &map_request(),
+            ValidatedWaitParams::new(1, 5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(message, "Contract verified successfully with Starkscan");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].0, "POST /verify_class HTTP/1.1");
+        assert_eq!(requests[1].0, "GET /verify_class_job_status/job-1 HTTP/1.1");
+        assert_eq!(requests[2].0, "GET /verify_class_job_status/job-1 HTTP/1.1");
+
+        let body: Value = serde_json::from_str(&requests[0].1).unwrap();
+        assert_eq!(body["class_hash"], "0x123");
+        assert_eq!(body["name"], "Map");
+        assert_eq!(body["compiler_version"], "2.6.3");
+        assert_eq!(body["is_account_contract"], false);
+        assert!(body["files"]["src/lib.cairo"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_verification_failed() {
+        let (url, _server) = serve_responses(vec![
+            json!({ "job_id": "job-1" }),
+            json!({ "status": "FAILED", "error_message": "Class hash mismatch" }),
+        ]);
+
+        let error = submit_and_wait(
+            Verifier::Starkscan,
+            &url,
+            &map_request(),
+            ValidatedWaitParams::new(1, 5),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Verification failed: Class hash mismatch"
+        );
+    }
+}
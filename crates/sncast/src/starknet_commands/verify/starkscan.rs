@@ -0,0 +1,80 @@
+use super::{response_text, VerificationRequest, VerificationStatus};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+struct VerificationBody<'a> {
+    class_hash: String,
+    name: &'a str,
+    compiler_version: &'a str,
+    is_account_contract: bool,
+    files: &'a BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct VerificationJob {
+    job_id: String,
+}
+
+#[derive(Deserialize)]
+struct VerificationJobStatus {
+    status: String,
+    error_message: Option<String>,
+}
+
+pub(super) fn api_url(network: &str) -> Option<&'static str> {
+    match network {
+        "alpha-mainnet" => Some("https://api.starkscan.co/api"),
+        "alpha-sepolia" => Some("https://api-sepolia.starkscan.co/api"),
+        _ => None,
+    }
+}
+
+pub(super) async fn submit(
+    client: &reqwest::Client,
+    api_url: &str,
+    request: &VerificationRequest<'_>,
+) -> Result<String> {
+    let body = VerificationBody {
+        class_hash: format!("{:#x}", request.class_hash),
+        name: request.contract_name,
+        compiler_version: &request.cairo_version,
+        is_account_contract: request.is_account_contract,
+        files: &request.files,
+    };
+
+    let response = client
+        .post(format!("{api_url}/verify_class"))
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_string(&body).context("Failed to serialize the request")?)
+        .send()
+        .await
+        .context("Failed to send the verification request")?;
+    let job: VerificationJob = serde_json::from_str(&response_text(response).await?)
+        .context("Failed to read the verification job")?;
+    Ok(job.job_id)
+}
+
+pub(super) async fn status(
+    client: &reqwest::Client,
+    api_url: &str,
+    job_id: &str,
+) -> Result<VerificationStatus> {
+    let response = client
+        .get(format!("{api_url}/verify_class_job_status/{job_id}"))
+        .send()
+        .await
+        .context("Failed to send the status request")?;
+    let job: VerificationJobStatus = serde_json::from_str(&response_text(response).await?)
+        .context("Failed to read the verification status")?;
+
+    Ok(match job.status.as_str() {
+        "SUCCESS" => VerificationStatus::Verified(None),
+        "FAILED" => VerificationStatus::Failed(
+            job.error_message
+                .unwrap_or_else(|| "no reason given by Starkscan".to_string()),
+        ),
+        _ => VerificationStatus::Pending,
+    })
+}
@@ -0,0 +1,100 @@
+use super::{response_text, VerificationRequest, VerificationStatus};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Statuses of finished jobs, all the others mean the job is still in progress
+const COMPILE_FAILED: u8 = 2;
+const FAIL: u8 = 3;
+const SUCCESS: u8 = 4;
+
+#[derive(Serialize)]
+struct VerificationBody<'a> {
+    compiler_version: &'a str,
+    scarb_version: &'a str,
+    project_dir_path: &'a str,
+    name: &'a str,
+    package_name: &'a str,
+    license: Option<&'a str>,
+    contract_file: &'a str,
+    files: &'a BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct VerificationJob {
+    job_id: String,
+}
+
+#[derive(Deserialize)]
+struct VerificationJobStatus {
+    status: u8,
+    status_description: Option<String>,
+}
+
+pub(super) fn api_url(network: &str) -> Option<&'static str> {
+    match network {
+        "alpha-mainnet" => Some("https://api.voyager.online/beta"),
+        "alpha-sepolia" => Some("https://sepolia-api.voyager.online/beta"),
+        _ => None,
+    }
+}
+
+pub(super) async fn submit(
+    client: &reqwest::Client,
+    api_url: &str,
+    request: &VerificationRequest<'_>,
+) -> Result<String> {
+    let body = VerificationBody {
+        compiler_version: &request.cairo_version,
+        scarb_version: &request.scarb_version,
+        project_dir_path: ".",
+        name: request.contract_name,
+        package_name: request.package_name,
+        license: request.license,
+        contract_file: contract_file(&request.files, request.contract_name),
+        files: &request.files,
+    };
+
+    let response = client
+        .post(format!("{api_url}/class-verify/{:#x}", request.class_hash))
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_string(&body).context("Failed to serialize the request")?)
+        .send()
+        .await
+        .context("Failed to send the verification request")?;
+    let job: VerificationJob = serde_json::from_str(&response_text(response).await?)
+        .context("Failed to read the verification job")?;
+    Ok(job.job_id)
+}
+
+pub(super) async fn status(
+    client: &reqwest::Client,
+    api_url: &str,
+    job_id: &str,
+) -> Result<VerificationStatus> {
+    let response = client
+        .get(format!("{api_url}/class-verify/job/{job_id}"))
+        .send()
+        .await
+        .context("Failed to send the status request")?;
+    let job: VerificationJobStatus = serde_json::from_str(&response_text(response).await?)
+        .context("Failed to read the verification status")?;
+
+    Ok(match job.status {
+        SUCCESS => VerificationStatus::Verified(job.status_description),
+        COMPILE_FAILED | FAIL => VerificationStatus::Failed(
+            job.status_description
+                .unwrap_or_else(|| format!("job ended with status {}", job.status)),
+        ),
+        _ => VerificationStatus::Pending,
+    })
+}
+
+/// Source file of the package declaring the `contract_name` module, `src/lib.cairo` if none does
+fn contract_file<'a>(files: &'a BTreeMap<String, String>, contract_name: &str) -> &'a str {
+    let declaration = format!("mod {contract_name} {{");
+    files
+        .iter()
+        .find(|(path, contents)| path.starts_with("src/") && contents.contains(&declaration))
+        .map_or("src/lib.cairo", |(path, _)| path.as_str())
+}
@@ -0,0 +1,80 @@
+// Account with the constructor and signature scheme of Argent accounts, used to test deploying them
+#[starknet::contract]
+mod ArgentAccount {
+    use array::{ArrayTrait, SpanTrait};
+    use box::BoxTrait;
+    use ecdsa::check_ecdsa_signature;
+    use starknet::{ContractAddress, SyscallResultTrait, VALIDATED, call_contract_syscall, get_tx_info};
+
+    #[derive(Drop, Serde)]
+    struct Call {
+        to: ContractAddress,
+        selector: felt252,
+        calldata: Array<felt252>,
+    }
+
+    #[storage]
+    struct Storage {
+        owner: felt252,
+        guardian: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: felt252, guardian: felt252) {
+        self.owner.write(owner);
+        self.guardian.write(guardian);
+    }
+
+    #[external(v0)]
+    fn __validate_deploy__(
+        self: @ContractState,
+        class_hash: felt252,
+        contract_address_salt: felt252,
+        owner: felt252,
+        guardian: felt252
+    ) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, mut calls: Array<Call>) -> Array<Span<felt252>> {
+        let mut results = array![];
+        loop {
+            match calls.pop_front() {
+                Option::Some(call) => {
+                    let result = call_contract_syscall(call.to, call.selector, call.calldata.span())
+                        .unwrap_syscall();
+                    results.append(result);
+                },
+                Option::None => { break; },
+            };
+        };
+        results
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn validate_transaction(self: @ContractState) -> felt252 {
+            let tx_info = get_tx_info().unbox();
+            let signature = tx_info.signature;
+            assert(signature.len() == 2, 'argent/invalid-signature-length');
+            assert(
+                check_ecdsa_signature(
+                    tx_info.transaction_hash, self.owner.read(), *signature.at(0), *signature.at(1)
+                ),
+                'argent/invalid-owner-sig'
+            );
+            VALIDATED
+        }
+    }
+}
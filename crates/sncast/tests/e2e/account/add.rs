@@ -2,12 +2,14 @@ use crate::helpers::constants::{
     DEVNET_OZ_CLASS_HASH_CAIRO_0, DEVNET_OZ_CLASS_HASH_CAIRO_1, DEVNET_PREDEPLOYED_ACCOUNT_ADDRESS,
     URL,
 };
+use crate::helpers::fixtures::copy_file;
 use crate::helpers::runner::runner;
 use camino::Utf8PathBuf;
 use configuration::CONFIG_FILENAME;
 use indoc::{formatdoc, indoc};
 use serde_json::json;
 use shared::test_utils::output_assert::assert_stderr_contains;
+use sncast::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
 use std::fs::{self, File};
 use tempfile::tempdir;
 
@@ -407,6 +409,159 @@ pub async fn test_private_key_from_file() {
     );
 }
 
+#[tokio::test]
+pub async fn test_import_private_key_from_keystore() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let keystore_file = "my_key.json";
+
+    copy_file(
+        "tests/data/keystore/my_key.json",
+        temp_dir.path().join(keystore_file),
+    );
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "import",
+        "--name",
+        "my_account_import",
+        "--address",
+        "0x123",
+        "--keystore",
+        keystore_file,
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+    ];
+
+    let snapbox = runner(&args)
+        .env(KEYSTORE_PASSWORD_ENV_VAR, "123")
+        .current_dir(temp_dir.path());
+
+    snapbox.assert().stdout_matches(indoc! {r"
+        command: account add
+        add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    let contents_json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(
+        contents_json["alpha-goerli"]["my_account_import"]["public_key"],
+        "0xe2d3d7080bfc665e0060a06e8e95c3db3ff78a1fec4cc81ddc87e49a12e0a"
+    );
+}
+
+#[tokio::test]
+pub async fn test_import_nonexistent_keystore() {
+    let args = vec![
+        "--url",
+        URL,
+        "account",
+        "import",
+        "--name",
+        "my_account_import",
+        "--address",
+        "0x123",
+        "--keystore",
+        "nonexistent_key.json",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account add
+        error: Failed to obtain private key from the keystore nonexistent_key.json: Keystore file does not exist
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_add_invalid_ledger_path() {
+    let args = vec![
+        "--url",
+        URL,
+        "account",
+        "add",
+        "--name",
+        "my_account_add",
+        "--address",
+        "0x123",
+        "--ledger-path",
+        "m/44'/60'/0'/0/0",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account add
+        error: Failed to obtain public key from Ledger: Invalid Ledger derivation path = m/44'/60'/0'/0/0. [..]
+        "},
+    );
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+pub async fn test_add_with_signer_command() {
+    let tempdir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let signer_command = r#"echo '{"public_key": "0x456"}'"#;
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "add",
+        "--name",
+        "my_account_add",
+        "--address",
+        "0x123",
+        "--signer-command",
+        signer_command,
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+
+    snapbox.assert().stdout_matches(indoc! {r"
+        command: account add
+        add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
+    "});
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    let contents_json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(
+        contents_json,
+        json!(
+            {
+                "alpha-goerli": {
+                  "my_account_add": {
+                    "address": "0x123",
+                    "class_hash": DEVNET_OZ_CLASS_HASH_CAIRO_0,
+                    "deployed": false,
+                    "legacy": true,
+                    "signer_command": signer_command,
+                    "public_key": "0x456",
+                  }
+                }
+            }
+        )
+    );
+}
+
 #[tokio::test]
 pub async fn test_accept_only_one_private_key() {
     let args = vec![
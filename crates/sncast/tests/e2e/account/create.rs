@@ -1,5 +1,5 @@
 use crate::helpers::constants::{DEVNET_OZ_CLASS_HASH_CAIRO_0, URL};
-use crate::helpers::fixtures::{copy_file, default_cli_args};
+use crate::helpers::fixtures::{copy_file, default_cli_args, from_env};
 use crate::helpers::runner::runner;
 use configuration::copy_config_to_tempdir;
 use indoc::indoc;
@@ -85,6 +85,78 @@ pub async fn test_invalid_class_hash() {
     );
 }
 
+#[tokio::test]
+pub async fn test_argent_type_uses_argent_class_hash() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "create",
+        "--type",
+        "argent",
+        "--name",
+        "my_account_create_argent",
+        "--salt",
+        "0x1",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account create
+        error: Class with hash 0x29927c8af6bccf3f6fda035981e765a7bdbf18a2dc0d630494f8758aa908e2b is not declared, try using --class-hash with a hash of the declared class
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_happy_case_argent_type() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let class_hash = from_env("CAST_ARGENT_ACCOUNT_CLASS_HASH").unwrap();
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "create",
+        "--type",
+        "argent",
+        "--name",
+        "my_account_create_argent_happy",
+        "--salt",
+        "0x1",
+        "--class-hash",
+        &class_hash,
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    let stdout_str = output.as_stdout();
+    assert!(stdout_str.contains("command: account create"));
+    assert!(stdout_str.contains("max_fee: "));
+    assert!(stdout_str.contains("address: 0x"));
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    let items: serde_json::Value =
+        serde_json::from_str(&contents).expect("Failed to parse accounts file");
+    let account = &items["alpha-goerli"]["my_account_create_argent_happy"];
+    assert_eq!(account["type"], "argent");
+    assert_eq!(account["legacy"], false);
+}
+
 #[tokio::test]
 pub async fn test_happy_case_generate_salt() {
     let temp_dir = tempdir().expect("Unable to create a temporary directory");
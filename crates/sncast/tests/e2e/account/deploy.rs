@@ -173,7 +173,39 @@ pub async fn test_invalid_class_hash() {
         output,
         indoc! {r"
         command: account deploy
-        error: Provided class hash 0x123 does not exist
+        error: Class with hash 0x123 is not declared, try using --class-hash with a hash of the declared class
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_class_hash_address_mismatch() {
+    let tempdir = create_account(true, DEVNET_OZ_CLASS_HASH_CAIRO_1).await;
+    let accounts_file = "accounts.json";
+
+    let args = vec![
+        "--profile",
+        "deploy_profile",
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "deploy",
+        "--name",
+        "my_account",
+        "--max-fee",
+        "10000000000000000",
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account deploy
+        error: Address of account my_account = 0x[..] does not match address 0x[..] computed with class hash = 0x[..]
         "},
     );
 }
@@ -0,0 +1,129 @@
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, URL};
+use crate::helpers::fixtures::copy_file;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use sncast::helpers::constants::CREATE_KEYSTORE_PASSWORD_ENV_VAR;
+use starknet::signers::SigningKey;
+use std::fs;
+use tempfile::tempdir;
+
+#[tokio::test]
+pub async fn test_happy_case() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    copy_file(ACCOUNT_FILE_PATH, temp_dir.path().join(accounts_file));
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "export",
+        "--name",
+        "user1",
+        "--network",
+        "alpha-goerli",
+        "--keystore",
+        "my_key.json",
+        "--account-file",
+        "my_account.json",
+    ];
+
+    let snapbox = runner(&args)
+        .env(CREATE_KEYSTORE_PASSWORD_ENV_VAR, "123")
+        .current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account export
+        keystore: my_key.json
+        account_file: my_account.json
+        "},
+    );
+
+    let private_key =
+        SigningKey::from_keystore(temp_dir.path().join("my_key.json"), "123").unwrap();
+    assert_eq!(
+        format!("{:#x}", private_key.secret_scalar()),
+        "0xffd33878eed7767e7c546ce3fc026295"
+    );
+
+    let contents = fs::read_to_string(temp_dir.path().join("my_account.json"))
+        .expect("Unable to read created file");
+    let account: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(account["variant"]["type"], "open_zeppelin");
+    assert_eq!(
+        account["variant"]["public_key"],
+        "0x17b62d16ee2b9b5ccd3320e2c0b234dfbdd1d01d09d0aa29ce164827cddf46a"
+    );
+}
+
+#[tokio::test]
+pub async fn test_keystore_already_exists() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    copy_file(ACCOUNT_FILE_PATH, temp_dir.path().join(accounts_file));
+    copy_file(
+        "tests/data/keystore/my_key.json",
+        temp_dir.path().join("my_key.json"),
+    );
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "export",
+        "--name",
+        "user1",
+        "--network",
+        "alpha-goerli",
+        "--keystore",
+        "my_key.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account export
+        error: Keystore file my_key.json already exists
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_account_does_not_exist() {
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "account",
+        "export",
+        "--name",
+        "user99",
+        "--network",
+        "alpha-goerli",
+        "--keystore",
+        "my_key.json",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account export
+        error: Account with name user99 does not exist
+        "},
+    );
+}
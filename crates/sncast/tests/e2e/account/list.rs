@@ -0,0 +1,107 @@
+use crate::helpers::constants::URL;
+use crate::helpers::fixtures::default_cli_args;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use serde_json::Value;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains, AsOutput};
+
+#[tokio::test]
+pub async fn test_happy_case() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["account", "list"]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account list
+        accounts:
+          alpha-goerli:
+            user1:
+              address: 0xf6ecd22832b7c3713cfa7826ee309ce96a2769833f093795fafa1b8f20c48b
+              deployed: true
+              keystore: false
+          alpha-goerli2:
+            user4:
+              address: 0x7ccdf182d27c7aaa2e733b94db4a3f7b28ff56336b34abf43c15e3a9edfbe91
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_json_output() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--json", "account", "list"]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    let json: Value = serde_json::from_str(output.as_stdout()).unwrap();
+    assert_eq!(json["command"], "account list");
+
+    let account = &json["accounts"]["alpha-goerli"]["user1"];
+    assert_eq!(
+        account["address"],
+        "0xf6ecd22832b7c3713cfa7826ee309ce96a2769833f093795fafa1b8f20c48b"
+    );
+    assert_eq!(account["deployed"], "true");
+    assert_eq!(account["keystore"], "false");
+    assert!(account["class_hash"].is_null());
+}
+
+#[tokio::test]
+pub async fn test_keystore_account() {
+    let args = vec![
+        "--url",
+        URL,
+        "--keystore",
+        "tests/data/keystore/my_key.json",
+        "--account",
+        "tests/data/keystore/my_account.json",
+        "account",
+        "list",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account list
+        accounts:
+          keystore:
+            tests/data/keystore/my_account.json:
+              address: 0xcce3217e4aea0ab738b55446b1b378750edfca617db549fda1ede28435206c
+              class_hash: 0x4d07e40e93398ed3c76981e72dd1fd22557a78ce36c0515f679e27f0bb5bc5f
+              deployed: true
+              type: open_zeppelin
+              keystore: true
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_accounts_file_does_not_exist() {
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        "nonexistent_accounts.json",
+        "account",
+        "list",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account list
+        error: Accounts file = nonexistent_accounts.json does not exist! [..]
+        "},
+    );
+}
@@ -2,3 +2,5 @@ mod add;
 mod create;
 mod delete;
 mod deploy;
+mod export;
+mod list;
@@ -0,0 +1,91 @@
+use crate::helpers::constants::URL;
+use crate::helpers::fixtures::default_cli_args;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[test]
+fn test_happy_case() {
+    let args = vec![
+        "--url",
+        URL,
+        "balance",
+        "--address",
+        "0x1234567",
+        "--token",
+        "strk",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: balance
+        balance: 0
+        formatted_balance: 0 STRK
+    "});
+}
+
+#[test]
+fn test_balance_of_account() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user1", "balance"]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: balance
+        balance: [..]
+        formatted_balance: [..] ETH
+        "},
+    );
+}
+
+#[test]
+fn test_token_does_not_exist() {
+    let args = vec![
+        "--url",
+        URL,
+        "balance",
+        "--address",
+        "0x1234567",
+        "--token",
+        "0x1",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: balance
+        error: There is no contract at the specified address
+        "},
+    );
+}
+
+#[test]
+fn test_unknown_token() {
+    let args = vec![
+        "--url",
+        URL,
+        "balance",
+        "--address",
+        "0x1234567",
+        "--token",
+        "usdc",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        error: invalid value 'usdc' for '--token <TOKEN>': Unknown token = usdc, expected eth, strk or a token address
+        "},
+    );
+}
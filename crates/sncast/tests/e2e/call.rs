@@ -27,6 +27,30 @@ fn test_happy_case() {
     "});
 }
 
+#[test]
+fn test_happy_case_with_arguments() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "call",
+        "--contract-address",
+        &contract_address,
+        "--function",
+        "get",
+        "--arguments",
+        "0x0",
+        "--block-id",
+        "latest",
+    ]);
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: call
+        response: [0x0]
+    "});
+}
+
 #[tokio::test]
 async fn test_call_after_storage_changed() {
     let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
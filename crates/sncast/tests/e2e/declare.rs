@@ -9,6 +9,8 @@ use indoc::indoc;
 use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use starknet::core::types::TransactionReceipt::Declare;
 use std::fs;
+use std::process::Command;
+use tempfile::tempdir;
 use test_case::test_case;
 
 #[test_case("cairo0"; "cairo_0_account")]
@@ -450,3 +452,164 @@ async fn test_no_scarb_profile() {
         transaction_hash: [..]
     "});
 }
+
+#[test]
+fn test_estimate_only() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "estimate",
+    );
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user6",
+        "declare",
+        "--contract-name",
+        "Map",
+        "--estimate-only",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: declare
+        data_gas_consumed: [..]
+        data_gas_price: [..]
+        gas_consumed: [..]
+        gas_price: [..]
+        overall_fee: [..]
+        unit: WEI
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_sierra_and_casm_files() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "prebuilt",
+    );
+    let build_status = Command::new("scarb")
+        .arg("build")
+        .current_dir(contract_path.path())
+        .status()
+        .expect("Failed to run scarb build");
+    assert!(build_status.success());
+
+    let target_dir = contract_path.path().join("target/dev");
+    let sierra_file = target_dir.join("map_Map.contract_class.json");
+    let casm_file = target_dir.join("map_Map.compiled_contract_class.json");
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user7",
+        "--int-format",
+        "--json",
+        "declare",
+        "--sierra-file",
+        sierra_file.to_str().unwrap(),
+        "--casm-file",
+        casm_file.to_str().unwrap(),
+        "--max-fee",
+        "99999999999999999",
+    ];
+
+    // run outside of the package, so it is not built by sncast
+    let tempdir = tempdir().expect("Unable to create a temporary directory");
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Declare(_)));
+}
+
+#[tokio::test]
+async fn test_happy_case_sierra_file_only() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "prebuilt_sierra",
+    );
+    let build_status = Command::new("scarb")
+        .arg("build")
+        .current_dir(contract_path.path())
+        .status()
+        .expect("Failed to run scarb build");
+    assert!(build_status.success());
+
+    let sierra_file = contract_path
+        .path()
+        .join("target/dev/map_Map.contract_class.json");
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user6",
+        "--int-format",
+        "--json",
+        "declare",
+        "--sierra-file",
+        sierra_file.to_str().unwrap(),
+        "--max-fee",
+        "99999999999999999",
+    ];
+
+    // casm is compiled by sncast from the sierra file
+    let tempdir = tempdir().expect("Unable to create a temporary directory");
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Declare(_)));
+}
+
+#[test]
+fn test_sierra_file_does_not_exist() {
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+
+    let args = vec![
+        "--url",
+        URL,
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user1",
+        "declare",
+        "--sierra-file",
+        "non_existent.contract_class.json",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: declare
+        error: Failed to read sierra file = non_existent.contract_class.json[..]
+        "},
+    );
+}
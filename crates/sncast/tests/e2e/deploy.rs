@@ -4,7 +4,7 @@ use crate::helpers::fixtures::{
 };
 use crate::helpers::runner::runner;
 use indoc::indoc;
-use shared::test_utils::output_assert::assert_stderr_contains;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use starknet::core::types::TransactionReceipt::Deploy;
 use test_case::test_case;
 
@@ -65,6 +65,57 @@ async fn test_happy_case_with_constructor() {
     assert!(matches!(receipt, Deploy(_)));
 }
 
+#[tokio::test]
+async fn test_happy_case_with_arguments() {
+    let class_hash = from_env("CAST_WITH_CONSTRUCTOR_CLASS_HASH").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user4",
+        "--int-format",
+        "--json",
+        "deploy",
+        "--arguments",
+        "0x1, u256:1",
+        "--class-hash",
+        &class_hash,
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Deploy(_)));
+}
+
+#[test]
+fn test_wrong_arguments() {
+    let class_hash = from_env("CAST_WITH_CONSTRUCTOR_CLASS_HASH").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user4",
+        "deploy",
+        "--class-hash",
+        &class_hash,
+        "--arguments",
+        "0x1",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: deploy
+        error: Failed to serialize constructor arguments: Expected 2 arguments, got 1
+        "},
+    );
+}
+
 #[test]
 fn test_wrong_calldata() {
     let class_hash = from_env("CAST_WITH_CONSTRUCTOR_CLASS_HASH").unwrap();
@@ -170,3 +221,67 @@ fn test_too_low_max_fee() {
         "},
     );
 }
+
+#[test]
+fn test_estimate_only() {
+    let class_hash = from_env("CAST_MAP_CLASS_HASH").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "deploy",
+        "--class-hash",
+        &class_hash,
+        "--estimate-only",
+    ]);
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: deploy
+        data_gas_consumed: [..]
+        data_gas_price: [..]
+        gas_consumed: [..]
+        gas_price: [..]
+        overall_fee: [..]
+        unit: WEI
+    "});
+}
+
+#[test]
+fn test_simulate() {
+    let class_hash = from_env("CAST_MAP_CLASS_HASH").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "deploy",
+        "--class-hash",
+        &class_hash,
+        "--simulate",
+    ]);
+
+    let snapbox = runner(&args);
+
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: deploy
+        data_gas_consumed: [..]
+        execute_invocation:
+        execution_resources:
+          steps: [..]
+        fee_transfer_invocation:
+        gas_consumed: [..]
+        overall_fee: [..]
+        state_diff:
+          deployed_contracts:
+            - address: 0x[..]
+              class_hash: 0x[..]
+        unit: WEI
+        validate_invocation:
+        "},
+    );
+}
@@ -1,11 +1,13 @@
 use crate::helpers::constants::ACCOUNT;
 use crate::helpers::fixtures::{
-    default_cli_args, from_env, get_transaction_hash, get_transaction_receipt,
+    create_test_provider, default_cli_args, from_env, get_transaction_hash, get_transaction_receipt,
 };
 use crate::helpers::runner::runner;
 use indoc::indoc;
-use shared::test_utils::output_assert::assert_stderr_contains;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use starknet::core::types::TransactionReceipt::Invoke;
+use starknet::core::types::{FieldElement, InvokeTransaction, Transaction};
+use starknet::providers::Provider;
 use test_case::test_case;
 
 #[test_case("cairo0"; "cairo_0_account")]
@@ -39,6 +41,69 @@ async fn test_happy_case(account: &str) {
     assert!(matches!(receipt, Invoke(_)));
 }
 
+#[tokio::test]
+async fn test_consecutive_transactions_use_consecutive_nonces() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let provider = create_test_provider();
+
+    let mut nonces = vec![];
+    for calldata in ["0x3 0x1", "0x3 0x2"] {
+        let mut args = default_cli_args();
+        args.append(&mut vec![
+            "--account",
+            // not used by other tests, which could send transactions in between
+            "user0",
+            "--json",
+            "invoke",
+            "--contract-address",
+            &contract_address,
+            "--function",
+            "put",
+            "--calldata",
+            calldata,
+        ]);
+
+        let snapbox = runner(&args);
+        let output = snapbox.assert().success().get_output().stdout.clone();
+
+        let hash = get_transaction_hash(&output);
+        let transaction = provider.get_transaction_by_hash(hash).await.unwrap();
+        let Transaction::Invoke(InvokeTransaction::V1(transaction)) = transaction else {
+            panic!("Expected an invoke v1 transaction");
+        };
+        nonces.push(transaction.nonce);
+    }
+
+    assert_eq!(nonces[1], nonces[0] + FieldElement::ONE);
+}
+
+#[tokio::test]
+async fn test_happy_case_with_arguments() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user5",
+        "--int-format",
+        "--json",
+        "invoke",
+        "--contract-address",
+        &contract_address,
+        "--function",
+        "put",
+        "--arguments",
+        r#"0x1, "value""#,
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Invoke(_)));
+}
+
 #[tokio::test]
 async fn test_contract_does_not_exist() {
     let mut args = default_cli_args();
@@ -118,6 +183,34 @@ fn test_wrong_calldata() {
     );
 }
 
+#[test]
+fn test_wrong_arguments() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        "user5",
+        "invoke",
+        "--contract-address",
+        &contract_address,
+        "--function",
+        "put",
+        "--arguments",
+        "0x1",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: Failed to serialize arguments of put: Expected 2 arguments, got 1
+        "},
+    );
+}
+
 #[test]
 fn test_too_low_max_fee() {
     let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
@@ -149,3 +242,75 @@ fn test_too_low_max_fee() {
         "},
     );
 }
+
+#[test]
+fn test_estimate_only() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "invoke",
+        "--contract-address",
+        &contract_address,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--estimate-only",
+    ]);
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: invoke
+        data_gas_consumed: [..]
+        data_gas_price: [..]
+        gas_consumed: [..]
+        gas_price: [..]
+        overall_fee: [..]
+        unit: WEI
+    "});
+}
+
+#[test]
+fn test_simulate() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "invoke",
+        "--contract-address",
+        &contract_address,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--simulate",
+    ]);
+
+    let snapbox = runner(&args);
+
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        data_gas_consumed: [..]
+        execute_invocation:
+          contract_address: 0x[..]
+        execution_resources:
+          steps: [..]
+        fee_transfer_invocation:
+        gas_consumed: [..]
+        overall_fee: [..]
+        state_diff:
+          storage_diffs:
+            - address: 0x[..]
+        unit: WEI
+        validate_invocation:
+        "},
+    );
+}
@@ -1,9 +1,14 @@
 mod account;
+mod balance;
 mod call;
 mod declare;
 mod deploy;
 mod invoke;
 mod main_tests;
 mod multicall;
+mod receipt;
 mod script;
 mod show_config;
+mod transfer;
+mod tx_status;
+mod verify;
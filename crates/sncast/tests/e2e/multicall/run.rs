@@ -2,7 +2,7 @@ use crate::helpers::constants::MULTICALL_CONFIGS_DIR;
 use crate::helpers::fixtures::default_cli_args;
 use crate::helpers::runner::runner;
 use indoc::indoc;
-use shared::test_utils::output_assert::{assert_stderr_contains, AsOutput};
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains, AsOutput};
 use std::path::Path;
 use test_case::test_case;
 
@@ -64,6 +64,34 @@ async fn test_calldata_ids() {
     "});
 }
 
+#[tokio::test]
+async fn test_happy_case_json() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user6"]);
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke.json");
+    let path_str = path.to_str().expect("failed converting path to str");
+
+    args.append(&mut vec!["multicall", "run", "--path", path_str]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert();
+
+    let stderr_str = output.as_stderr();
+    assert!(
+        stderr_str.is_empty(),
+        "Multicall error, stderr: \n{stderr_str}",
+    );
+
+    output.stdout_matches(indoc! {r"
+        command: multicall run
+        transaction_hash: 0x[..]
+    "});
+}
+
 #[tokio::test]
 async fn test_invalid_path() {
     let mut args = default_cli_args();
@@ -158,3 +186,126 @@ async fn test_deploy_success_invoke_fails() {
         "},
     );
 }
+
+#[tokio::test]
+async fn test_invalid_nonce() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user3"]);
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke.toml");
+    let path_str = path.to_str().expect("failed converting path to str");
+
+    args.append(&mut vec![
+        "multicall",
+        "run",
+        "--path",
+        path_str,
+        "--max-fee",
+        "99999999999999999",
+        "--nonce",
+        "12345",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: multicall run
+        error: Invalid transaction nonce
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user2"]);
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke.toml");
+    let path_str = path.to_str().expect("failed converting path to str");
+
+    args.append(&mut vec![
+        "multicall",
+        "run",
+        "--path",
+        path_str,
+        "--dry-run",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    output.stdout_matches(indoc! {r"
+        command: multicall run
+        calldata: [0x3, [..]]
+        calls: [deploy `map_contract` at 0x[..]: 0x41a78e741e5af2fec34b695679bc6891742439f7afb8484ecd7766661ad02bf::deployContract([0x2a09379665a749e609b4a8459c86fe954566a6beeaddd0950e43f6c700ed321, 0x[..], 0x0, 0x0]), invoke 0x7537a17e169c96cf2b0392508b3a66cbc50c9a811a8a7896529004c5e93fdf6::put([0x123, 0xea]), invoke 0x[..]::put([0x123, 0xea])]
+        data_gas_consumed: [..]
+        gas_consumed: [..]
+        overall_fee: [..]
+        unit: WEI
+    "});
+}
+
+#[tokio::test]
+async fn test_invoke_outputs() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user2"]);
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("invoke_outputs.toml");
+    let path_str = path.to_str().expect("failed converting path to str");
+
+    args.append(&mut vec![
+        "multicall",
+        "run",
+        "--path",
+        path_str,
+        "--dry-run",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(output, "calls: [[..], invoke 0x[..]::put([0x2, 0x2a])]");
+}
+
+#[tokio::test]
+async fn test_duplicated_ids() {
+    let mut args = default_cli_args();
+    args.append(&mut vec!["--account", "user2"]);
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("duplicated_ids.toml");
+    let path_str = path.to_str().expect("failed converting path to str");
+
+    args.append(&mut vec![
+        "multicall",
+        "run",
+        "--path",
+        path_str,
+        "--dry-run",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: multicall run
+        error: Duplicated id = map_contract found in multicall calls
+        "},
+    );
+}
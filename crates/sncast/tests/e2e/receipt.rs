@@ -0,0 +1,57 @@
+use crate::helpers::constants::URL;
+use crate::helpers::fixtures::{from_env, invoke_contract};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[tokio::test]
+async fn test_happy_case() {
+    let contract_address = from_env("CAST_MAP_ADDRESS").unwrap();
+    let transaction_hash =
+        invoke_contract("user2", &contract_address, "put", None, &["0x72", "0x63"])
+            .await
+            .transaction_hash;
+    let transaction_hash = format!("{transaction_hash:#x}");
+
+    let args = vec!["--url", URL, "receipt", &transaction_hash];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        actual_fee:
+          amount: [..]
+          unit: WEI
+        block_number: [..]
+        events:
+          - data: [..]
+            from_address: 0x[..]
+            keys: [..]
+        execution_resources:
+          steps: [..]
+        execution_status: SUCCEEDED
+        finality_status: ACCEPTED_ON_L2
+        messages_sent: []
+        transaction_hash: 0x[..]
+        transaction_type: INVOKE
+        "},
+    );
+}
+
+#[test]
+fn test_nonexistent_transaction() {
+    let args = vec!["--url", URL, "receipt", "0x123456789"];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: receipt
+        error: Transaction with provided hash was not found (does not exist)
+        "},
+    );
+}
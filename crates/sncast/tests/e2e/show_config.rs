@@ -1,6 +1,10 @@
 use crate::helpers::runner::runner;
 use configuration::copy_config_to_tempdir;
 use indoc::indoc;
+use serde_json::json;
+use shared::consts::EXPECTED_RPC_VERSION;
+use shared::test_utils::mock_rpc::MockRpcServer;
+use shared::test_utils::output_assert::assert_stderr_contains;
 
 #[tokio::test]
 async fn test_show_config_from_snfoundry_toml() {
@@ -105,3 +109,77 @@ async fn test_show_config_when_keystore() {
         wait_timeout: 300
     "});
 }
+
+#[tokio::test]
+async fn test_show_config_with_default_block_id() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec!["--profile", "profile6", "show-config"];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: show-config
+        account: user1
+        accounts_file_path: ../account-file
+        chain_id: alpha-goerli
+        default_block_id: latest
+        profile: profile6
+        rpc_url: http://127.0.0.1:5055/rpc
+        wait_retry_interval: 5
+        wait_timeout: 300
+    "});
+}
+
+#[test]
+fn test_show_config_with_mocked_rpc() {
+    let server = MockRpcServer::start()
+        .with_response("starknet_specVersion", json!(EXPECTED_RPC_VERSION))
+        .with_response("starknet_chainId", json!("0x534e5f5345504f4c4941"));
+    let args = vec![
+        "--url",
+        server.url(),
+        "--account",
+        "/path/to/account.json",
+        "--keystore",
+        "../keystore",
+        "show-config",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: show-config
+        account: /path/to/account.json
+        chain_id: alpha-sepolia
+        keystore: ../keystore
+        rpc_url: http://127.0.0.1:[..]
+        wait_retry_interval: 5
+        wait_timeout: 300
+    "});
+
+    server.assert_called("starknet_specVersion");
+    server.assert_called("starknet_chainId");
+}
+
+#[test]
+fn test_show_config_invalid_default_block_id() {
+    let tempdir = copy_config_to_tempdir(
+        "tests/data/files/invalid_default_block_id_snfoundry.toml",
+        None,
+    )
+    .unwrap();
+    let args = vec!["show-config"];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        Error: Invalid value of `default-block-id` in snfoundry.toml
+
+        Caused by:
+            Incorrect value passed for block_id = lates. Possible values are pending, latest, block hash (hex) and block number (u64)
+        "},
+    );
+}
@@ -0,0 +1,66 @@
+use crate::helpers::constants::{ACCOUNT, URL};
+use crate::helpers::fixtures::{default_cli_args, get_transaction_hash, get_transaction_receipt};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+use starknet::core::types::TransactionReceipt::Invoke;
+
+#[tokio::test]
+async fn test_happy_case() {
+    let recipient = "0x7472616e73666572";
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "--json",
+        "--wait",
+        "transfer",
+        "--recipient",
+        recipient,
+        "--amount",
+        "0.001",
+        "--token",
+        "eth",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+    assert!(matches!(receipt, Invoke(_)));
+
+    let args = vec!["--url", URL, "balance", "--address", recipient];
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: balance
+        balance: 1000000000000000
+        formatted_balance: 0.001 ETH
+    "});
+}
+
+#[test]
+fn test_too_many_decimal_places() {
+    let mut args = default_cli_args();
+    args.append(&mut vec![
+        "--account",
+        ACCOUNT,
+        "transfer",
+        "--recipient",
+        "0x1",
+        "--amount",
+        "0.0000000000000000001",
+    ]);
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: transfer
+        error: Amount = 0.0000000000000000001 has more than 18 decimal places
+        "},
+    );
+}
@@ -0,0 +1,68 @@
+use crate::helpers::constants::{ACCOUNT, URL};
+use crate::helpers::fixtures::{from_env, invoke_contract};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use sncast::helpers::constants::UDC_ADDRESS;
+
+#[test]
+fn test_happy_case() {
+    let hash = from_env("CAST_MAP_DECLARE_HASH").unwrap();
+    let args = vec!["--url", URL, "tx-status", &hash];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: tx-status
+        execution_status: SUCCEEDED
+        finality_status: ACCEPTED_ON_L2
+    "});
+}
+
+#[tokio::test]
+async fn test_reverted_transaction() {
+    let class_hash = from_env("CAST_WITH_CONSTRUCTOR_CLASS_HASH").unwrap();
+    let salt = "0x7478737461747573";
+    let max_fee: u64 = 43_400_000_000_000 - 1;
+
+    let transaction_hash = invoke_contract(
+        ACCOUNT,
+        UDC_ADDRESS,
+        "deployContract",
+        Some(max_fee.into()),
+        &[&class_hash, salt, "0x1", "0x3", "0x43", "0x41", "0x1"],
+    )
+    .await
+    .transaction_hash;
+    let transaction_hash = format!("{transaction_hash:#x}");
+
+    let args = vec!["--url", URL, "--wait", "tx-status", &transaction_hash];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: tx-status
+        execution_status: REVERTED
+        finality_status: ACCEPTED_ON_L2
+        revert_reason: Insufficient max fee[..]
+        "},
+    );
+}
+
+#[test]
+fn test_nonexistent_transaction() {
+    let args = vec!["--url", URL, "tx-status", "0x123456789"];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: tx-status
+        error: Transaction with provided hash was not found (does not exist)
+        "},
+    );
+}
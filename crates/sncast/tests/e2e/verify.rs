@@ -0,0 +1,58 @@
+use crate::helpers::constants::{CONTRACTS_DIR, URL};
+use crate::helpers::fixtures::{copy_directory_to_tempdir, from_env};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+
+#[test]
+fn test_unsupported_network() {
+    let contract_path = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+    let class_hash = from_env("CAST_MAP_CLASS_HASH").unwrap();
+    let args = vec![
+        "--url",
+        URL,
+        "verify",
+        "--class-hash",
+        &class_hash,
+        "--contract-name",
+        "Map",
+        "--verifier",
+        "starkscan",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: verify
+        error: Verification with Starkscan is not supported on network = alpha-goerli
+        "},
+    );
+}
+
+#[test]
+fn test_undeclared_class() {
+    let contract_path = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+    let args = vec![
+        "--url",
+        URL,
+        "verify",
+        "--class-hash",
+        "0x123456789",
+        "--contract-name",
+        "Map",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: verify
+        error: Provided class hash does not exist
+        "},
+    );
+}
@@ -76,6 +76,11 @@ fn start_devnet() {
         "/constructor_with_params/target/dev/constructor_with_params_ConstructorWithParams",
         "CAST_WITH_CONSTRUCTOR",
     ));
+    rt.block_on(declare_contract(
+        "user0",
+        "/argent_account/target/dev/argent_account_ArgentAccount",
+        "CAST_ARGENT_ACCOUNT",
+    ));
 
     rt.block_on(deploy_keystore_account());
     rt.block_on(deploy_cairo_0_account());
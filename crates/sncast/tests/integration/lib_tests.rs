@@ -5,8 +5,12 @@ use crate::helpers::fixtures::create_test_provider;
 
 use camino::Utf8PathBuf;
 use shared::rpc::{get_rpc_version, is_expected_version};
-use sncast::{check_if_legacy_contract, get_account, get_provider, parse_number};
+use sncast::helpers::constants::BRAAVOS_BASE_ACCOUNT_CLASS_HASH;
+use sncast::{check_if_legacy_contract, get_account, get_provider, parse_number, AccountType};
+use starknet::core::types::FieldElement;
+use starknet::core::utils::get_contract_address;
 use std::fs;
+use std::str::FromStr;
 use url::ParseError;
 
 #[tokio::test]
@@ -182,3 +186,50 @@ async fn test_check_if_legacy_contract_by_address() {
         .unwrap();
     assert!(!is_legacy);
 }
+
+#[test]
+fn test_account_type_from_str() {
+    assert_eq!(
+        AccountType::from_str("open_zeppelin").unwrap(),
+        AccountType::Oz
+    );
+    assert_eq!(
+        AccountType::from_str("argent").unwrap(),
+        AccountType::Argent
+    );
+    assert_eq!(
+        AccountType::from_str("braavos").unwrap(),
+        AccountType::Braavos
+    );
+    assert!(AccountType::from_str("unknown").is_err());
+}
+
+#[test]
+fn test_account_address_depends_on_account_type() {
+    let class_hash = parse_number("0x123").unwrap();
+    let salt = parse_number("0x1").unwrap();
+    let public_key = parse_number("0x456").unwrap();
+
+    assert_eq!(
+        AccountType::Oz.account_address(class_hash, salt, public_key),
+        get_contract_address(salt, class_hash, &[public_key], FieldElement::ZERO)
+    );
+    assert_eq!(
+        AccountType::Argent.account_address(class_hash, salt, public_key),
+        get_contract_address(
+            salt,
+            class_hash,
+            &[public_key, FieldElement::ZERO],
+            FieldElement::ZERO
+        )
+    );
+    assert_eq!(
+        AccountType::Braavos.account_address(class_hash, salt, public_key),
+        get_contract_address(
+            salt,
+            parse_number(BRAAVOS_BASE_ACCOUNT_CLASS_HASH).unwrap(),
+            &[public_key],
+            FieldElement::ZERO
+        )
+    );
+}
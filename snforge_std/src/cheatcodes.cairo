@@ -1,7 +1,10 @@
 use starknet::{ContractAddress, ClassHash, contract_address_const};
 use starknet::testing::cheatcode;
+use super::byte_array::byte_array_as_felt_array;
 
 mod events;
+mod messages_to_l1;
+mod measure;
 mod l1_handler;
 mod contract_class;
 mod tx_info;
@@ -69,6 +72,17 @@ fn stop_prank(target: CheatTarget) {
     cheatcode::<'stop_prank'>(inputs.span());
 }
 
+/// Makes `account` skip signature validation: its `__validate__`, `__validate_declare__`,
+/// `__validate_deploy__` and `is_valid_signature` return `'VALID'` without being executed
+/// and its `__execute__` sees zero caller address, like when called by the protocol
+fn start_impersonate(account: ContractAddress) {
+    cheatcode::<'start_impersonate'>(array![account.into()].span());
+}
+
+fn stop_impersonate(account: ContractAddress) {
+    cheatcode::<'stop_impersonate'>(array![account.into()].span());
+}
+
 fn warp(target: CheatTarget, block_timestamp: u64, span: CheatSpan) {
     validate_cheat_target_and_span(@target, @span);
 
@@ -109,6 +123,58 @@ fn stop_elect(target: CheatTarget) {
     cheatcode::<'stop_elect'>(inputs.span());
 }
 
+/// Changes the block hash returned by `get_block_hash_syscall` for `block_number`
+/// in the given target until `stop_mock_block_hash` is called
+fn start_mock_block_hash(target: CheatTarget, block_number: u64, block_hash: felt252) {
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    inputs.append(block_number.into());
+    inputs.append(block_hash);
+    cheatcode::<'start_mock_block_hash'>(inputs.span());
+}
+
+/// Restores the original block hash returned by `get_block_hash_syscall` for `block_number`
+fn stop_mock_block_hash(target: CheatTarget, block_number: u64) {
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    inputs.append(block_number.into());
+    cheatcode::<'stop_mock_block_hash'>(inputs.span());
+}
+
+/// Syscalls which results can be mocked with `start_mock_syscall`
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+enum MockableSyscall {
+    /// `get_block_hash_syscall`, responds with the block hash
+    GetBlockHash: (),
+    /// `keccak_syscall`, responds with the `low` and `high` parts of the hash
+    Keccak: (),
+    /// `storage_read_syscall`, responds with the read value
+    StorageRead: (),
+    /// `get_execution_info_syscall`, responds with the serialized fields of `ExecutionInfo`
+    GetExecutionInfo: (),
+}
+
+/// Makes `syscall` return `result` in the given target until `stop_mock_syscall` is called,
+/// instead of executing it
+/// `result` - felts of the response, or error data the syscall fails with
+fn start_mock_syscall(
+    target: CheatTarget, syscall: MockableSyscall, result: Result<Array<felt252>, Array<felt252>>
+) {
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    syscall.serialize(ref inputs);
+    result.serialize(ref inputs);
+    cheatcode::<'start_mock_syscall'>(inputs.span());
+}
+
+/// Makes `syscall` executed normally again in the given target
+fn stop_mock_syscall(target: CheatTarget, syscall: MockableSyscall) {
+    let mut inputs = array![];
+    target.serialize(ref inputs);
+    syscall.serialize(ref inputs);
+    cheatcode::<'stop_mock_syscall'>(inputs.span());
+}
+
 fn mock_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
     contract_address: ContractAddress, function_selector: felt252, ret_data: T, n_times: u32
 ) {
@@ -148,10 +214,145 @@ fn stop_mock_call(contract_address: ContractAddress, function_selector: felt252)
     cheatcode::<'stop_mock_call'>(array![contract_address_felt, function_selector].span());
 }
 
+/// Mocks calls to `function_selector` of `contract_address` made with exactly `calldata`, for `n_times` calls
+/// Calls with other calldata are not affected and reach `mock_call` mocks or the real implementation
+fn mock_call_when<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: Array<felt252>,
+    ret_data: T,
+    n_times: u32
+) {
+    assert!(n_times > 0, "cannot mock_call_when 0 times, n_times argument must be greater than 0");
+
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+
+    CheatSpan::TargetCalls(n_times).serialize(ref inputs);
+    calldata.serialize(ref inputs);
+
+    let mut ret_data_arr = ArrayTrait::new();
+    ret_data.serialize(ref ret_data_arr);
+
+    ret_data_arr.serialize(ref inputs);
+
+    cheatcode::<'mock_call_when'>(inputs.span());
+}
+
+/// Mocks calls to `function_selector` of `contract_address` made with exactly `calldata`,
+/// until `stop_mock_call_when` is called
+fn start_mock_call_when<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: Array<felt252>,
+    ret_data: T
+) {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+
+    CheatSpan::Indefinite.serialize(ref inputs);
+    calldata.serialize(ref inputs);
+
+    let mut ret_data_arr = ArrayTrait::new();
+    ret_data.serialize(ref ret_data_arr);
+
+    ret_data_arr.serialize(ref inputs);
+
+    cheatcode::<'mock_call_when'>(inputs.span());
+}
+
+/// Cancels the `mock_call_when` / `start_mock_call_when` for `calldata`
+fn stop_mock_call_when(
+    contract_address: ContractAddress, function_selector: felt252, calldata: Array<felt252>
+) {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+    calldata.serialize(ref inputs);
+
+    cheatcode::<'stop_mock_call_when'>(inputs.span());
+}
+
+/// Expects `function_selector` of `contract_address` to be called before the test finishes,
+/// the test fails otherwise
+fn expect_call(contract_address: ContractAddress, function_selector: felt252) {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+    Option::<Array<felt252>>::None.serialize(ref inputs);
+
+    cheatcode::<'expect_call'>(inputs.span());
+}
+
+/// Expects `function_selector` of `contract_address` to be called with exactly `calldata`
+/// before the test finishes, the test fails otherwise
+fn expect_call_with_calldata(
+    contract_address: ContractAddress, function_selector: felt252, calldata: Array<felt252>
+) {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+    Option::Some(calldata).serialize(ref inputs);
+
+    cheatcode::<'expect_call'>(inputs.span());
+}
+
+/// Expects the next call made from the test to fail with `panic_data`
+/// Such a call fails as usual, a call that succeeds or fails with other data fails the test
+fn expect_revert(panic_data: Array<felt252>) {
+    let mut inputs = array![];
+    panic_data.serialize(ref inputs);
+
+    cheatcode::<'expect_revert'>(inputs.span());
+}
+
 fn replace_bytecode(contract: ContractAddress, new_class: ClassHash) {
     cheatcode::<'replace_bytecode'>(array![contract.into(), new_class.into()].span());
 }
 
+/// Restores the original class of `contract` after `replace_bytecode`
+fn reset_bytecode(contract: ContractAddress) {
+    cheatcode::<'reset_bytecode'>(array![contract.into()].span());
+}
+
+/// Declares the Sierra artifact at `path` and replaces the class of `contract` with it
+/// `path` - path to the `*.contract_class.json` file, relative to the package root
+/// Returns the class hash of the declared class
+fn replace_bytecode_from_file(contract: ContractAddress, path: ByteArray) -> ClassHash {
+    let new_class = contract_class::declare_from_file(path);
+    replace_bytecode(contract, new_class.class_hash);
+    new_class.class_hash
+}
+
+/// Sets the nonce of `contract_address` to `nonce`
+/// The nonce can only be increased, setting a lower one fails the test
+fn set_contract_nonce(contract_address: ContractAddress, nonce: felt252) {
+    cheatcode::<'set_contract_nonce'>(array![contract_address.into(), nonce].span());
+}
+
+/// Attaches a human-readable `label` to `contract_address`
+/// Labeled addresses are printed as `label (address)` in call traces, event assertions and errors
+fn label(contract_address: ContractAddress, label: ByteArray) {
+    let mut inputs = byte_array_as_felt_array(@label);
+    inputs.append(contract_address.into());
+    cheatcode::<'label'>(inputs.span());
+}
+
+/// Returns the label attached to `contract_address` with `label`, if there is one
+fn get_label(contract_address: ContractAddress) -> Option<ByteArray> {
+    let mut output = cheatcode::<'get_label'>(array![contract_address.into()].span());
+    Serde::deserialize(ref output).unwrap()
+}
+
+/// Returns a random felt252, generated from the seed of the test run
+/// The seed is printed when a test fails and can be set with `--fuzzer-seed`
+fn rand_felt() -> felt252 {
+    *cheatcode::<'rand_felt'>(array![].span())[0]
+}
+
+/// Returns a random felt252 from `low` to `high` inclusive, bounds are compared as integers
+/// Fails the test if `low` is greater than `high`
+fn rand_in_range(low: felt252, high: felt252) -> felt252 {
+    *cheatcode::<'rand_in_range'>(array![low, high].span())[0]
+}
+
 fn validate_cheat_target_and_span(target: @CheatTarget, span: @CheatSpan) {
     validate_cheat_span(span);
 
@@ -150,6 +150,34 @@ fn declare(contract: ByteArray) -> ContractClass {
     ContractClass { class_hash }
 }
 
+/// Declares a Cairo 0 (legacy) contract class
+/// `path` - path to the compiled Cairo 0 contract JSON file, relative to the package root
+/// Returns the `ContractClass` which was declared
+fn declare_legacy(path: ByteArray) -> ContractClass {
+    let span = cheatcode::<'declare_legacy'>(byte_array_as_felt_array(@path).span());
+
+    let exit_code = *span[0];
+    let result = *span[1];
+    assert(exit_code == 0, 'declare should never fail');
+    let class_hash = result.try_into().unwrap();
+
+    ContractClass { class_hash }
+}
+
+/// Declares a contract class from its Sierra artifact
+/// `path` - path to the `*.contract_class.json` file, relative to the package root
+/// Returns the `ContractClass` which was declared
+fn declare_from_file(path: ByteArray) -> ContractClass {
+    let span = cheatcode::<'declare_from_file'>(byte_array_as_felt_array(@path).span());
+
+    let exit_code = *span[0];
+    let result = *span[1];
+    assert(exit_code == 0, 'declare should never fail');
+    let class_hash = result.try_into().unwrap();
+
+    ContractClass { class_hash }
+}
+
 /// Retrieves a class hash of a contract deployed under the given address
 /// `contract_address` - target contract address
 /// Returns the `ClassHash` under given address
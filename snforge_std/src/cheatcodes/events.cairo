@@ -1,5 +1,7 @@
 use starknet::testing::cheatcode;
 use starknet::ContractAddress;
+use super::super::byte_array::byte_array_as_felt_array;
+use super::get_label;
 
 #[derive(Drop, Serde)]
 enum SpyOn {
@@ -11,6 +13,18 @@ enum SpyOn {
 fn spy_events(spy_on: SpyOn) -> EventSpy {
     let mut inputs = array![];
     spy_on.serialize(ref inputs);
+    Option::<Array<felt252>>::None.serialize(ref inputs);
+    let output = cheatcode::<'spy_events'>(inputs.span());
+
+    EventSpy { _id: *output[0], events: array![] }
+}
+
+/// Creates `EventSpy` catching only events with one of `selectors` emitted by contracts from `spy_on`
+/// Selector of an event is the `selector!` of its name
+fn spy_events_with_selectors(spy_on: SpyOn, selectors: Array<felt252>) -> EventSpy {
+    let mut inputs = array![];
+    spy_on.serialize(ref inputs);
+    Option::Some(selectors).serialize(ref inputs);
     let output = cheatcode::<'spy_events'>(inputs.span());
 
     EventSpy { _id: *output[0], events: array![] }
@@ -75,10 +89,10 @@ impl EventAssertionsImpl<
             let emitted = is_emitted(ref self, from, event);
 
             if !emitted {
-                panic(
-                    array![
-                        'Event with matching data and', 'keys was not emitted from', (*from).into()
-                    ]
+                panic_with_address_and_details(
+                    "Event with matching data and keys was not emitted from",
+                    *from,
+                    event_mismatch_details(@self, from, event)
                 );
             }
 
@@ -99,8 +113,8 @@ impl EventAssertionsImpl<
             let emitted = is_emitted(ref self, from, event);
 
             if emitted {
-                panic(
-                    array!['Event with matching data and', 'keys was emitted from', (*from).into()]
+                panic_with_address(
+                    "Event with matching data and keys was emitted from", *from
                 );
             }
 
@@ -109,6 +123,166 @@ impl EventAssertionsImpl<
     }
 }
 
+/// Panics with `message` followed by `address`, formatted as `label (0x...)` if a label was attached with `label`
+fn panic_with_address(message: ByteArray, address: ContractAddress) {
+    panic_with_address_and_details(message, address, "");
+}
+
+/// Like `panic_with_address`, with `details` appended after the address
+fn panic_with_address_and_details(message: ByteArray, address: ContractAddress, details: ByteArray) {
+    let mut message = message;
+    message.append(@" ");
+    message.append(@format_address(address));
+    message.append(@details);
+
+    panic(byte_array_as_felt_array(@message))
+}
+
+/// Formats `address` as `label (0x...)` if a label was attached to it with `label`, as `0x...` otherwise
+fn format_address(address: ContractAddress) -> ByteArray {
+    let mut hex_address = "";
+    append_hex(ref hex_address, address.into());
+
+    match get_label(address) {
+        Option::Some(label) => {
+            let mut formatted = label;
+            formatted.append(@" (");
+            formatted.append(@hex_address);
+            formatted.append(@")");
+            formatted
+        },
+        Option::None => hex_address,
+    }
+}
+
+/// Appends `value` to `output` as a `0x` prefixed lowercase hexadecimal number
+fn append_hex(ref output: ByteArray, value: felt252) {
+    output.append(@"0x");
+
+    let mut value: u256 = value.into();
+    if value == 0 {
+        output.append(@"0");
+        return;
+    }
+
+    let mut digits: Array<u8> = array![];
+    loop {
+        if value == 0 {
+            break;
+        }
+        digits.append((value % 16).try_into().unwrap());
+        value = value / 16;
+    };
+
+    let mut i = digits.len();
+    loop {
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+
+        let digit = *digits.at(i);
+        // ASCII codes of `0` and of `a` shifted by 10
+        if digit < 10 {
+            output.append_byte(48 + digit);
+        } else {
+            output.append_byte(87 + digit);
+        }
+    };
+}
+
+/// Describes how not yet asserted events emitted from `expected_from` with the same selector as `expected_event`
+/// differ from it, naming the first mismatched key and data field of each of them
+fn event_mismatch_details<T, impl TEvent: starknet::Event<T>, impl TDrop: Drop<T>>(
+    self: @EventSpy, expected_from: @ContractAddress, expected_event: @T
+) -> ByteArray {
+    let mut expected_keys = array![];
+    let mut expected_data = array![];
+    expected_event.append_keys_and_data(ref expected_keys, ref expected_data);
+
+    let mut details = "";
+    if expected_keys.len() == 0 {
+        return details;
+    }
+
+    let emitted_events = self.events;
+    let mut i = 0;
+    loop {
+        if i >= emitted_events.len() {
+            break;
+        }
+        let (from, event) = emitted_events.at(i);
+
+        if from == expected_from && event.keys.len() > 0 {
+            if event.keys.at(0) == expected_keys.at(0) {
+                details.append(@"\nEmitted event with the same selector differs at ");
+
+                let mut differences: Array<ByteArray> = array![];
+                match first_difference("keys", @expected_keys, event.keys) {
+                    Option::Some(difference) => differences.append(difference),
+                    Option::None => {},
+                };
+                match first_difference("data", @expected_data, event.data) {
+                    Option::Some(difference) => differences.append(difference),
+                    Option::None => {},
+                };
+
+                let mut j = 0;
+                loop {
+                    if j >= differences.len() {
+                        break;
+                    }
+                    if j > 0 {
+                        details.append(@" and ");
+                    }
+                    details.append(differences.at(j));
+
+                    j += 1;
+                };
+            }
+        }
+
+        i += 1;
+    };
+
+    if details.len() == 0 {
+        details.append(@"\nNo event with the same selector was emitted from this address");
+    }
+
+    details
+}
+
+/// Describes the first field at which `emitted` differs from `expected`, or their lengths if one is a prefix of the other
+fn first_difference(
+    name: ByteArray, expected: @Array<felt252>, emitted: @Array<felt252>
+) -> Option<ByteArray> {
+    let mut i = 0;
+    let difference = loop {
+        if i >= expected.len() || i >= emitted.len() {
+            break Option::None;
+        }
+
+        if expected.at(i) != emitted.at(i) {
+            let mut expected_value = "";
+            append_hex(ref expected_value, *expected.at(i));
+            let mut emitted_value = "";
+            append_hex(ref emitted_value, *emitted.at(i));
+
+            break Option::Some(
+                format!("{}[{}]: expected {}, emitted {}", name, i, expected_value, emitted_value)
+            );
+        }
+
+        i += 1;
+    };
+
+    if difference.is_some() || expected.len() == emitted.len() {
+        return difference;
+    }
+
+    Option::Some(format!("{} length: expected {}, emitted {}", name, expected.len(), emitted.len()))
+}
+
 fn is_emitted<T, impl TEvent: starknet::Event<T>, impl TDrop: Drop<T>>(
     ref self: EventSpy, expected_from: @ContractAddress, expected_event: @T
 ) -> bool {
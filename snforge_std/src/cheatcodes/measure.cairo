@@ -0,0 +1,31 @@
+use starknet::testing::cheatcode;
+
+/// Resources used between `start_measure` and `stop_measure`
+#[derive(Drop, Serde, Clone, PartialEq)]
+struct Measurement {
+    steps: usize,
+    memory_holes: usize,
+    /// Builtin names with numbers of their used instances, e.g. `('range_check_builtin', 2)`
+    builtins: Array<(felt252, usize)>,
+    /// Syscall names with numbers of their executions, e.g. `('CallContract', 1)`
+    syscalls: Array<(felt252, usize)>,
+}
+
+/// Starts measuring resources used by contract calls and syscalls made from the test
+/// Calling it again restarts the measurement
+fn start_measure() {
+    cheatcode::<'start_measure'>(array![].span());
+}
+
+/// Stops the measurement started with `start_measure` and returns resources used since then
+fn stop_measure() -> Measurement {
+    let mut output = cheatcode::<'stop_measure'>(array![].span());
+    Serde::deserialize(ref output).unwrap()
+}
+
+/// Returns syscall names with numbers of their executions since the test started,
+/// including syscalls executed by contracts called from the test
+fn get_syscall_counts() -> Array<(felt252, usize)> {
+    let mut output = cheatcode::<'get_syscall_counts'>(array![].span());
+    Serde::deserialize(ref output).unwrap()
+}
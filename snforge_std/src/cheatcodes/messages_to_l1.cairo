@@ -0,0 +1,135 @@
+use starknet::testing::cheatcode;
+use starknet::{ContractAddress, EthAddress};
+use super::events::panic_with_address;
+
+/// Message sent to L1 with `send_message_to_l1_syscall`
+#[derive(Drop, Clone, Serde, PartialEq)]
+struct MessageToL1 {
+    to_address: EthAddress,
+    payload: Array<felt252>
+}
+
+#[derive(Drop, Serde)]
+struct MessageToL1Spy {
+    _id: felt252,
+    messages: Array<(ContractAddress, MessageToL1)>,
+}
+
+/// Creates `MessageToL1Spy` instance which spies on messages sent to L1 after its creation
+fn spy_messages_to_l1() -> MessageToL1Spy {
+    let output = cheatcode::<'spy_messages_to_l1'>(array![].span());
+
+    MessageToL1Spy { _id: *output[0], messages: array![] }
+}
+
+trait MessageToL1SpyTrait {
+    fn fetch_messages(ref self: MessageToL1Spy);
+}
+
+impl MessageToL1SpyTraitImpl of MessageToL1SpyTrait {
+    fn fetch_messages(ref self: MessageToL1Spy) {
+        let mut output = cheatcode::<'fetch_messages_to_l1'>(array![self._id].span());
+        let messages = Serde::<Array<(ContractAddress, MessageToL1)>>::deserialize(ref output)
+            .unwrap();
+
+        let mut i = 0;
+        loop {
+            if i >= messages.len() {
+                break;
+            }
+            let (from, message) = messages.at(i);
+            self.messages.append((*from, message.clone()));
+            i += 1;
+        }
+    }
+}
+
+trait MessageToL1SpyAssertions {
+    fn assert_sent(ref self: MessageToL1Spy, messages: @Array<(ContractAddress, MessageToL1)>);
+    fn assert_not_sent(ref self: MessageToL1Spy, messages: @Array<(ContractAddress, MessageToL1)>);
+}
+
+impl MessageToL1SpyAssertionsImpl of MessageToL1SpyAssertions {
+    fn assert_sent(ref self: MessageToL1Spy, messages: @Array<(ContractAddress, MessageToL1)>) {
+        self.fetch_messages();
+
+        let mut i = 0;
+        loop {
+            if i >= messages.len() {
+                break;
+            }
+
+            let (from, message) = messages.at(i);
+            let sent = is_sent(ref self, from, message);
+
+            if !sent {
+                panic_with_address(
+                    "Message with matching data and receiver was not sent from", *from
+                );
+            }
+
+            i += 1;
+        };
+    }
+
+    fn assert_not_sent(ref self: MessageToL1Spy, messages: @Array<(ContractAddress, MessageToL1)>) {
+        self.fetch_messages();
+
+        let mut i = 0;
+        loop {
+            if i >= messages.len() {
+                break;
+            }
+
+            let (from, message) = messages.at(i);
+            let sent = is_sent(ref self, from, message);
+
+            if sent {
+                panic_with_address(
+                    "Message with matching data and receiver was sent from", *from
+                );
+            }
+
+            i += 1;
+        };
+    }
+}
+
+fn is_sent(
+    ref self: MessageToL1Spy, expected_from: @ContractAddress, expected_message: @MessageToL1
+) -> bool {
+    let sent_messages = @self.messages;
+
+    let mut j = 0;
+    return loop {
+        if j >= sent_messages.len() {
+            break false;
+        }
+        let (from, message) = sent_messages.at(j);
+
+        if from == expected_from && message == expected_message {
+            remove_message(ref self, j);
+            break true;
+        }
+
+        j += 1;
+    };
+}
+
+fn remove_message(ref self: MessageToL1Spy, index: usize) {
+    let sent_messages = @self.messages;
+    let mut sent_messages_deleted_message = array![];
+    let mut k = 0;
+    loop {
+        if k >= sent_messages.len() {
+            break;
+        }
+
+        if k != index {
+            let (from, message) = sent_messages.at(k);
+            sent_messages_deleted_message.append((*from, message.clone()));
+        }
+        k += 1;
+    };
+    self.messages = sent_messages_deleted_message;
+}
@@ -52,8 +52,64 @@ fn load(target: ContractAddress, storage_address: felt252, size: felt252) -> Arr
     output_array
 }
 
+/// Restores `size` felts in `target` contract's storage, starting at `storage_address`,
+/// to their values from before they were first overridden with `store`
+/// Felts which were not overridden are left unchanged
+fn reset_store(target: ContractAddress, storage_address: felt252, size: felt252) {
+    let mut offset: usize = 0;
+    loop {
+        if offset.into() == size {
+            break;
+        }
+
+        let address = storage_address + offset.into();
+        validate_storage_address_felt(address);
+        cheatcode::<'reset_store'>(array![target.into(), address].span());
+        offset += 1;
+    }
+}
+
+/// Storage read or write made by a contract or the test
+#[derive(Drop, Clone, Serde, PartialEq)]
+struct StorageAccess {
+    contract_address: ContractAddress,
+    key: felt252,
+    is_write: bool,
+    /// Value read from or written to the slot
+    value: felt252,
+}
+
+/// Returns storage reads and writes made with syscalls since the test started, in order
+/// Accesses made with `store` and `load` cheatcodes are not included
+fn get_storage_accesses() -> Array<StorageAccess> {
+    let mut output = cheatcode::<'get_storage_accesses'>(array![].span());
+    Serde::deserialize(ref output).unwrap()
+}
+
 fn map_entry_address(map_selector: felt252, keys: Span<felt252>) -> felt252 {
     let mut inputs = array![map_selector];
     keys.serialize(ref inputs);
     *cheatcode::<'map_entry_address'>(inputs.span()).at(0)
 }
+
+/// Sets the balance of `account` in the ERC20 `token` to `amount` by writing the token's storage directly
+/// Works for tokens keeping balances in `ERC20_balances: LegacyMap<ContractAddress, u256>`,
+/// like the StarkGate fee tokens and OpenZeppelin ERC20, total supply is not changed
+fn deal(token: ContractAddress, account: ContractAddress, amount: u256) {
+    deal_with_balances_variable(token, account, amount, selector!("ERC20_balances"));
+}
+
+/// Like `deal`, for tokens keeping balances in `LegacyMap<ContractAddress, u256>` variable
+/// with `balances_variable` selector
+fn deal_with_balances_variable(
+    token: ContractAddress, account: ContractAddress, amount: u256, balances_variable: felt252
+) {
+    let mut serialized_amount = array![];
+    amount.serialize(ref serialized_amount);
+
+    store(
+        token,
+        map_entry_address(balances_variable, array![account.into()].span()),
+        serialized_amount.span()
+    );
+}
@@ -1,3 +1,4 @@
 mod env_vars;
 
 use env_vars::var;
+use env_vars::var_byte_array;
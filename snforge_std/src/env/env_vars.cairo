@@ -5,3 +5,9 @@ fn var(name: ByteArray) -> felt252 {
     let outputs = cheatcode::<'var'>(byte_array_as_felt_array(@name).span());
     *outputs[0]
 }
+
+/// Reads an environment variable holding a string in double quotes, e.g. `"Hello, world!"`
+fn var_byte_array(name: ByteArray) -> ByteArray {
+    let mut outputs = cheatcode::<'var'>(byte_array_as_felt_array(@name).span());
+    Serde::deserialize(ref outputs).expect('Failed to deserialize ByteArray')
+}
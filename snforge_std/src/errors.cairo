@@ -1,6 +1,7 @@
 use core::option::OptionTrait;
 use core::starknet::SyscallResult;
 use core::byte_array::BYTE_ARRAY_MAGIC;
+use starknet::testing::cheatcode;
 
 pub enum PanicDataOrString {
     String: ByteArray,
@@ -29,3 +30,24 @@ impl SyscallResultStringErrorTraitImpl<T> of SyscallResultStringErrorTrait<T> {
         }
     }
 }
+
+/// Single value of panic data decoded with `decode_panic_data`
+#[derive(Drop, Clone, PartialEq, Serde)]
+enum PanicDataItem {
+    /// Felt which is a printable short string, e.g. a message passed to `assert`
+    ShortString: felt252,
+    /// `ByteArray`, e.g. a message passed to `panic!`
+    String: ByteArray,
+    /// Any other felt, e.g. an error selector
+    Felt: felt252,
+}
+
+/// Splits panic data returned from a failed `SafeDispatcher` call
+/// into short strings, `ByteArray`s and other felts
+fn decode_panic_data(panic_data: @Array<felt252>) -> Array<PanicDataItem> {
+    let mut inputs = array![];
+    panic_data.serialize(ref inputs);
+
+    let mut output = cheatcode::<'decode_panic_data'>(inputs.span());
+    Serde::deserialize(ref output).unwrap()
+}
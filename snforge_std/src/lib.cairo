@@ -1,6 +1,8 @@
 mod cheatcodes;
 
 use cheatcodes::contract_class::declare;
+use cheatcodes::contract_class::declare_legacy;
+use cheatcodes::contract_class::declare_from_file;
 use cheatcodes::contract_class::get_class_hash;
 use cheatcodes::contract_class::RevertedTransaction;
 use cheatcodes::contract_class::RevertedTransactionTrait;
@@ -25,11 +27,28 @@ use cheatcodes::events::EventSpy;
 use cheatcodes::events::EventFetcher;
 use cheatcodes::events::EventAssertions;
 use cheatcodes::events::spy_events;
+use cheatcodes::events::spy_events_with_selectors;
 use cheatcodes::events::event_name_hash;
 
+use cheatcodes::messages_to_l1::MessageToL1;
+use cheatcodes::messages_to_l1::MessageToL1Spy;
+use cheatcodes::messages_to_l1::MessageToL1SpyTrait;
+use cheatcodes::messages_to_l1::MessageToL1SpyAssertions;
+use cheatcodes::messages_to_l1::spy_messages_to_l1;
+
+use cheatcodes::measure::Measurement;
+use cheatcodes::measure::start_measure;
+use cheatcodes::measure::stop_measure;
+use cheatcodes::measure::get_syscall_counts;
+
 use cheatcodes::storage::store;
 use cheatcodes::storage::load;
+use cheatcodes::storage::reset_store;
 use cheatcodes::storage::map_entry_address;
+use cheatcodes::storage::deal;
+use cheatcodes::storage::deal_with_balances_variable;
+use cheatcodes::storage::StorageAccess;
+use cheatcodes::storage::get_storage_accesses;
 
 use cheatcodes::CheatTarget;
 use cheatcodes::CheatSpan;
@@ -38,6 +57,8 @@ use cheatcodes::test_selector;
 use cheatcodes::prank;
 use cheatcodes::start_prank;
 use cheatcodes::stop_prank;
+use cheatcodes::start_impersonate;
+use cheatcodes::stop_impersonate;
 use cheatcodes::roll;
 use cheatcodes::start_roll;
 use cheatcodes::stop_roll;
@@ -47,10 +68,28 @@ use cheatcodes::stop_warp;
 use cheatcodes::elect;
 use cheatcodes::start_elect;
 use cheatcodes::stop_elect;
+use cheatcodes::start_mock_block_hash;
+use cheatcodes::stop_mock_block_hash;
+use cheatcodes::MockableSyscall;
+use cheatcodes::start_mock_syscall;
+use cheatcodes::stop_mock_syscall;
 use cheatcodes::mock_call;
 use cheatcodes::start_mock_call;
 use cheatcodes::stop_mock_call;
+use cheatcodes::mock_call_when;
+use cheatcodes::start_mock_call_when;
+use cheatcodes::stop_mock_call_when;
+use cheatcodes::expect_call;
+use cheatcodes::expect_call_with_calldata;
+use cheatcodes::expect_revert;
 use cheatcodes::replace_bytecode;
+use cheatcodes::reset_bytecode;
+use cheatcodes::replace_bytecode_from_file;
+use cheatcodes::set_contract_nonce;
+use cheatcodes::label;
+use cheatcodes::get_label;
+use cheatcodes::rand_felt;
+use cheatcodes::rand_in_range;
 
 mod fs;
 
@@ -62,4 +101,6 @@ mod trace;
 
 mod errors;
 
+mod version;
+
 mod byte_array;
@@ -1,6 +1,7 @@
 use core::result::ResultTrait;
 use core::starknet::testing::cheatcode;
 use core::starknet::ContractAddress;
+use super::cheatcodes::get_label;
 
 /// Tree-like structure which contains all of the starknet calls and sub-calls along with the results
 #[derive(Drop, Serde, PartialEq)]
@@ -222,12 +223,12 @@ impl DisplayIndentedEntryPoint of Display<Indented<CallEntryPoint>> {
         write!(f, "\n")?;
         write_indents_to_formatter(*self.base_indents, ref f);
         write!(f, "Storage address: ")?;
-        Debug::fmt(*self.struct_ref.contract_address, ref f)?;
+        write_address_to_formatter(*self.struct_ref.contract_address, ref f)?;
 
         write!(f, "\n")?;
         write_indents_to_formatter(*self.base_indents, ref f);
         write!(f, "Caller address: ")?;
-        Debug::fmt(*self.struct_ref.caller_address, ref f)?;
+        write_address_to_formatter(*self.struct_ref.caller_address, ref f)?;
 
         write!(f, "\n")?;
         write_indents_to_formatter(*self.base_indents, ref f);
@@ -256,3 +257,15 @@ fn write_indents_to_formatter(indents: u8, ref f: Formatter) {
         i = i + 1;
     }
 }
+
+/// Writes `address` prefixed with its label, if one was attached with `label`
+fn write_address_to_formatter(address: @ContractAddress, ref f: Formatter) -> Result<(), Error> {
+    match get_label(*address) {
+        Option::Some(label) => {
+            write!(f, "{} (", label)?;
+            Debug::fmt(address, ref f)?;
+            write!(f, ")")
+        },
+        Option::None => Debug::fmt(address, ref f),
+    }
+}
@@ -0,0 +1,43 @@
+use starknet::testing::cheatcode;
+
+/// Version of the snforge binary running the tests
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+struct SnforgeVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+/// Returns the version of the snforge binary running the tests, which determines available cheatcodes
+fn cheatcode_version() -> SnforgeVersion {
+    let mut output = cheatcode::<'cheatcode_version'>(array![].span());
+    Serde::deserialize(ref output).unwrap()
+}
+
+/// Checks if the snforge binary running the tests is `major.minor.patch` or newer
+fn is_snforge_at_least(major: u32, minor: u32, patch: u32) -> bool {
+    let version = cheatcode_version();
+    if version.major != major {
+        return version.major > major;
+    }
+    if version.minor != minor {
+        return version.minor > minor;
+    }
+    version.patch >= patch
+}
+
+/// Panics if the snforge binary running the tests is older than `major.minor.patch`,
+/// use it before cheatcodes added in that version to fail with a clear message
+fn require_snforge_version(major: u32, minor: u32, patch: u32) {
+    let version = cheatcode_version();
+    assert!(
+        is_snforge_at_least(major, minor, patch),
+        "snforge {}.{}.{} or newer is required, tests are run with snforge {}.{}.{}",
+        major,
+        minor,
+        patch,
+        version.major,
+        version.minor,
+        version.patch
+    );
+}
@@ -0,0 +1,96 @@
+use starknet::account::Call;
+
+#[starknet::interface]
+pub trait IAccount<TContractState> {
+    fn get_public_key(self: @TContractState) -> felt252;
+    fn is_valid_signature(
+        self: @TContractState, hash: felt252, signature: Array<felt252>
+    ) -> felt252;
+    fn __validate__(ref self: TContractState, calls: Array<Call>) -> felt252;
+    fn __validate_declare__(self: @TContractState, class_hash: felt252) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+}
+
+#[starknet::contract(account)]
+mod Account {
+    use core::ecdsa::check_ecdsa_signature;
+    use core::zeroable::Zeroable;
+    use starknet::account::Call;
+    use starknet::{get_caller_address, get_tx_info, call_contract_syscall};
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[abi(embed_v0)]
+    impl AccountImpl of super::IAccount<ContractState> {
+        fn get_public_key(self: @ContractState) -> felt252 {
+            self.public_key.read()
+        }
+
+        fn is_valid_signature(
+            self: @ContractState, hash: felt252, signature: Array<felt252>
+        ) -> felt252 {
+            if self._is_valid_signature(hash, signature.span()) {
+                starknet::VALIDATED
+            } else {
+                0
+            }
+        }
+
+        fn __validate__(ref self: ContractState, calls: Array<Call>) -> felt252 {
+            self._validate_transaction()
+        }
+
+        fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+            self._validate_transaction()
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            assert(get_caller_address().is_zero(), 'Account: invalid caller');
+
+            let mut calls = calls;
+            let mut results = ArrayTrait::new();
+            loop {
+                match calls.pop_front() {
+                    Option::Some(call) => {
+                        let result = call_contract_syscall(
+                            call.to, call.selector, call.calldata.span()
+                        )
+                            .unwrap();
+                        results.append(result);
+                    },
+                    Option::None => { break; },
+                };
+            };
+            results
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn _is_valid_signature(
+            self: @ContractState, hash: felt252, signature: Span<felt252>
+        ) -> bool {
+            if signature.len() != 2 {
+                return false;
+            }
+            check_ecdsa_signature(hash, self.public_key.read(), *signature.at(0), *signature.at(1))
+        }
+
+        fn _validate_transaction(self: @ContractState) -> felt252 {
+            let tx_info = get_tx_info().unbox();
+            assert(
+                self._is_valid_signature(tx_info.transaction_hash, tx_info.signature),
+                'Account: invalid signature'
+            );
+            starknet::VALIDATED
+        }
+    }
+}
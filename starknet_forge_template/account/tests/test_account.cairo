@@ -0,0 +1,42 @@
+use starknet::ContractAddress;
+
+use snforge_std::{declare, ContractClassTrait};
+use snforge_std::signature::{KeyPairTrait, SignerTrait};
+use snforge_std::signature::stark_curve::{StarkCurveKeyPairImpl, StarkCurveSignerImpl};
+
+use {{ PROJECT_NAME }}::IAccountDispatcher;
+use {{ PROJECT_NAME }}::IAccountDispatcherTrait;
+
+fn deploy_account(public_key: felt252) -> ContractAddress {
+    let contract = declare("Account");
+    contract.deploy(@array![public_key]).unwrap()
+}
+
+#[test]
+fn test_get_public_key() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let contract_address = deploy_account(key_pair.public_key);
+
+    let dispatcher = IAccountDispatcher { contract_address };
+
+    assert(dispatcher.get_public_key() == key_pair.public_key, 'Invalid public key');
+}
+
+#[test]
+fn test_is_valid_signature() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let contract_address = deploy_account(key_pair.public_key);
+
+    let dispatcher = IAccountDispatcher { contract_address };
+
+    let hash = 'message hash';
+    let (r, s): (felt252, felt252) = key_pair.sign(hash);
+
+    assert(
+        dispatcher.is_valid_signature(hash, array![r, s]) == starknet::VALIDATED,
+        'Signature should be valid'
+    );
+    assert(
+        dispatcher.is_valid_signature(hash, array![r, s + 1]) == 0, 'Signature should be invalid'
+    );
+}
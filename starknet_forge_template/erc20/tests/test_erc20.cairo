@@ -0,0 +1,89 @@
+use starknet::{ContractAddress, contract_address_const};
+
+use snforge_std::{declare, ContractClassTrait, start_prank, stop_prank, CheatTarget};
+
+use {{ PROJECT_NAME }}::IERC20SafeDispatcher;
+use {{ PROJECT_NAME }}::IERC20SafeDispatcherTrait;
+use {{ PROJECT_NAME }}::IERC20Dispatcher;
+use {{ PROJECT_NAME }}::IERC20DispatcherTrait;
+
+const INITIAL_SUPPLY: u256 = 1000;
+
+fn owner() -> ContractAddress {
+    contract_address_const::<'owner'>()
+}
+
+fn recipient() -> ContractAddress {
+    contract_address_const::<'recipient'>()
+}
+
+fn deploy_erc20() -> ContractAddress {
+    let contract = declare("ERC20");
+
+    let mut constructor_calldata = array!['Token', 'TKN', 18];
+    INITIAL_SUPPLY.serialize(ref constructor_calldata);
+    constructor_calldata.append(owner().into());
+
+    contract.deploy(@constructor_calldata).unwrap()
+}
+
+#[test]
+fn test_constructor() {
+    let contract_address = deploy_erc20();
+
+    let dispatcher = IERC20Dispatcher { contract_address };
+
+    assert(dispatcher.name() == 'Token', 'Invalid name');
+    assert(dispatcher.symbol() == 'TKN', 'Invalid symbol');
+    assert(dispatcher.decimals() == 18, 'Invalid decimals');
+    assert(dispatcher.total_supply() == INITIAL_SUPPLY, 'Invalid total supply');
+    assert(dispatcher.balance_of(owner()) == INITIAL_SUPPLY, 'Invalid balance');
+}
+
+#[test]
+fn test_transfer() {
+    let contract_address = deploy_erc20();
+
+    let dispatcher = IERC20Dispatcher { contract_address };
+
+    start_prank(CheatTarget::One(contract_address), owner());
+    dispatcher.transfer(recipient(), 100);
+    stop_prank(CheatTarget::One(contract_address));
+
+    assert(dispatcher.balance_of(owner()) == INITIAL_SUPPLY - 100, 'Invalid owner balance');
+    assert(dispatcher.balance_of(recipient()) == 100, 'Invalid recipient balance');
+}
+
+#[test]
+fn test_transfer_from() {
+    let contract_address = deploy_erc20();
+
+    let dispatcher = IERC20Dispatcher { contract_address };
+
+    start_prank(CheatTarget::One(contract_address), owner());
+    dispatcher.approve(recipient(), 100);
+
+    start_prank(CheatTarget::One(contract_address), recipient());
+    dispatcher.transfer_from(owner(), recipient(), 40);
+    stop_prank(CheatTarget::One(contract_address));
+
+    assert(dispatcher.allowance(owner(), recipient()) == 60, 'Invalid allowance');
+    assert(dispatcher.balance_of(recipient()) == 40, 'Invalid recipient balance');
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_cannot_transfer_more_than_balance() {
+    let contract_address = deploy_erc20();
+
+    let safe_dispatcher = IERC20SafeDispatcher { contract_address };
+
+    start_prank(CheatTarget::One(contract_address), owner());
+
+    match safe_dispatcher.transfer(recipient(), INITIAL_SUPPLY + 1) {
+        Result::Ok(_) => core::panic_with_felt252('Should have panicked'),
+        Result::Err(panic_data) => {
+            assert(*panic_data.at(0) == 'ERC20: insufficient balance', *panic_data.at(0));
+        }
+    };
+}